@@ -1,4 +1,6 @@
 mod error;
 mod parameter;
-mod types;
+pub mod types;
 mod validation;
+
+pub use parameter::{ParameterCollection, ParameterError, ParameterValue};
@@ -29,6 +29,8 @@ pub enum DisplayCondition {
     IsEmpty,
     /// Value is NOT empty
     IsNotEmpty,
+    /// Value is a mode with the specified key selected
+    Mode(String),
     /// All conditions must be met (logical AND)
     And(Vec<DisplayCondition>),
     /// At least one condition must be met (logical OR)
@@ -54,6 +56,7 @@ impl DisplayCondition {
             Self::NotIn(list) => ValueComparison::not_in_list(value, list),
             Self::IsEmpty => ValueComparison::is_empty(value),
             Self::IsNotEmpty => ValueComparison::is_not_empty(value),
+            Self::Mode(key) => matches!(value, Value::Mode(mode) if mode.key() == key),
 
             Self::And(conditions) => conditions.iter().all(|condition| condition.check(value)),
             Self::Or(conditions) => conditions.iter().any(|condition| condition.check(value)),
@@ -121,6 +124,11 @@ impl DisplayCondition {
         Self::IsNotEmpty
     }
 
+    /// Creates a "mode selected" condition
+    pub fn mode_is(key: impl Into<String>) -> Self {
+        Self::Mode(key.into())
+    }
+
     /// Creates a logical AND from multiple conditions
     pub fn and(conditions: Vec<Self>) -> Self {
         Self::And(conditions)
@@ -355,6 +363,16 @@ impl ParameterDisplayBuilder {
         self.show_when(field, DisplayCondition::is_in(values))
     }
 
+    /// Adds a hide condition by selected mode
+    pub fn hide_when_mode(self, field: ParameterKey, mode: impl Into<String>) -> Self {
+        self.hide_when(field, DisplayCondition::mode_is(mode))
+    }
+
+    /// Adds a show condition by selected mode
+    pub fn show_when_mode(self, field: ParameterKey, mode: impl Into<String>) -> Self {
+        self.show_when(field, DisplayCondition::mode_is(mode))
+    }
+
     /// Adds a hide condition for empty value
     pub fn hide_when_empty(self, field: ParameterKey) -> Self {
         self.hide_when(field, DisplayCondition::is_empty())
@@ -490,6 +508,16 @@ mod tests {
         assert!(!display.should_display(&values));
     }
 
+    #[test]
+    fn test_display_condition_mode() {
+        use crate::value::ModeValue;
+
+        let condition = DisplayCondition::mode_is("advanced");
+        assert!(condition.check(&Value::Mode(ModeValue::text("advanced", "{}"))));
+        assert!(!condition.check(&Value::Mode(ModeValue::text("simple", "{}"))));
+        assert!(!condition.check(&Value::string("advanced")));
+    }
+
     #[test]
     fn test_deref_behavior() {
         let param_value = ParameterValue::new(Value::string("test"));
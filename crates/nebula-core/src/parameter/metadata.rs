@@ -1,9 +1,11 @@
-use super::ParameterError;
+use super::{ParameterDisplay, ParameterError, ParameterKind, ParameterValidation, ParameterValue};
 use crate::types::ParameterKey;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[non_exhaustive]
 #[builder(
     pattern = "owned",
     setter(strip_option, into),
@@ -26,6 +28,18 @@ pub struct ParameterMetadata {
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hint: Option<String>,
+    /// Rules for showing/hiding the parameter based on other parameters' values
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<ParameterDisplay>,
+    /// Kind of the parameter, which decides the value type it accepts
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ParameterKind>,
+    /// Rules the parameter's value must satisfy
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<ParameterValidation>,
 }
 
 impl ParameterMetadata {
@@ -37,9 +51,49 @@ impl ParameterMetadata {
             description: None,
             placeholder: None,
             hint: None,
+            display: None,
+            kind: None,
+            validation: None,
         }
     }
     pub fn builder() -> ParameterMetadataBuilder {
         ParameterMetadataBuilder::default()
     }
+
+    /// Checks whether the parameter should be shown given all current values
+    ///
+    /// Parameters without display rules are always visible.
+    pub fn is_visible(&self, all_values: &HashMap<ParameterKey, ParameterValue>) -> bool {
+        self.display.as_ref().is_none_or(|display| display.should_display(all_values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameter::ParameterDisplayBuilder;
+    use crate::value::Value;
+
+    #[test]
+    fn test_is_visible() {
+        let mode = ParameterKey::new("mode").unwrap();
+        let mut metadata = ParameterMetadata::new(ParameterKey::new("timeout").unwrap(), "Timeout");
+
+        let mut values = HashMap::new();
+        values.insert(mode.clone(), ParameterValue::new(Value::string("advanced")));
+        assert!(metadata.is_visible(&values));
+
+        metadata.display = Some(
+            ParameterDisplayBuilder::new()
+                .show_when_equals(mode.clone(), "advanced")
+                .build(),
+        );
+        assert!(metadata.is_visible(&values));
+
+        values.insert(mode.clone(), ParameterValue::new(Value::string("simple")));
+        assert!(!metadata.is_visible(&values));
+
+        values.remove(&mode);
+        assert!(!metadata.is_visible(&values));
+    }
 }
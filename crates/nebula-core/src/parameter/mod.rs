@@ -8,6 +8,7 @@ mod parameter;
 mod validation;
 mod value;
 
+pub use collection::ParameterCollection;
 pub use display::*;
 pub use error::*;
 pub use kind::*;
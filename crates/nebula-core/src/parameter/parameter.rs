@@ -34,7 +34,7 @@ pub trait Parameter: TypedParameter + DynClone + Downcast + Any + Debug + Send +
     }
 
     fn display_conditions(&self) -> Option<&ParameterDisplay> {
-        None
+        self.metadata().display.as_ref()
     }
 
     fn validate(&self) -> Result<(), ValidationError> {
@@ -51,9 +51,8 @@ pub trait Parameter: TypedParameter + DynClone + Downcast + Any + Debug + Send +
             return false;
         }
 
-
-
-        true // По умолчанию показываем
+        self.display_conditions()
+            .is_none_or(|display| display.should_display(values))
     }
 }
 
@@ -1,10 +1,13 @@
-use crate::parameter::ParameterValue;
+use crate::parameter::{ParameterKind, ParameterMetadata, ParameterValue};
 use crate::types::ParameterKey;
-use crate::value::{ComparisonResult, Value, ValueComparison, ValueError};
+use crate::value::{
+    ArrayValue, ComparisonResult, LengthUnit, ObjectValue, Value, ValueComparison, ValueError,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::Debug;
-use std::sync::Arc;
+use std::ops::RangeBounds;
+use std::sync::{Arc, OnceLock, RwLock};
 use thiserror::Error;
 
 /// Errors that can occur during validation
@@ -58,6 +61,10 @@ pub enum ValidationError {
         source: ValueError,
     },
 
+    /// Validation rule is misconfigured (e.g. a zero divisor)
+    #[error("Field '{field}' has an invalid validation rule: {reason}")]
+    InvalidRule { field: ParameterKey, reason: String },
+
     /// Custom validation error
     #[error("Field '{field}' custom validation failed: {message}")]
     Custom {
@@ -68,6 +75,76 @@ pub enum ValidationError {
     /// Missing required field
     #[error("Required field '{field}' is missing")]
     MissingField { field: ParameterKey },
+
+    /// Value has a key the schema does not declare (strict schema validation)
+    #[error("Field '{key}' is not declared in the schema")]
+    UnknownField { key: String },
+}
+
+impl ValidationError {
+    /// Returns the field that failed, if the error concerns a single field
+    pub fn field(&self) -> Option<&ParameterKey> {
+        match self {
+            Self::ComparisonFailed { field, .. }
+            | Self::StringLengthFailed { field, .. }
+            | Self::CrossFieldFailed { field, .. }
+            | Self::ConditionalRequired { field, .. }
+            | Self::ValueError { field, .. }
+            | Self::InvalidRule { field, .. }
+            | Self::Custom { field, .. }
+            | Self::MissingField { field } => Some(field),
+            Self::GroupValidationFailed { .. } | Self::UnknownField { .. } => None,
+        }
+    }
+
+    /// Returns the error category, e.g. for picking a message template
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::ComparisonFailed { .. } => "comparison",
+            Self::StringLengthFailed { .. } => "string_length",
+            Self::CrossFieldFailed { .. } => "cross_field",
+            Self::ConditionalRequired { .. } => "conditional_required",
+            Self::GroupValidationFailed { .. } => "group",
+            Self::ValueError { .. } => "value",
+            Self::InvalidRule { .. } => "invalid_rule",
+            Self::Custom { .. } => "custom",
+            Self::MissingField { .. } => "missing_field",
+            Self::UnknownField { .. } => "unknown_field",
+        }
+    }
+}
+
+/// A validation failure together with its user-facing message
+#[derive(Debug, Error, Clone)]
+#[error("{message}")]
+pub struct ResolvedValidationError {
+    /// The structured error
+    #[source]
+    pub error: ValidationError,
+    /// Message produced by the message resolver, or the error's own text
+    pub message: String,
+}
+
+/// Maps structured validation errors to user-facing (e.g. localized) messages
+#[derive(Clone)]
+pub struct MessageResolver(Arc<dyn Fn(&ValidationError) -> String + Send + Sync>);
+
+impl MessageResolver {
+    /// Wraps a resolver function
+    pub fn new(resolver: Arc<dyn Fn(&ValidationError) -> String + Send + Sync>) -> Self {
+        Self(resolver)
+    }
+
+    /// Produces the message for an error
+    pub fn resolve(&self, error: &ValidationError) -> String {
+        (self.0)(error)
+    }
+}
+
+impl Debug for MessageResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MessageResolver")
+    }
 }
 
 /// Custom validator function type
@@ -87,6 +164,43 @@ impl Debug for ValidatorFn {
     }
 }
 
+/// Process-wide validators that [`ValidationCondition::NamedCustom`] resolves by name
+static VALIDATOR_REGISTRY: OnceLock<RwLock<HashMap<String, ValidatorFn>>> = OnceLock::new();
+
+fn validator_registry() -> &'static RwLock<HashMap<String, ValidatorFn>> {
+    VALIDATOR_REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers a validator under `name`, replacing any previous registration
+///
+/// Conditions created with [`ValidationCondition::named_custom`] serialize as
+/// this name and look the function up when they are validated.
+pub fn register_validator<F>(name: impl Into<String>, validator: F)
+where
+    F: Fn(
+            &ParameterValue,
+            &ParameterKey,
+            &HashMap<ParameterKey, ParameterValue>,
+        ) -> Result<(), ValidationError>
+        + Send
+        + Sync
+        + 'static,
+{
+    validator_registry()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(name.into(), Arc::new(validator));
+}
+
+/// Returns the validator registered under `name`, if any
+pub fn registered_validator(name: &str) -> Option<ValidatorFn> {
+    validator_registry()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(name)
+        .cloned()
+}
+
 /// Validation conditions with full cross-field support
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ValidationCondition {
@@ -108,9 +222,21 @@ pub enum ValidationCondition {
     /// Value is NOT in the list of specified values
     NotIn(Vec<Value>),
     /// Value is empty (empty string, empty array, null)
+    ///
+    /// Unlike `Value::is_truthy`, `0` and `false` are not considered empty.
     IsEmpty,
     /// Value is NOT empty
     IsNotEmpty,
+    /// Value is null, i.e. was not submitted
+    IsNull,
+    /// Value is not null; an empty string passes
+    IsNotNull,
+    /// Value is a string containing only whitespace (or nothing)
+    ///
+    /// Non-string values, including null, are not blank.
+    IsBlank,
+    /// Value is not a whitespace-only string
+    IsNotBlank,
     /// String contains the specified substring
     Contains(Value),
     /// String starts with the specified prefix
@@ -121,17 +247,32 @@ pub enum ValidationCondition {
     Regex(String),
 
     // String length constraints
-    /// String has minimum length
+    /// String has minimum length in characters
     MinLength(usize),
-    /// String has maximum length
+    /// String has maximum length in characters
     MaxLength(usize),
-    /// String length is between min and max (inclusive)
+    /// String length in characters is between min and max (inclusive)
     LengthBetween { min: usize, max: usize },
 
+    // String length constraints in an explicit unit
+    //
+    // Use `LengthUnit::Bytes` for the UTF-8 encoded size and
+    // `LengthUnit::Graphemes` to count what users see as characters, e.g. an
+    // emoji sequence or a letter with a combining accent.
+    /// String has minimum length in `unit`
+    MinLengthIn { min: usize, unit: LengthUnit },
+    /// String has maximum length in `unit`
+    MaxLengthIn { max: usize, unit: LengthUnit },
+    /// String length in `unit` is between min and max (inclusive)
+    LengthBetweenIn { min: usize, max: usize, unit: LengthUnit },
+
     // Numeric range constraints
     /// Numeric value is between min and max (inclusive)
     Between { min: Value, max: Value },
 
+    /// Numeric value is a multiple of the specified step
+    MultipleOf(Value),
+
     // Cross-field validations
     /// Field value equals another field's value
     EqualsField(ParameterKey),
@@ -162,6 +303,12 @@ pub enum ValidationCondition {
         field: ParameterKey,
         condition: Box<ValidationCondition>,
     },
+    /// Rules that only apply if another field meets a condition
+    When {
+        field: ParameterKey,
+        condition: Box<ValidationCondition>,
+        then: Box<ValidationCondition>,
+    },
 
     // Group validations
     /// At least one of the specified fields must be non-empty
@@ -185,6 +332,8 @@ pub enum ValidationCondition {
     /// Custom validator function (not serializable)
     #[serde(skip)]
     Custom(ValidatorFn),
+    /// Custom validator looked up by name via [`register_validator`] (serializable)
+    NamedCustom(String),
 }
 
 impl ValidationCondition {
@@ -341,6 +490,25 @@ impl ValidationCondition {
                 }
             }
 
+            Self::IsNull | Self::IsNotNull | Self::IsBlank | Self::IsNotBlank => {
+                let (operator, holds, description) = match self {
+                    Self::IsNull => ("is_null", value.is_null(), "null value"),
+                    Self::IsNotNull => ("is_not_null", !value.is_null(), "non-null value"),
+                    Self::IsBlank => ("is_blank", is_blank(value), "blank string"),
+                    _ => ("is_not_blank", !is_blank(value), "non-blank value"),
+                };
+                if holds {
+                    Ok(())
+                } else {
+                    Err(ValidationError::ComparisonFailed {
+                        field: field.clone(),
+                        operator: operator.to_string(),
+                        expected: description.to_string(),
+                        actual: ValueComparison::format_for_display(value),
+                    })
+                }
+            }
+
             Self::Contains(substring) => match ValueComparison::contains(value, substring) {
                 ComparisonResult::True => Ok(()),
                 ComparisonResult::False => Err(ValidationError::ComparisonFailed {
@@ -407,57 +575,33 @@ impl ValidationCondition {
             },
 
             // String length validations
-            Self::MinLength(min) => match ValueComparison::min_length(value, *min) {
-                ComparisonResult::True => Ok(()),
-                ComparisonResult::False => {
-                    let actual_len = value.as_string().map(|s| s.len()).unwrap_or(0);
-                    Err(ValidationError::StringLengthFailed {
-                        field: field.clone(),
-                        constraint: format!("at least {}", min),
-                        actual: actual_len,
-                    })
-                }
-                ComparisonResult::Error(e) => Err(ValidationError::ValueError {
-                    field: field.clone(),
-                    source: e,
-                }),
+            Self::MinLength(min) => check_length(value, field, LengthUnit::Chars, *min.., || {
+                format!("at least {}", min)
+            }),
+
+            Self::MaxLength(max) => check_length(value, field, LengthUnit::Chars, ..=*max, || {
+                format!("at most {}", max)
+            }),
+
+            Self::LengthBetween { min, max } => {
+                check_length(value, field, LengthUnit::Chars, *min..=*max, || {
+                    format!("between {} and {}", min, max)
+                })
             },
 
-            Self::MaxLength(max) => match ValueComparison::max_length(value, *max) {
-                ComparisonResult::True => Ok(()),
-                ComparisonResult::False => {
-                    let actual_len = value.as_string().map(|s| s.len()).unwrap_or(0);
-                    Err(ValidationError::StringLengthFailed {
-                        field: field.clone(),
-                        constraint: format!("at most {}", max),
-                        actual: actual_len,
-                    })
-                }
-                ComparisonResult::Error(e) => Err(ValidationError::ValueError {
-                    field: field.clone(),
-                    source: e,
-                }),
+            Self::MinLengthIn { min, unit } => {
+                check_length(value, field, *unit, *min.., || format!("at least {} {}", min, unit))
             },
 
-            Self::LengthBetween { min, max } => {
-                if let Some(text) = value.as_string() {
-                    let len = text.len();
-                    if len >= *min && len <= *max {
-                        Ok(())
-                    } else {
-                        Err(ValidationError::StringLengthFailed {
-                            field: field.clone(),
-                            constraint: format!("between {} and {}", min, max),
-                            actual: len,
-                        })
-                    }
-                } else {
-                    Err(ValidationError::ValueError {
-                        field: field.clone(),
-                        source: ValueError::type_conversion(value.type_name(), "string"),
-                    })
-                }
-            }
+            Self::MaxLengthIn { max, unit } => {
+                check_length(value, field, *unit, ..=*max, || format!("at most {} {}", max, unit))
+            },
+
+            Self::LengthBetweenIn { min, max, unit } => {
+                check_length(value, field, *unit, *min..=*max, || {
+                    format!("between {} and {} {}", min, max, unit)
+                })
+            },
 
             Self::Between { min, max } => match ValueComparison::between(value, min, max) {
                 ComparisonResult::True => Ok(()),
@@ -477,6 +621,44 @@ impl ValidationCondition {
                 }),
             },
 
+            Self::MultipleOf(step) => {
+                let step_number = match step.as_number().map(|n| n.as_f64()) {
+                    Some(n) if n != 0.0 && n.is_finite() => n,
+                    _ => {
+                        return Err(ValidationError::InvalidRule {
+                            field: field.clone(),
+                            reason: format!(
+                                "multiple_of step must be a non-zero number, got {}",
+                                ValueComparison::format_for_display(step)
+                            ),
+                        })
+                    }
+                };
+
+                let Some(number) = value.as_number().map(|n| n.as_f64()) else {
+                    return Err(ValidationError::ValueError {
+                        field: field.clone(),
+                        source: ValueError::type_conversion(value.type_name(), "number"),
+                    });
+                };
+
+                // Allow for float rounding, e.g. 0.75 / 0.25
+                let quotient = number / step_number;
+                if (quotient - quotient.round()).abs() <= 1e-9 * quotient.abs().max(1.0) {
+                    Ok(())
+                } else {
+                    Err(ValidationError::ComparisonFailed {
+                        field: field.clone(),
+                        operator: "multiple_of".to_string(),
+                        expected: format!(
+                            "multiple of {}",
+                            ValueComparison::format_for_display(step)
+                        ),
+                        actual: ValueComparison::format_for_display(value),
+                    })
+                }
+            }
+
             // Cross-field validations
             Self::EqualsField(target_field) => {
                 if let Some(target_value) = all_values.get(target_field) {
@@ -694,6 +876,21 @@ impl ValidationCondition {
                 }
             }
 
+            Self::When {
+                field: condition_field,
+                condition,
+                then,
+            } => match all_values.get(condition_field) {
+                Some(condition_value)
+                    if condition
+                        .validate(condition_value, condition_field, all_values)
+                        .is_ok() =>
+                {
+                    then.validate(value, field, all_values)
+                }
+                _ => Ok(()), // Guard not met or field missing, rules don't apply
+            },
+
             // Group validations
             Self::OneOf(fields) => {
                 let non_empty_count = fields
@@ -806,6 +1003,120 @@ impl ValidationCondition {
 
             // Custom validation
             Self::Custom(validator) => validator(value, field, all_values),
+
+            Self::NamedCustom(name) => match registered_validator(name) {
+                Some(validator) => validator(value, field, all_values),
+                None => Err(ValidationError::InvalidRule {
+                    field: field.clone(),
+                    reason: format!("no validator registered under the name '{}'", name),
+                }),
+            },
+        }
+    }
+}
+
+/// Checks that a string value's length in `unit` lies in `range`;
+/// non-strings are a type error
+fn check_length(
+    value: &ParameterValue,
+    field: &ParameterKey,
+    unit: LengthUnit,
+    range: impl RangeBounds<usize>,
+    constraint: impl FnOnce() -> String,
+) -> Result<(), ValidationError> {
+    let Some(text) = value.as_string() else {
+        return Err(ValidationError::ValueError {
+            field: field.clone(),
+            source: ValueError::type_conversion(value.type_name(), "string"),
+        });
+    };
+    let len = unit.count(text);
+    if range.contains(&len) {
+        Ok(())
+    } else {
+        Err(ValidationError::StringLengthFailed {
+            field: field.clone(),
+            constraint: constraint(),
+            actual: len,
+        })
+    }
+}
+
+/// Outcome of a condition and each of its sub-conditions, as produced by
+/// [`ValidationCondition::explain`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationTree {
+    /// The condition this node evaluated
+    pub condition: ValidationCondition,
+    /// The error if the condition failed, `None` if it passed
+    pub error: Option<ValidationError>,
+    /// Outcomes of the operands of `And`, `Or` and `Not`; empty for leaves
+    pub children: Vec<ValidationTree>,
+}
+
+impl ValidationTree {
+    /// Whether the condition at this node passed
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+impl ValidationCondition {
+    /// Evaluates the condition and every sub-condition, recording each outcome
+    ///
+    /// Unlike [`validate`](Self::validate), logical operators do not
+    /// short-circuit, so a failing `Or` reports why each branch failed.
+    pub fn explain(
+        &self,
+        value: &ParameterValue,
+        field: &ParameterKey,
+        all_values: &HashMap<ParameterKey, ParameterValue>,
+    ) -> ValidationTree {
+        let explain_all = |conditions: &[ValidationCondition]| -> Vec<ValidationTree> {
+            conditions
+                .iter()
+                .map(|condition| condition.explain(value, field, all_values))
+                .collect()
+        };
+
+        let (error, children) = match self {
+            Self::And(conditions) => {
+                let children = explain_all(conditions);
+                let error = children.iter().find_map(|child| child.error.clone());
+                (error, children)
+            }
+
+            Self::Or(conditions) => {
+                let children = explain_all(conditions);
+                let error = if children.iter().any(ValidationTree::passed) {
+                    None
+                } else {
+                    let errors: Vec<_> =
+                        children.iter().filter_map(|child| child.error.clone()).collect();
+                    Some(ValidationError::Custom {
+                        field: field.clone(),
+                        message: format!("All OR conditions failed: {:?}", errors),
+                    })
+                };
+                (error, children)
+            }
+
+            Self::Not(condition) => {
+                let child = condition.explain(value, field, all_values);
+                let error = child.passed().then(|| ValidationError::Custom {
+                    field: field.clone(),
+                    message: "NOT condition failed: inner condition passed".to_string(),
+                });
+                (error, vec![child])
+            }
+
+            _ => (self.validate(value, field, all_values).err(), Vec::new()),
+        };
+
+        ValidationTree {
+            condition: self.clone(),
+            error,
+            children,
         }
     }
 }
@@ -862,6 +1173,26 @@ impl ValidationCondition {
         Self::IsNotEmpty
     }
 
+    /// Creates an "is null" condition
+    pub fn is_null() -> Self {
+        Self::IsNull
+    }
+
+    /// Creates an "is not null" condition
+    pub fn is_not_null() -> Self {
+        Self::IsNotNull
+    }
+
+    /// Creates an "is blank" condition
+    pub fn is_blank() -> Self {
+        Self::IsBlank
+    }
+
+    /// Creates an "is not blank" condition
+    pub fn is_not_blank() -> Self {
+        Self::IsNotBlank
+    }
+
     /// Creates a "contains" condition
     pub fn contains<T: Into<Value>>(substring: T) -> Self {
         Self::Contains(substring.into())
@@ -882,21 +1213,36 @@ impl ValidationCondition {
         Self::Regex(pattern.into())
     }
 
-    /// Creates a minimum length condition
+    /// Creates a minimum length condition, counted in characters
     pub fn min_length(length: usize) -> Self {
         Self::MinLength(length)
     }
 
-    /// Creates a maximum length condition
+    /// Creates a minimum length condition counted in `unit`
+    pub fn min_length_in(length: usize, unit: LengthUnit) -> Self {
+        Self::MinLengthIn { min: length, unit }
+    }
+
+    /// Creates a maximum length condition, counted in characters
     pub fn max_length(length: usize) -> Self {
         Self::MaxLength(length)
     }
 
-    /// Creates a length range condition
+    /// Creates a maximum length condition counted in `unit`
+    pub fn max_length_in(length: usize, unit: LengthUnit) -> Self {
+        Self::MaxLengthIn { max: length, unit }
+    }
+
+    /// Creates a length range condition, counted in characters
     pub fn length_between(min: usize, max: usize) -> Self {
         Self::LengthBetween { min, max }
     }
 
+    /// Creates a length range condition counted in `unit`
+    pub fn length_between_in(min: usize, max: usize, unit: LengthUnit) -> Self {
+        Self::LengthBetweenIn { min, max, unit }
+    }
+
     /// Creates a numeric range condition
     pub fn between<T: Into<Value>, U: Into<Value>>(min: T, max: U) -> Self {
         Self::Between {
@@ -905,6 +1251,11 @@ impl ValidationCondition {
         }
     }
 
+    /// Creates a "multiple of" (step) condition
+    pub fn multiple_of<T: Into<Value>>(step: T) -> Self {
+        Self::MultipleOf(step.into())
+    }
+
     /// Creates an "equals field" condition
     pub fn equals_field(field: ParameterKey) -> Self {
         Self::EqualsField(field)
@@ -959,6 +1310,19 @@ impl ValidationCondition {
         }
     }
 
+    /// Creates a condition that applies `then` only if `field` meets `condition`
+    pub fn when(
+        field: ParameterKey,
+        condition: ValidationCondition,
+        then: ValidationCondition,
+    ) -> Self {
+        Self::When {
+            field,
+            condition: Box::new(condition),
+            then: Box::new(then),
+        }
+    }
+
     /// Creates a "one of" group condition
     pub fn one_of(fields: Vec<ParameterKey>) -> Self {
         Self::OneOf(fields)
@@ -1008,6 +1372,11 @@ impl ValidationCondition {
     {
         Self::Custom(Arc::new(validator))
     }
+
+    /// Creates a condition that runs the validator registered under `name`
+    pub fn named_custom(name: impl Into<String>) -> Self {
+        Self::NamedCustom(name.into())
+    }
 }
 
 /// Parameter validation container
@@ -1017,6 +1386,17 @@ impl ValidationCondition {
 pub struct ParameterValidation {
     /// List of validation rules to apply
     rules: Vec<ValidationCondition>,
+    /// Turns failures into user-facing messages
+    #[serde(skip)]
+    message_resolver: Option<MessageResolver>,
+}
+
+/// Validations are equal when their rules are; message resolvers are not
+/// compared
+impl PartialEq for ParameterValidation {
+    fn eq(&self, other: &Self) -> bool {
+        self.rules == other.rules
+    }
 }
 
 impl ParameterValidation {
@@ -1027,7 +1407,10 @@ impl ParameterValidation {
 
     /// Creates a validation container from a list of rules
     pub fn from_rules(rules: Vec<ValidationCondition>) -> Self {
-        Self { rules }
+        Self {
+            rules,
+            message_resolver: None,
+        }
     }
 
     /// Creates a new validation builder
@@ -1086,6 +1469,37 @@ impl ParameterValidation {
         Ok(())
     }
 
+    /// Validates like [`validate`](Self::validate), pairing a failure with its
+    /// user-facing message
+    pub fn validate_with_message(
+        &self,
+        value: &ParameterValue,
+        field: &ParameterKey,
+        all_values: &HashMap<ParameterKey, ParameterValue>,
+    ) -> Result<(), ResolvedValidationError> {
+        self.validate(value, field, all_values).map_err(|error| ResolvedValidationError {
+            message: self.message_for(&error),
+            error,
+        })
+    }
+
+    /// Returns the user-facing message for an error
+    ///
+    /// Uses the configured message resolver, falling back to the error's
+    /// `Display` text.
+    pub fn message_for(&self, error: &ValidationError) -> String {
+        match &self.message_resolver {
+            Some(resolver) => resolver.resolve(error),
+            None => error.to_string(),
+        }
+    }
+
+    /// Sets the resolver used by [`message_for`](Self::message_for)
+    pub fn set_message_resolver(&mut self, resolver: MessageResolver) -> &mut Self {
+        self.message_resolver = Some(resolver);
+        self
+    }
+
     /// Validates and collects all validation errors
     ///
     /// Unlike `validate()`, this method doesn't stop at the first error
@@ -1111,6 +1525,39 @@ impl ParameterValidation {
         }
     }
 
+    /// Validates every element of an array against these rules
+    ///
+    /// Returns one result per element, paired with its index, so callers can
+    /// report which rows failed. Elements are validated without sibling
+    /// values, so cross-field rules see an empty value map.
+    pub fn validate_each(
+        &self,
+        values: &ArrayValue,
+        field: &ParameterKey,
+    ) -> Vec<(usize, Result<(), ValidationError>)> {
+        let no_values = HashMap::new();
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let value = ParameterValue::new(value.clone());
+                (index, self.validate(&value, field, &no_values))
+            })
+            .collect()
+    }
+
+    /// Like [`validate_each`](Self::validate_each), but keeps only the failures
+    pub fn validate_each_failures(
+        &self,
+        values: &ArrayValue,
+        field: &ParameterKey,
+    ) -> Vec<(usize, ValidationError)> {
+        self.validate_each(values, field)
+            .into_iter()
+            .filter_map(|(index, result)| result.err().map(|error| (index, error)))
+            .collect()
+    }
+
     /// Checks if the validation contains any cross-field rules
     pub fn has_cross_field_rules(&self) -> bool {
         self.rules.iter().any(|rule| rule.is_cross_field())
@@ -1126,6 +1573,225 @@ impl ParameterValidation {
         fields.dedup();
         fields
     }
+
+    /// Orders fields so that every field comes after the fields its
+    /// cross-field rules depend on
+    ///
+    /// Only keys of `validations` are ordered; dependencies on fields without
+    /// an entry are ignored. Ties are broken by key order, so the result is
+    /// deterministic. If the dependencies are circular, returns the fields
+    /// that lie on a cycle, sorted.
+    pub fn validation_order(
+        validations: &HashMap<ParameterKey, ParameterValidation>,
+    ) -> Result<Vec<ParameterKey>, Vec<ParameterKey>> {
+        // Edges point from a dependency to the fields that depend on it
+        let mut dependents: BTreeMap<&ParameterKey, BTreeSet<&ParameterKey>> = BTreeMap::new();
+        let mut pending: BTreeMap<&ParameterKey, usize> = BTreeMap::new();
+
+        for (field, validation) in validations {
+            let dependencies = validation.dependent_fields();
+            let mut count = 0;
+            for dependency in &dependencies {
+                if let Some((dependency, _)) = validations.get_key_value(dependency) {
+                    dependents.entry(dependency).or_default().insert(field);
+                    count += 1;
+                }
+            }
+            pending.insert(field, count);
+        }
+
+        let mut ready: BTreeSet<&ParameterKey> =
+            pending.iter().filter(|(_, count)| **count == 0).map(|(field, _)| *field).collect();
+        let mut order = Vec::with_capacity(validations.len());
+
+        while let Some(field) = ready.pop_first() {
+            order.push(field.clone());
+            for dependent in dependents.get(field).into_iter().flatten() {
+                let count = pending.get_mut(dependent).expect("dependent is a validated field");
+                *count -= 1;
+                if *count == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+
+        if order.len() == validations.len() {
+            return Ok(order);
+        }
+
+        // Fields left over are on a cycle or downstream of one; keep only
+        // those that can reach themselves
+        let blocked: BTreeSet<&ParameterKey> =
+            pending.iter().filter(|(_, count)| **count > 0).map(|(field, _)| *field).collect();
+        let on_cycle = |start: &ParameterKey| {
+            let mut stack = vec![start];
+            let mut seen = BTreeSet::new();
+            while let Some(field) = stack.pop() {
+                for next in dependents.get(field).into_iter().flatten() {
+                    if *next == start {
+                        return true;
+                    }
+                    if blocked.contains(next) && seen.insert(*next) {
+                        stack.push(next);
+                    }
+                }
+            }
+            false
+        };
+
+        Err(blocked.iter().filter(|field| on_cycle(field)).map(|field| (*field).clone()).collect())
+    }
+}
+
+/// Validates a whole object against a parameter schema
+///
+/// Collects every failure rather than stopping at the first:
+/// - required parameters that are missing or null
+/// - values whose type does not match the parameter kind, after coercing
+///   numeric strings for numbers, `"true"`/`"false"` for checkboxes and
+///   single values for multi-selects
+/// - each parameter's validation rules, run against the full (coerced) map
+///   so cross-field rules see their sibling values
+///
+/// Absent optional parameters only run their presence rules (`RequiredIf`,
+/// `RequiredUnless` and the group rules), against a null value. Keys the
+/// schema does not declare are ignored; use [`validate_against_schema_strict`]
+/// to report them.
+pub fn validate_against_schema(
+    values: &ObjectValue,
+    schema: &[ParameterMetadata],
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    let mut all_values = HashMap::new();
+
+    for (key, value) in values.iter() {
+        if let Ok(key) = ParameterKey::new(key) {
+            all_values.insert(key, ParameterValue::new(value.clone()));
+        }
+    }
+
+    // Coerce every value first so cross-field rules compare coerced values
+    let mut type_errors = Vec::new();
+    for metadata in schema {
+        let (Some(kind), Some(value)) = (metadata.kind, all_values.get_mut(&metadata.key)) else {
+            continue;
+        };
+        if value.is_null() {
+            continue;
+        }
+        match coerce_to_kind(value, kind) {
+            Some(coerced) => value.set_value(coerced),
+            None => type_errors.push(ValidationError::ValueError {
+                field: metadata.key.clone(),
+                source: ValueError::type_conversion(value.type_name(), kind.as_str()),
+            }),
+        }
+    }
+
+    for metadata in schema {
+        if metadata.kind.is_some_and(|kind| !kind.has_value()) {
+            continue;
+        }
+        if let Some(index) = type_errors.iter().position(|e| e.field() == Some(&metadata.key)) {
+            errors.push(type_errors.remove(index));
+            continue;
+        }
+
+        let present = all_values.get(&metadata.key).filter(|value| !value.is_null());
+        if present.is_none() && metadata.required {
+            errors.push(ValidationError::MissingField { field: metadata.key.clone() });
+            continue;
+        }
+        let Some(validation) = &metadata.validation else {
+            continue;
+        };
+
+        match present {
+            Some(value) => {
+                if let Err(failures) = validation.validate_all(value, &metadata.key, &all_values)
+                {
+                    errors.extend(failures);
+                }
+            }
+            None => {
+                let null = ParameterValue::new(Value::null());
+                for rule in validation.rules().iter().filter(|rule| rule.is_presence_rule()) {
+                    if let Err(error) = rule.validate(&null, &metadata.key, &all_values) {
+                        errors.push(error);
+                    }
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Like [`validate_against_schema`], but also reports every key of `values`
+/// the schema does not declare as [`ValidationError::UnknownField`]
+pub fn validate_against_schema_strict(
+    values: &ObjectValue,
+    schema: &[ParameterMetadata],
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Vec<ValidationError> = values
+        .keys()
+        .filter(|key| !schema.iter().any(|metadata| metadata.key.as_str() == key.as_str()))
+        .map(|key| ValidationError::UnknownField { key: key.clone() })
+        .collect();
+
+    if let Err(schema_errors) = validate_against_schema(values, schema) {
+        errors.extend(schema_errors);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Returns `value` converted to the type `kind` accepts, or `None` if it
+/// cannot be
+///
+/// Kinds without a fixed value type accept anything unchanged.
+fn coerce_to_kind(value: &Value, kind: ParameterKind) -> Option<Value> {
+    match kind {
+        ParameterKind::Text
+        | ParameterKind::Textarea
+        | ParameterKind::Secret
+        | ParameterKind::Color => value.is_string().then(|| value.clone()),
+        // Integer strings stay integers, which keeps them exact beyond 2^53
+        ParameterKind::Number => match value.as_string().map(str::trim) {
+            Some(text) => match text.parse::<i64>() {
+                Ok(integer) => Some(Value::number(integer)),
+                Err(_) => text.parse::<f64>().ok().map(Value::number),
+            },
+            None => value.is_number().then(|| value.clone()),
+        },
+        ParameterKind::Checkbox => match value.as_string() {
+            Some("true") => Some(Value::boolean(true)),
+            Some("false") => Some(Value::boolean(false)),
+            _ => value.is_boolean().then(|| value.clone()),
+        },
+        ParameterKind::Select | ParameterKind::Radio => {
+            let scalar = value.is_string() || value.is_number() || value.is_boolean();
+            scalar.then(|| value.clone())
+        }
+        ParameterKind::MultiSelect => Some(Value::Array(value.coerce_to_array())),
+        ParameterKind::DateTime | ParameterKind::Date | ParameterKind::Time => {
+            (value.is_datetime() || value.is_string()).then(|| value.clone())
+        }
+        _ => Some(value.clone()),
+    }
+}
+
+/// Checks whether a value is a string containing only whitespace
+fn is_blank(value: &Value) -> bool {
+    value.as_string().is_some_and(|text| text.trim().is_empty())
 }
 
 /// Helper methods for ValidationCondition
@@ -1142,6 +1808,7 @@ impl ValidationCondition {
             | Self::RequiredIf { .. }
             | Self::RequiredUnless { .. }
             | Self::ForbiddenIf { .. }
+            | Self::When { .. }
             | Self::OneOf(_)
             | Self::AllOrNone(_)
             | Self::MutuallyExclusive(_)
@@ -1156,6 +1823,20 @@ impl ValidationCondition {
         }
     }
 
+    /// Checks if this condition constrains whether a field is present rather
+    /// than what its value is
+    pub fn is_presence_rule(&self) -> bool {
+        matches!(
+            self,
+            Self::RequiredIf { .. }
+                | Self::RequiredUnless { .. }
+                | Self::OneOf(_)
+                | Self::AllOrNone(_)
+                | Self::MutuallyExclusive(_)
+                | Self::AllRequired(_)
+        )
+    }
+
     /// Collects all fields that this condition depends on
     pub fn collect_dependent_fields(&self, fields: &mut Vec<ParameterKey>) {
         match self {
@@ -1175,6 +1856,16 @@ impl ValidationCondition {
                 condition.collect_dependent_fields(fields);
             }
 
+            Self::When {
+                field,
+                condition,
+                then,
+            } => {
+                fields.push(field.clone());
+                condition.collect_dependent_fields(fields);
+                then.collect_dependent_fields(fields);
+            }
+
             Self::OneOf(fs)
             | Self::AllOrNone(fs)
             | Self::MutuallyExclusive(fs)
@@ -1215,6 +1906,20 @@ impl ParameterValidationBuilder {
         self
     }
 
+    /// Sets a resolver that maps failures to user-facing messages, e.g. for
+    /// localization
+    ///
+    /// The resolver receives the structured [`ValidationError`]; use
+    /// [`ValidationError::category`], [`ValidationError::field`] and the
+    /// variant's fields to pick a message template.
+    pub fn with_message_resolver(
+        mut self,
+        resolver: Arc<dyn Fn(&ValidationError) -> String + Send + Sync>,
+    ) -> Self {
+        self.validation.set_message_resolver(MessageResolver::new(resolver));
+        self
+    }
+
     /// Adds multiple validation rules
     pub fn with_rules(mut self, rules: Vec<ValidationCondition>) -> Self {
         self.validation.add_rules(rules);
@@ -1228,6 +1933,26 @@ impl ParameterValidationBuilder {
         self.with_rule(ValidationCondition::is_not_empty())
     }
 
+    /// Adds a validation that the value is null (not submitted)
+    pub fn is_null(self) -> Self {
+        self.with_rule(ValidationCondition::is_null())
+    }
+
+    /// Adds a validation that the value was submitted, even if empty
+    pub fn is_not_null(self) -> Self {
+        self.with_rule(ValidationCondition::is_not_null())
+    }
+
+    /// Adds a validation that the value is a whitespace-only string
+    pub fn is_blank(self) -> Self {
+        self.with_rule(ValidationCondition::is_blank())
+    }
+
+    /// Adds a validation that the value is not a whitespace-only string
+    pub fn is_not_blank(self) -> Self {
+        self.with_rule(ValidationCondition::is_not_blank())
+    }
+
     /// Adds an equality validation
     pub fn equals<T: Into<Value>>(self, value: T) -> Self {
         self.with_rule(ValidationCondition::equals(value))
@@ -1248,6 +1973,21 @@ impl ParameterValidationBuilder {
         self.with_rule(ValidationCondition::length_between(min, max))
     }
 
+    /// Adds a minimum length validation counted in `unit`
+    pub fn min_length_in(self, length: usize, unit: LengthUnit) -> Self {
+        self.with_rule(ValidationCondition::min_length_in(length, unit))
+    }
+
+    /// Adds a maximum length validation counted in `unit`
+    pub fn max_length_in(self, length: usize, unit: LengthUnit) -> Self {
+        self.with_rule(ValidationCondition::max_length_in(length, unit))
+    }
+
+    /// Adds a length range validation counted in `unit`
+    pub fn length_between_in(self, min: usize, max: usize, unit: LengthUnit) -> Self {
+        self.with_rule(ValidationCondition::length_between_in(min, max, unit))
+    }
+
     /// Adds a regex validation
     pub fn regex<T: Into<String>>(self, pattern: T) -> Self {
         self.with_rule(ValidationCondition::regex(pattern))
@@ -1258,6 +1998,11 @@ impl ParameterValidationBuilder {
         self.with_rule(ValidationCondition::between(min, max))
     }
 
+    /// Adds a "multiple of" (step) validation
+    pub fn multiple_of<T: Into<Value>>(self, step: T) -> Self {
+        self.with_rule(ValidationCondition::multiple_of(step))
+    }
+
     /// Adds a "greater than" validation
     pub fn greater_than<T: Into<Value>>(self, value: T) -> Self {
         self.with_rule(ValidationCondition::greater_than(value))
@@ -1325,6 +2070,20 @@ impl ParameterValidationBuilder {
         self.with_rule(ValidationCondition::required_if(field, condition))
     }
 
+    /// Starts a group of rules that only apply if `field` meets `condition`;
+    /// finish it with [`ConditionalGroupBuilder::then`]
+    pub fn when(
+        self,
+        field: ParameterKey,
+        condition: ValidationCondition,
+    ) -> ConditionalGroupBuilder {
+        ConditionalGroupBuilder {
+            parent: self,
+            field,
+            condition,
+        }
+    }
+
     /// Adds a "one of" group validation
     pub fn one_of(self, fields: Vec<ParameterKey>) -> Self {
         self.with_rule(ValidationCondition::one_of(fields))
@@ -1355,12 +2114,44 @@ impl ParameterValidationBuilder {
         self.with_rule(ValidationCondition::custom(validator))
     }
 
+    /// Adds a validation that runs the validator registered under `name`
+    pub fn named_custom(self, name: impl Into<String>) -> Self {
+        self.with_rule(ValidationCondition::named_custom(name))
+    }
+
     /// Builds the final ParameterValidation
     pub fn build(self) -> ParameterValidation {
         self.validation
     }
 }
 
+/// Guarded rule group started by [`ParameterValidationBuilder::when`]
+#[derive(Debug)]
+pub struct ConditionalGroupBuilder {
+    parent: ParameterValidationBuilder,
+    field: ParameterKey,
+    condition: ValidationCondition,
+}
+
+impl ConditionalGroupBuilder {
+    /// Adds the rules built by `rules` as a single [`ValidationCondition::When`]
+    /// and returns the parent builder
+    ///
+    /// An empty group adds no rule.
+    pub fn then<F>(self, rules: F) -> ParameterValidationBuilder
+    where
+        F: FnOnce(ParameterValidationBuilder) -> ParameterValidationBuilder,
+    {
+        let group = rules(ParameterValidationBuilder::new()).build();
+        if group.is_empty() {
+            return self.parent;
+        }
+
+        let then = ValidationCondition::and(group.rules().to_vec());
+        self.parent.with_rule(ValidationCondition::when(self.field, self.condition, then))
+    }
+}
+
 /// Common validation patterns
 pub mod validators {
     use super::*;
@@ -1469,7 +2260,7 @@ pub mod validators {
 mod tests {
     use super::*;
     use crate::parameter::ParameterValue;
-    use crate::value::Value;
+    use crate::value::{NumberValue, Value};
 
     #[test]
     fn test_basic_validation() {
@@ -1494,6 +2285,112 @@ mod tests {
         assert!(validation.validate(&empty_value, &field, &values).is_err());
     }
 
+    #[test]
+    fn test_multiple_of_validation() {
+        let field = ParameterKey::new("quantity").unwrap();
+        let values = HashMap::new();
+
+        let packs = ParameterValidation::builder().multiple_of(6).build();
+        let twelve = ParameterValue::new(Value::number(12));
+        assert!(packs.validate(&twelve, &field, &values).is_ok());
+        let thirteen = ParameterValue::new(Value::number(13));
+        assert!(packs.validate(&thirteen, &field, &values).is_err());
+
+        let quarters = ParameterValidation::builder().multiple_of(0.25).build();
+        let valid = ParameterValue::new(Value::number(1.75));
+        assert!(quarters.validate(&valid, &field, &values).is_ok());
+        let invalid = ParameterValue::new(Value::number(1.8));
+        assert!(quarters.validate(&invalid, &field, &values).is_err());
+
+        let zero = ParameterValidation::builder().multiple_of(0).build();
+        assert!(matches!(
+            zero.validate(&twelve, &field, &values),
+            Err(ValidationError::InvalidRule { .. })
+        ));
+    }
+
+    #[test]
+    fn test_message_resolver() {
+        let validation = ParameterValidation::builder()
+            .min_length(5)
+            .with_message_resolver(Arc::new(|error: &ValidationError| match error {
+                ValidationError::StringLengthFailed { constraint, .. } => {
+                    format!("Debe tener {} caracteres", constraint.replace("at least", "al menos"))
+                }
+                other => other.to_string(),
+            }))
+            .build();
+
+        let field = ParameterKey::new("name").unwrap();
+        let values = HashMap::new();
+        let short = ParameterValue::new(Value::string("hola"));
+
+        let error = validation.validate_with_message(&short, &field, &values).unwrap_err();
+        assert_eq!(error.message, "Debe tener al menos 5 caracteres");
+        assert_eq!(error.error.category(), "string_length");
+        assert_eq!(error.error.field(), Some(&field));
+        assert_eq!(error.to_string(), error.message);
+
+        let unresolved = ParameterValidation::builder().min_length(5).build();
+        let error = unresolved.validate_with_message(&short, &field, &values).unwrap_err();
+        assert_eq!(error.message, error.error.to_string());
+
+        let long = ParameterValue::new(Value::string("hola mundo"));
+        assert!(validation.validate_with_message(&long, &field, &values).is_ok());
+    }
+
+    #[test]
+    fn test_explain_or() {
+        let field = ParameterKey::new("code").unwrap();
+        let values = HashMap::new();
+        let condition = ValidationCondition::or(vec![
+            ValidationCondition::min_length(5),
+            ValidationCondition::regex("^[0-9]+$"),
+        ]);
+
+        let value = ParameterValue::new(Value::string("ab"));
+        let tree = condition.explain(&value, &field, &values);
+        assert!(!tree.passed());
+        assert_eq!(tree.children.len(), 2);
+        assert_eq!(tree.children[0].condition, ValidationCondition::MinLength(5));
+        assert!(matches!(
+            tree.children[0].error,
+            Some(ValidationError::StringLengthFailed { actual: 2, .. })
+        ));
+        assert!(!tree.children[1].passed());
+        assert_eq!(tree.error, condition.validate(&value, &field, &values).err());
+
+        let digits = ParameterValue::new(Value::string("42"));
+        let tree = condition.explain(&digits, &field, &values);
+        assert!(tree.passed());
+        assert!(!tree.children[0].passed());
+        assert!(tree.children[1].passed());
+    }
+
+    #[test]
+    fn test_validate_each() {
+        let validation = ParameterValidation::builder()
+            .required()
+            .min_length(3)
+            .build();
+        let field = ParameterKey::new("name").unwrap();
+        let values = ArrayValue::new(vec![
+            Value::string("alice"),
+            Value::string("x"),
+            Value::string("bob"),
+            Value::string(""),
+        ]);
+
+        let results = validation.validate_each(&values, &field);
+        assert_eq!(results.len(), 4);
+        let outcomes: Vec<_> = results.iter().map(|(i, r)| (*i, r.is_ok())).collect();
+        assert_eq!(outcomes, vec![(0, true), (1, false), (2, true), (3, false)]);
+
+        let failures = validation.validate_each_failures(&values, &field);
+        let indices: Vec<_> = failures.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indices, vec![1, 3]);
+    }
+
     #[test]
     fn test_cross_field_validation() {
         let validation = ParameterValidation::builder()
@@ -1522,6 +2419,50 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn test_validation_order() {
+        let key = |name: &str| ParameterKey::new(name).unwrap();
+        let mut validations = HashMap::new();
+        validations.insert(
+            key("confirm"),
+            ParameterValidation::builder().equals_field(key("password")).build(),
+        );
+        validations.insert(
+            key("password"),
+            ParameterValidation::builder()
+                .with_rule(ValidationCondition::not_equals_field(key("username")))
+                .build(),
+        );
+        validations.insert(key("username"), ParameterValidation::builder().min_length(3).build());
+
+        assert_eq!(
+            ParameterValidation::validation_order(&validations),
+            Ok(vec![key("username"), key("password"), key("confirm")])
+        );
+
+        let mut cyclic = HashMap::new();
+        cyclic.insert(
+            key("a"),
+            ParameterValidation::from_rules(vec![ValidationCondition::required_if(
+                key("b"),
+                ValidationCondition::is_not_empty(),
+            )]),
+        );
+        cyclic.insert(
+            key("b"),
+            ParameterValidation::from_rules(vec![ValidationCondition::required_if(
+                key("a"),
+                ValidationCondition::is_not_empty(),
+            )]),
+        );
+        cyclic.insert(key("c"), ParameterValidation::builder().equals_field(key("a")).build());
+
+        assert_eq!(
+            ParameterValidation::validation_order(&cyclic),
+            Err(vec![key("a"), key("b")])
+        );
+    }
+
     #[test]
     fn test_conditional_validation() {
         let validation = ParameterValidation::builder()
@@ -1555,6 +2496,80 @@ mod tests {
         assert!(validation.validate(&empty_email, &field, &values).is_ok());
     }
 
+    #[test]
+    fn test_length_units() {
+        let field = ParameterKey::new("nickname").unwrap();
+        let values = HashMap::new();
+        let family = ["\u{1F468}", "\u{1F469}", "\u{1F467}", "\u{1F466}"].join("\u{200D}");
+        let family = ParameterValue::new(Value::string(family));
+
+        let graphemes =
+            ParameterValidation::builder().max_length_in(1, LengthUnit::Graphemes).build();
+        assert!(graphemes.validate(&family, &field, &values).is_ok());
+
+        let bytes = ParameterValidation::builder().max_length_in(1, LengthUnit::Bytes).build();
+        match bytes.validate(&family, &field, &values) {
+            Err(ValidationError::StringLengthFailed { actual, .. }) => assert_eq!(actual, 25),
+            other => panic!("expected a length failure, got {:?}", other),
+        }
+
+        // The unitless rules count characters: 4 emoji and 3 joiners
+        let chars = ParameterValidation::builder().length_between(7, 7).build();
+        assert!(chars.validate(&family, &field, &values).is_ok());
+        let chars = ParameterValidation::builder().max_length(6).build();
+        match chars.validate(&family, &field, &values) {
+            Err(ValidationError::StringLengthFailed { actual, .. }) => assert_eq!(actual, 7),
+            other => panic!("expected a length failure, got {:?}", other),
+        }
+        let explicit =
+            ParameterValidation::builder().length_between_in(7, 7, LengthUnit::Chars).build();
+        assert!(explicit.validate(&family, &field, &values).is_ok());
+        assert_eq!(ValidationCondition::min_length(3), ValidationCondition::MinLength(3));
+        assert_ne!(
+            ValidationCondition::min_length(3),
+            ValidationCondition::min_length_in(3, LengthUnit::Bytes)
+        );
+    }
+
+    #[test]
+    fn test_when_then_group() {
+        let auth_field = ParameterKey::new("auth_type").unwrap();
+        let validation = ParameterValidation::builder()
+            .when(auth_field.clone(), ValidationCondition::equals("basic"))
+            .then(|rules| rules.required().min_length(8))
+            .max_length(64)
+            .build();
+
+        assert_eq!(validation.rule_count(), 2);
+        assert_eq!(validation.dependent_fields(), vec![auth_field.clone()]);
+
+        let field = ParameterKey::new("password").unwrap();
+        let short = ParameterValue::new(Value::string("short"));
+        let empty = ParameterValue::new(Value::string(""));
+        let mut values = HashMap::new();
+
+        // Guard matches - grouped rules are enforced
+        values.insert(
+            auth_field.clone(),
+            ParameterValue::new(Value::string("basic")),
+        );
+        assert!(validation.validate(&short, &field, &values).is_err());
+        assert!(validation.validate(&empty, &field, &values).is_err());
+        let long = ParameterValue::new(Value::string("long enough"));
+        assert!(validation.validate(&long, &field, &values).is_ok());
+
+        // Guard doesn't match - grouped rules are skipped
+        values.insert(auth_field, ParameterValue::new(Value::string("token")));
+        assert!(validation.validate(&short, &field, &values).is_ok());
+        assert!(validation.validate(&empty, &field, &values).is_ok());
+
+        // Guard field missing - grouped rules are skipped, ungrouped still apply
+        values.clear();
+        assert!(validation.validate(&short, &field, &values).is_ok());
+        let too_long = ParameterValue::new(Value::string("x".repeat(65)));
+        assert!(validation.validate(&too_long, &field, &values).is_err());
+    }
+
     #[test]
     fn test_group_validation() {
         let validation = ParameterValidation::builder()
@@ -1627,4 +2642,160 @@ mod tests {
             .validate(&non_matching_confirm, &confirm_field, &values)
             .is_err());
     }
+
+    #[test]
+    fn test_named_custom_validation() {
+        register_validator("test_is_ok", |value, field, _| {
+            if ValueComparison::equals(value, &Value::string("ok")) {
+                Ok(())
+            } else {
+                Err(ValidationError::Custom {
+                    field: field.clone(),
+                    message: "expected 'ok'".to_string(),
+                })
+            }
+        });
+
+        let validation = ParameterValidation::builder()
+            .required()
+            .named_custom("test_is_ok")
+            .build();
+
+        let json = serde_json::to_string(&validation).unwrap();
+        assert!(json.contains("test_is_ok"));
+        let validation: ParameterValidation = serde_json::from_str(&json).unwrap();
+
+        let field = ParameterKey::new("status").unwrap();
+        let values = HashMap::new();
+
+        let ok = ParameterValue::new(Value::string("ok"));
+        assert!(validation.validate(&ok, &field, &values).is_ok());
+
+        let bad = ParameterValue::new(Value::string("nope"));
+        assert!(matches!(
+            validation.validate(&bad, &field, &values),
+            Err(ValidationError::Custom { .. })
+        ));
+
+        // Unregistered names fail with a descriptive error instead of passing
+        let missing = ValidationCondition::named_custom("test_not_registered");
+        let error = missing.validate(&ok, &field, &values).unwrap_err();
+        assert!(matches!(error, ValidationError::InvalidRule { .. }));
+        assert!(error.to_string().contains("test_not_registered"));
+    }
+
+    #[test]
+    fn test_null_and_blank_validation() {
+        let field = ParameterKey::new("comment").unwrap();
+        let values = HashMap::new();
+        let null = ParameterValue::new(Value::null());
+        let empty = ParameterValue::new(Value::string(""));
+        let spaces = ParameterValue::new(Value::string(" \t "));
+        let text = ParameterValue::new(Value::string("hi"));
+        let passes = |condition: ValidationCondition, value: &ParameterValue| {
+            condition.validate(value, &field, &values).is_ok()
+        };
+
+        // Null is distinguished from an empty string
+        assert!(passes(ValidationCondition::is_null(), &null));
+        assert!(!passes(ValidationCondition::is_null(), &empty));
+        assert!(passes(ValidationCondition::is_not_null(), &empty));
+        assert!(!passes(ValidationCondition::is_not_null(), &null));
+
+        // Whitespace-only strings are blank; null and other strings are not
+        let not_blank = ParameterValidation::builder().is_not_null().is_not_blank().build();
+        assert!(not_blank.validate(&spaces, &field, &values).is_err());
+        assert!(not_blank.validate(&empty, &field, &values).is_err());
+        assert!(not_blank.validate(&null, &field, &values).is_err());
+        assert!(not_blank.validate(&text, &field, &values).is_ok());
+        assert!(passes(ValidationCondition::is_blank(), &spaces));
+        assert!(!passes(ValidationCondition::is_blank(), &null));
+        assert!(!passes(ValidationCondition::is_blank(), &text));
+
+        // IsEmpty still treats null and empty strings alike
+        assert!(passes(ValidationCondition::is_empty(), &null));
+        assert!(passes(ValidationCondition::is_empty(), &empty));
+        assert!(!passes(ValidationCondition::is_empty(), &text));
+    }
+
+    #[test]
+    fn test_coerce_number_strings() {
+        let integer = coerce_to_kind(&Value::string(" 42 "), ParameterKind::Number).unwrap();
+        assert!(matches!(integer, Value::Number(NumberValue::Integer(42))));
+        let big = coerce_to_kind(&Value::string("9007199254740993"), ParameterKind::Number);
+        assert!(matches!(big, Some(Value::Number(NumberValue::Integer(9_007_199_254_740_993)))));
+        let float = coerce_to_kind(&Value::string("2.5"), ParameterKind::Number).unwrap();
+        assert!(matches!(float, Value::Number(NumberValue::Float(_))));
+        assert_eq!(coerce_to_kind(&Value::string("many"), ParameterKind::Number), None);
+    }
+
+    #[test]
+    fn test_validate_against_schema() {
+        let parameter = |key: &str, kind: ParameterKind, required: bool| {
+            let mut metadata = ParameterMetadata::new(ParameterKey::new(key).unwrap(), key);
+            metadata.kind = Some(kind);
+            metadata.required = required;
+            metadata
+        };
+        let mut name = parameter("name", ParameterKind::Text, true);
+        name.validation = Some(ParameterValidation::builder().min_length(3).build());
+        let mut confirm = parameter("confirm_name", ParameterKind::Text, false);
+        confirm.validation = Some(
+            ParameterValidation::builder()
+                .equals_field(ParameterKey::new("name").unwrap())
+                .build(),
+        );
+        let schema = vec![
+            name,
+            parameter("retries", ParameterKind::Number, false),
+            parameter("enabled", ParameterKind::Checkbox, true),
+            confirm,
+        ];
+
+        let object = |fields: &[(&str, Value)]| -> ObjectValue {
+            fields.iter().map(|(k, v)| (k.to_string(), v.clone())).collect()
+        };
+
+        let valid = object(&[
+            ("name", Value::string("alice")),
+            ("retries", Value::string("3")),
+            ("enabled", Value::boolean(true)),
+            ("confirm_name", Value::string("alice")),
+            ("extra", Value::number(1)),
+        ]);
+        assert!(validate_against_schema(&valid, &schema).is_ok());
+
+        let errors = validate_against_schema_strict(&valid, &schema).unwrap_err();
+        assert_eq!(errors, vec![ValidationError::UnknownField { key: "extra".to_string() }]);
+
+        let missing = object(&[("name", Value::string("alice"))]);
+        let errors = validate_against_schema(&missing, &schema).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ValidationError::MissingField { field } if field.as_str() == "enabled"
+        ));
+
+        let mismatched = object(&[
+            ("name", Value::string("alice")),
+            ("retries", Value::string("many")),
+            ("enabled", Value::boolean(false)),
+        ]);
+        let errors = validate_against_schema(&mismatched, &schema).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(&errors[0], ValidationError::ValueError { .. }));
+        assert_eq!(errors[0].field().map(|f| f.as_str()), Some("retries"));
+        assert_eq!(errors[0].category(), "value");
+
+        // Per-field and cross-field rules run against the full map
+        let invalid = object(&[
+            ("name", Value::string("al")),
+            ("enabled", Value::string("true")),
+            ("confirm_name", Value::string("bob")),
+        ]);
+        let errors = validate_against_schema(&invalid, &schema).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(&errors[0], ValidationError::StringLengthFailed { .. }));
+        assert!(matches!(&errors[1], ValidationError::CrossFieldFailed { .. }));
+    }
 }
@@ -1,11 +1,115 @@
 use serde::{Deserialize, Serialize};
 use nebula_value::Value;
+use std::ops::Deref;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
-pub struct ParameterValue(pub Value);
+/// Value of a parameter, tracking whether it was changed since it was loaded
+///
+/// Serializes as the bare value; the change-tracking state is not persisted.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(from = "Value", into = "Value")]
+pub struct ParameterValue {
+    value: Value,
+    original: Value,
+    dirty: bool,
+}
 
 impl ParameterValue {
     pub fn new(value: impl Into<Value>) -> Self {
-        Self(value.into())
+        let value = value.into();
+        Self { original: value.clone(), value, dirty: false }
+    }
+
+    /// Current value
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Value the parameter was created with
+    pub fn original(&self) -> &Value {
+        &self.original
+    }
+
+    /// Replace the value, marking the parameter dirty if it changed
+    pub fn set_value(&mut self, value: Value) {
+        if value != self.value {
+            self.value = value;
+            self.dirty = true;
+        }
+    }
+
+    /// Whether the value changed since creation or the last [`mark_clean`]
+    ///
+    /// [`mark_clean`]: ParameterValue::mark_clean
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Clear the dirty flag, e.g. after the change has been saved
+    ///
+    /// [`original`](ParameterValue::original) is left untouched.
+    pub fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    pub fn into_inner(self) -> Value {
+        self.value
+    }
+}
+
+/// Compares the current values only; the change-tracking state is ignored
+impl PartialEq for ParameterValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Deref for ParameterValue {
+    type Target = Value;
+
+    fn deref(&self) -> &Value {
+        &self.value
+    }
+}
+
+impl From<Value> for ParameterValue {
+    fn from(value: Value) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<ParameterValue> for Value {
+    fn from(value: ParameterValue) -> Self {
+        value.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_tracking() {
+        let mut value = ParameterValue::new(Value::string("draft"));
+        assert!(!value.is_dirty());
+
+        value.set_value(Value::string("draft"));
+        assert!(!value.is_dirty());
+
+        value.set_value(Value::string("published"));
+        assert!(value.is_dirty());
+        assert_eq!(value.value(), &Value::string("published"));
+
+        value.mark_clean();
+        assert!(!value.is_dirty());
+        assert_eq!(value.original(), &Value::string("draft"));
+    }
+
+    #[test]
+    fn test_equality_ignores_tracking() {
+        let mut edited = ParameterValue::new(Value::string("draft"));
+        edited.set_value(Value::string("published"));
+
+        assert!(edited.is_dirty());
+        assert_eq!(edited, ParameterValue::new(Value::string("published")));
     }
 }
@@ -178,8 +178,9 @@ pub fn derive_parameters_impl(input: DeriveInput) -> Result<TokenStream> {
 
     // Генерируем код
     let parameter_collection_impl = generate_parameter_collection(&field_configs)?;
-    let from_values_impl = generate_from_values(struct_name, &field_configs)?;
+    let from_values_impl = generate_from_values(&field_configs)?;
     let to_values_impl = generate_to_values(&field_configs)?;
+    let select_options_impl = generate_select_options(&field_configs);
 
     let nebula_core = crate::nebula_core_path();
 
@@ -190,14 +191,19 @@ pub fn derive_parameters_impl(input: DeriveInput) -> Result<TokenStream> {
             }
 
             pub fn from_values(
-                values: &std::collections::HashMap<#nebula_core::types::Key, #nebula_core::ParameterValue>
+                values: &std::collections::HashMap<#nebula_core::types::ParameterKey, #nebula_core::ParameterValue>
             ) -> Result<Self, #nebula_core::ParameterError> {
                 #from_values_impl
             }
 
-            pub fn to_values(&self) -> std::collections::HashMap<#nebula_core::types::Key, #nebula_core::ParameterValue> {
+            pub fn to_values(&self) -> std::collections::HashMap<#nebula_core::types::ParameterKey, #nebula_core::ParameterValue> {
                 #to_values_impl
             }
+
+            /// Options of the select or multi-select parameter with this key
+            pub fn select_options(key: &str) -> Option<&'static [&'static str]> {
+                #select_options_impl
+            }
         }
     })
 }
@@ -238,7 +244,9 @@ fn parse_attribute(attr: &Attribute, config: &mut FieldConfig) -> Result<()> {
     } else if path.is_ident("textarea") {
         config.param_type = parse_textarea_attribute(attr)?;
     } else if path.is_ident("select") {
-        config.param_type = parse_select_attribute(attr)?;
+        config.param_type = parse_select_attribute(attr, false)?;
+    } else if path.is_ident("multi_select") {
+        config.param_type = parse_select_attribute(attr, true)?;
     } else if path.is_ident("checkbox") {
         config.param_type = parse_checkbox_attribute(attr)?;
     } else if path.is_ident("notice") {
@@ -261,7 +269,7 @@ fn infer_parameter_type(field_type: &syn::Type) -> Result<ParameterTypeConfig> {
     match field_type {
         // Обработка Option<T>
         syn::Type::Path(type_path) if is_option_type(type_path) => {
-            let inner_type = extract_option_inner_type(type_path)?;
+            let inner_type = extract_type_argument(type_path)?;
             infer_parameter_type_for_inner(&inner_type)
         }
 
@@ -280,7 +288,8 @@ fn is_option_type(type_path: &syn::TypePath) -> bool {
         .unwrap_or(false)
 }
 
-fn extract_option_inner_type(type_path: &syn::TypePath) -> Result<syn::Type> {
+/// The single type argument of `Option<T>`, `Vec<T>` and the like
+fn extract_type_argument(type_path: &syn::TypePath) -> Result<syn::Type> {
     let last_segment = type_path
         .path
         .segments
@@ -292,7 +301,7 @@ fn extract_option_inner_type(type_path: &syn::TypePath) -> Result<syn::Type> {
             if args.args.len() != 1 {
                 return Err(syn::Error::new_spanned(
                     args,
-                    "Option must have exactly one type argument",
+                    format!("{} must have exactly one type argument", last_segment.ident),
                 ));
             }
 
@@ -306,7 +315,7 @@ fn extract_option_inner_type(type_path: &syn::TypePath) -> Result<syn::Type> {
         }
         _ => Err(syn::Error::new_spanned(
             last_segment,
-            "Option must have type arguments",
+            format!("{} must have type arguments", last_segment.ident),
         )),
     }
 }
@@ -368,11 +377,58 @@ fn parse_textarea_attribute(_attr: &Attribute) -> Result<ParameterTypeConfig> {
     })
 }
 
-fn parse_select_attribute(_attr: &Attribute) -> Result<ParameterTypeConfig> {
-    Ok(ParameterTypeConfig::Select {
-        options: None,
-        multiple: None,
-    })
+/// Parses `select(options = ["a", "b"])` / `multi_select(options = [...])`
+///
+/// Options must be unique string literals, checked at compile time. A bare
+/// `#[select]` declares a select whose options are supplied at runtime, so
+/// no option list or check is generated for it.
+fn parse_select_attribute(attr: &Attribute, multiple: bool) -> Result<ParameterTypeConfig> {
+    let mut options = None;
+    if matches!(attr.meta, syn::Meta::Path(_)) {
+        return Ok(ParameterTypeConfig::Select { options, multiple: Some(multiple) });
+    }
+
+    attr.parse_nested_meta(|meta| {
+        if !meta.path.is_ident("options") {
+            return Err(meta.error("unknown select attribute, expected `options`"));
+        }
+
+        let list: syn::ExprArray = meta.value()?.parse()?;
+        let mut values: Vec<String> = Vec::with_capacity(list.elems.len());
+        for elem in &list.elems {
+            let option = match elem {
+                syn::Expr::Lit(syn::ExprLit { lit: Lit::Str(lit), .. }) => lit.value(),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        elem,
+                        "select options must be string literals",
+                    ));
+                },
+            };
+            if values.contains(&option) {
+                return Err(syn::Error::new_spanned(
+                    elem,
+                    format!("duplicate select option `{}`", option),
+                ));
+            }
+            values.push(option);
+        }
+        if values.is_empty() {
+            return Err(syn::Error::new_spanned(list, "select options cannot be empty"));
+        }
+
+        options = Some(values);
+        Ok(())
+    })?;
+
+    if options.is_none() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "select fields need their options: `options = [\"a\", \"b\"]`",
+        ));
+    }
+
+    Ok(ParameterTypeConfig::Select { options, multiple: Some(multiple) })
 }
 
 fn parse_checkbox_attribute(_attr: &Attribute) -> Result<ParameterTypeConfig> {
@@ -413,11 +469,99 @@ fn generate_parameter_collection(configs: &[FieldConfig]) -> Result<TokenStream>
     })
 }
 
-fn generate_from_values(struct_name: &syn::Ident, _configs: &[FieldConfig]) -> Result<TokenStream> {
+fn generate_from_values(configs: &[FieldConfig]) -> Result<TokenStream> {
+    let select_checks = generate_select_checks(configs);
+    let extractions = configs.iter().map(generate_field_extraction).collect::<Result<Vec<_>>>()?;
+    let idents = configs
+        .iter()
+        .map(|config| syn::parse_str::<syn::Ident>(&config.field_name))
+        .collect::<Result<Vec<_>>>()?;
+
     Ok(quote! {
-        Ok(#struct_name {
-            // TODO: десериализация полей
-        })
+        #(#select_checks)*
+
+        #(#extractions)*
+
+        Ok(Self { #(#idents),* })
+    })
+}
+
+/// Reads one field from `values` into a local of the same name
+///
+/// `Option` fields are `None` when their key is absent; any other field is
+/// required and reports `NotFound`. A value that does not convert to the
+/// field's type reports `InvalidType`.
+fn generate_field_extraction(config: &FieldConfig) -> Result<TokenStream> {
+    let nebula_core = crate::nebula_core_path();
+    let ident = syn::parse_str::<syn::Ident>(&config.field_name)?;
+    let key = config.common_attrs.key.as_deref().unwrap_or(&config.field_name);
+
+    let (ty, converted, missing) = match &config.field_type {
+        syn::Type::Path(type_path) if is_option_type(type_path) => {
+            (extract_type_argument(type_path)?, quote!(Some(converted)), quote!(None))
+        },
+        ty => (
+            ty.clone(),
+            quote!(converted),
+            quote! {
+                return Err(#nebula_core::ParameterError::NotFound(
+                    #nebula_core::types::ParameterKey::from_static(#key),
+                ))
+            },
+        ),
+    };
+    let conversion = generate_conversion(&ty, &quote!(value))?;
+    let expected_type = quote!(#ty).to_string().replace(' ', "");
+
+    Ok(quote! {
+        let #ident = match values.iter().find(|(key, _)| key.as_str() == #key) {
+            Some((key, value)) => {
+                let value = value.value();
+                let converted = (#conversion).map_err(|reason| {
+                    #nebula_core::ParameterError::InvalidType {
+                        key: key.clone(),
+                        expected_type: #expected_type.to_string(),
+                        actual_details: reason,
+                    }
+                })?;
+                #converted
+            },
+            None => #missing,
+        };
+    })
+}
+
+/// Expression converting the `&Value` in `value` to `ty`, as a
+/// `Result<ty, String>`
+///
+/// Types implementing `TryFrom<&Value>` convert directly, other integer types
+/// go through `i64`, and `Vec` converts each array item.
+fn generate_conversion(ty: &syn::Type, value: &TokenStream) -> Result<TokenStream> {
+    const NARROW_INTEGERS: &[&str] =
+        &["i8", "i16", "i32", "isize", "u8", "u16", "u32", "u64", "usize"];
+
+    if let syn::Type::Path(type_path) = ty {
+        let last = type_path.path.segments.last();
+        if last.is_some_and(|segment| segment.ident == "Vec") {
+            let item = generate_conversion(&extract_type_argument(type_path)?, &quote!(item))?;
+            return Ok(quote! {
+                match #value.as_array() {
+                    Some(items) => items.iter().map(|item| #item).collect::<Result<Vec<_>, String>>(),
+                    None => Err(format!("expected an array, got {}", #value.type_name())),
+                }
+            });
+        }
+        if last.is_some_and(|segment| NARROW_INTEGERS.iter().any(|int| segment.ident == int)) {
+            return Ok(quote! {
+                i64::try_from(#value)
+                    .map_err(|error| error.to_string())
+                    .and_then(|number| <#ty>::try_from(number).map_err(|error| error.to_string()))
+            });
+        }
+    }
+
+    Ok(quote! {
+        <#ty as ::core::convert::TryFrom<&_>>::try_from(#value).map_err(|error| error.to_string())
     })
 }
 
@@ -428,3 +572,140 @@ fn generate_to_values(_configs: &[FieldConfig]) -> Result<TokenStream> {
         values
     })
 }
+
+/// Select and multi-select fields as `(key, options, multiple)`
+fn select_fields(configs: &[FieldConfig]) -> impl Iterator<Item = (&str, &[String], bool)> {
+    configs.iter().filter_map(|config| match &config.param_type {
+        ParameterTypeConfig::Select { options: Some(options), multiple } => Some((
+            config.common_attrs.key.as_deref().unwrap_or(&config.field_name),
+            options.as_slice(),
+            multiple.unwrap_or(false),
+        )),
+        _ => None,
+    })
+}
+
+fn generate_select_options(configs: &[FieldConfig]) -> TokenStream {
+    let arms = select_fields(configs).map(|(key, options, _)| {
+        quote! { #key => Some(&[#(#options),*]), }
+    });
+
+    quote! {
+        match key {
+            #(#arms)*
+            _ => None,
+        }
+    }
+}
+
+/// Runtime checks that select values are one of the declared options
+fn generate_select_checks(configs: &[FieldConfig]) -> Vec<TokenStream> {
+    let nebula_core = crate::nebula_core_path();
+
+    select_fields(configs)
+        .map(|(key, options, multiple)| {
+            let is_valid = if multiple {
+                quote! {
+                    value.as_array().is_some_and(|items| {
+                        items.iter().all(|item| {
+                            item.as_string().is_some_and(|item| OPTIONS.contains(&item))
+                        })
+                    })
+                }
+            } else {
+                quote! {
+                    value.as_string().is_some_and(|value| OPTIONS.contains(&value))
+                }
+            };
+            let allowed = options.join(", ");
+
+            quote! {
+                if let Some((key, value)) = values.iter().find(|(key, _)| key.as_str() == #key) {
+                    const OPTIONS: &[&str] = &[#(#options),*];
+                    if !(#is_valid) {
+                        return Err(#nebula_core::ParameterError::ValidationError {
+                            key: key.clone(),
+                            reason: format!("{} is not one of: {}", value.value(), #allowed),
+                        });
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select_config(field: Field) -> Result<(Vec<String>, bool)> {
+        match parse_field(&field)?.param_type {
+            ParameterTypeConfig::Select { options: Some(options), multiple } => {
+                Ok((options, multiple.unwrap_or(false)))
+            },
+            other => panic!("expected a select parameter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_select_options() {
+        let field: Field = syn::parse_quote! {
+            #[select(options = ["low", "medium", "high"])]
+            priority: String
+        };
+        let (options, multiple) = select_config(field).unwrap();
+        assert_eq!(options, ["low", "medium", "high"]);
+        assert!(!multiple);
+
+        let field: Field = syn::parse_quote! {
+            #[multi_select(options = ["email", "sms"])]
+            channels: Vec<String>
+        };
+        assert_eq!(
+            select_config(field).unwrap(),
+            (vec!["email".to_string(), "sms".to_string()], true)
+        );
+
+        let duplicate: Field = syn::parse_quote! {
+            #[select(options = ["low", "low"])]
+            priority: String
+        };
+        let error = select_config(duplicate).unwrap_err().to_string();
+        assert!(error.contains("duplicate select option `low`"));
+
+        let missing: Field = syn::parse_quote! {
+            #[select]
+            priority: String
+        };
+        assert!(matches!(
+            parse_field(&missing).unwrap().param_type,
+            ParameterTypeConfig::Select { options: None, multiple: Some(false) }
+        ));
+
+        let empty: Field = syn::parse_quote! {
+            #[select()]
+            priority: String
+        };
+        assert!(parse_field(&empty).is_err());
+
+        let not_literal: Field = syn::parse_quote! {
+            #[select(options = [LOW])]
+            priority: String
+        };
+        assert!(select_config(not_literal).is_err());
+    }
+
+    #[test]
+    fn test_select_generated_code() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct Settings {
+                #[select(options = ["low", "high"])]
+                priority: String,
+            }
+        };
+        let tokens = derive_parameters_impl(input).unwrap().to_string();
+        assert!(tokens.contains(r#""priority" => Some (& ["low" , "high"])"#));
+        assert!(tokens.contains("is not one of: "));
+        assert!(tokens.contains(r#""low, high""#));
+    }
+}
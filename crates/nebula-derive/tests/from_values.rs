@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use nebula_core::types::ParameterKey;
+use nebula_core::{ParameterError, ParameterValue};
+use nebula_derive::Parameters;
+use nebula_value::Value;
+
+#[derive(Debug, Parameters)]
+struct Settings {
+    #[select(options = ["low", "medium", "high"])]
+    priority: String,
+    #[multi_select(options = ["email", "sms"])]
+    channels: Vec<String>,
+    retries: u32,
+    note: Option<String>,
+}
+
+#[derive(Debug, Parameters)]
+struct Empty {}
+
+fn values(
+    pairs: impl IntoIterator<Item = (&'static str, Value)>,
+) -> HashMap<ParameterKey, ParameterValue> {
+    pairs
+        .into_iter()
+        .map(|(key, value)| (ParameterKey::new(key).unwrap(), ParameterValue::new(value)))
+        .collect()
+}
+
+fn valid() -> HashMap<ParameterKey, ParameterValue> {
+    values([
+        ("priority", Value::string("high")),
+        ("channels", Value::array(vec![Value::string("email"), Value::string("sms")])),
+        ("retries", Value::number(3)),
+    ])
+}
+
+#[test]
+fn test_from_values_reads_fields() {
+    let settings = Settings::from_values(&valid()).unwrap();
+    assert_eq!(settings.priority, "high");
+    assert_eq!(settings.channels, ["email", "sms"]);
+    assert_eq!(settings.retries, 3);
+    assert_eq!(settings.note, None);
+
+    let mut with_note = valid();
+    with_note.extend(values([("note", Value::string("on call"))]));
+    let settings = Settings::from_values(&with_note).unwrap();
+    assert_eq!(settings.note.as_deref(), Some("on call"));
+
+    assert!(Empty::from_values(&HashMap::new()).is_ok());
+}
+
+#[test]
+fn test_from_values_rejects_unknown_option() {
+    let mut input = valid();
+    input.extend(values([("priority", Value::string("urgent"))]));
+    match Settings::from_values(&input) {
+        Err(ParameterError::ValidationError { key, reason }) => {
+            assert_eq!(key.as_str(), "priority");
+            assert_eq!(reason, "urgent is not one of: low, medium, high");
+        },
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+
+    let mut input = valid();
+    let channels = Value::array(vec![Value::string("email"), Value::string("fax")]);
+    input.extend(values([("channels", channels)]));
+    assert!(matches!(Settings::from_values(&input), Err(ParameterError::ValidationError { .. })));
+}
+
+#[test]
+fn test_from_values_reports_missing_and_mistyped_fields() {
+    let mut input = valid();
+    input.retain(|key, _| key.as_str() != "retries");
+    match Settings::from_values(&input) {
+        Err(ParameterError::NotFound(key)) => assert_eq!(key.as_str(), "retries"),
+        other => panic!("expected a missing parameter, got {other:?}"),
+    }
+
+    let mut input = valid();
+    input.extend(values([("retries", Value::string("three"))]));
+    match Settings::from_values(&input) {
+        Err(ParameterError::InvalidType { key, expected_type, .. }) => {
+            assert_eq!(key.as_str(), "retries");
+            assert_eq!(expected_type, "u32");
+        },
+        other => panic!("expected a type error, got {other:?}"),
+    }
+}
@@ -0,0 +1,6 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/select_duplicate_option.rs");
+    t.compile_fail("tests/ui/select_non_literal_option.rs");
+}
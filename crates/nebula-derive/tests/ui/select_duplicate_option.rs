@@ -0,0 +1,9 @@
+use nebula_derive::Parameters;
+
+#[derive(Parameters)]
+struct Settings {
+    #[select(options = ["low", "high", "low"])]
+    priority: String,
+}
+
+fn main() {}
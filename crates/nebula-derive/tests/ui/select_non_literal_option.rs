@@ -0,0 +1,11 @@
+use nebula_derive::Parameters;
+
+const LOW: &str = "low";
+
+#[derive(Parameters)]
+struct Settings {
+    #[select(options = [LOW, "high"])]
+    priority: String,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+//! Time sources for `$system.datetime`
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+/// Source of the current time used when resolving `$system.datetime`
+///
+/// The default [`SystemClock`] reads the wall clock; use [`FixedClock`] to
+/// make time-dependent template output deterministic.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Get the current time
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock reading the system wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always returns the same instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    instant: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// Create a clock frozen at the given instant
+    ///
+    /// Accepts anything convertible to `DateTime<Utc>`, including
+    /// [`SystemTime`](std::time::SystemTime).
+    pub fn new(instant: impl Into<DateTime<Utc>>) -> Self {
+        Self { instant: instant.into() }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.instant
+    }
+}
@@ -1,10 +1,12 @@
 //! Context for template evaluation
 
 use crate::{
+    clock::{Clock, SystemClock},
     error::{Error, Result},
     value::Value,
 };
-use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use std::{collections::HashMap, sync::Arc};
 
 /// Data source types for template expressions
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -21,6 +23,9 @@ pub enum DataSource {
     Environment,
     /// Workflow information - `$workflow`
     Workflow,
+    /// Template variable bound with `{{ let name = ... }}`, referenced by
+    /// its bare name
+    Local(String),
 }
 
 impl DataSource {
@@ -33,6 +38,7 @@ impl DataSource {
             Self::Execution => "$execution",
             Self::Environment => "$env",
             Self::Workflow => "$workflow",
+            Self::Local(name) => name,
         }
     }
 
@@ -43,66 +49,70 @@ impl DataSource {
 }
 
 /// System data that's always available
+///
+/// `datetime` is computed from the [`Clock`] each time it is read.
 #[derive(Debug, Clone)]
 pub struct SystemData {
     data: HashMap<String, Value>,
+    clock: Arc<dyn Clock>,
 }
 
 impl SystemData {
-    /// Create new system data
+    /// Create new system data using the system clock
     pub fn new() -> Self {
-        let mut data = HashMap::new();
-
-        // Add current timestamp
-        #[cfg(feature = "chrono")]
-        {
-            use chrono::Utc;
-            let now = Utc::now();
-
-            let mut datetime = HashMap::new();
-            datetime.insert("now".to_string(), Value::string(now.to_rfc3339()));
-            datetime.insert("timestamp".to_string(), Value::integer(now.timestamp()));
-            datetime.insert("iso".to_string(), Value::string(now.format("%Y-%m-%dT%H:%M:%SZ").to_string()));
-            datetime.insert("date".to_string(), Value::string(now.format("%Y-%m-%d").to_string()));
-            datetime.insert("time".to_string(), Value::string(now.format("%H:%M:%S").to_string()));
-
-            data.insert("datetime".to_string(), Value::object(datetime));
-        }
+        Self::with_clock(Arc::new(SystemClock))
+    }
 
-        #[cfg(not(feature = "chrono"))]
-        {
-            // Basic timestamp using std
-            use std::time::{SystemTime, UNIX_EPOCH};
-            if let Ok(duration) = SystemTime::now().duration_since(UNIX_EPOCH) {
-                let mut datetime = HashMap::new();
-                datetime.insert("timestamp".to_string(), Value::integer(duration.as_secs() as i64));
-                data.insert("datetime".to_string(), Value::object(datetime));
-            }
+    /// Create new system data using the given clock
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            data: HashMap::new(),
+            clock,
         }
+    }
+
+    /// Get the clock used for `datetime`
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
 
-        Self { data }
+    /// Replace the clock used for `datetime`
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
     }
 
     /// Get a system value by path
-    pub fn get(&self, path: &str) -> Option<&Value> {
+    pub fn get(&self, path: &str) -> Option<Value> {
+        let root = self.as_value();
         if path.is_empty() {
-            return Some(&Value::object(self.data.clone()));
-        }
-
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current = &Value::object(self.data.clone());
-
-        for part in parts {
-            current = current.get(part)?;
+            return Some(root);
         }
 
-        Some(current)
+        resolve_path(&root, path).ok().flatten().cloned()
     }
 
     /// Add or update a system value
     pub fn set(&mut self, key: String, value: Value) {
         self.data.insert(key, value);
     }
+
+    /// Get all system data as a value
+    pub fn as_value(&self) -> Value {
+        let mut data = self.data.clone();
+        data.insert("datetime".to_string(), datetime_value(self.clock.now()));
+        Value::object(data)
+    }
+}
+
+/// Build the `$system.datetime` object for an instant
+fn datetime_value(now: DateTime<Utc>) -> Value {
+    let mut datetime = HashMap::new();
+    datetime.insert("now".to_string(), Value::string(now.to_rfc3339()));
+    datetime.insert("timestamp".to_string(), Value::integer(now.timestamp()));
+    datetime.insert("iso".to_string(), Value::string(now.format("%Y-%m-%dT%H:%M:%SZ").to_string()));
+    datetime.insert("date".to_string(), Value::string(now.format("%Y-%m-%d").to_string()));
+    datetime.insert("time".to_string(), Value::string(now.format("%H:%M:%S").to_string()));
+    Value::object(datetime)
 }
 
 impl Default for SystemData {
@@ -182,9 +192,18 @@ pub struct Context {
     env_vars: HashMap<String, String>,
     /// Workflow metadata
     workflow_data: WorkflowData,
+    /// Partial template sources available to `{{ include 'name' }}`
+    partials: HashMap<String, String>,
+    /// Template variables bound during rendering
+    locals: HashMap<String, Value>,
 }
 
 impl Context {
+    /// Start building a context with chained setters
+    pub fn builder() -> ContextBuilder {
+        ContextBuilder::default()
+    }
+
     /// Create a new empty context
     pub fn new() -> Self {
         Self {
@@ -194,6 +213,8 @@ impl Context {
             execution_data: ExecutionData::new(),
             env_vars: HashMap::new(),
             workflow_data: WorkflowData::new(),
+            partials: HashMap::new(),
+            locals: HashMap::new(),
         }
     }
 
@@ -247,6 +268,56 @@ impl Context {
         self.workflow_data.get(key)
     }
 
+    /// Register a partial template that can be included with
+    /// `{{ include 'name' }}`, replacing any existing one
+    pub fn register_partial(&mut self, name: impl Into<String>, source: impl Into<String>) {
+        self.partials.insert(name.into(), source.into());
+    }
+
+    /// Get the source of a registered partial
+    pub fn get_partial(&self, name: &str) -> Option<&str> {
+        self.partials.get(name).map(String::as_str)
+    }
+
+    /// Names of all registered partials
+    pub fn partial_names(&self) -> Vec<String> {
+        self.partials.keys().cloned().collect()
+    }
+
+    /// Bind a template variable, replacing any existing binding
+    ///
+    /// Templates bind variables with `{{ let name = ... }}` on a copy of the
+    /// context while rendering, so the caller's context is left unchanged.
+    pub fn set_local(&mut self, name: impl Into<String>, value: Value) {
+        self.locals.insert(name.into(), value);
+    }
+
+    /// Get the value of a template variable
+    pub fn get_local(&self, name: &str) -> Option<&Value> {
+        self.locals.get(name)
+    }
+
+    /// Names of all bound template variables
+    pub fn local_names(&self) -> Vec<String> {
+        self.locals.keys().cloned().collect()
+    }
+
+    /// Use the given clock for `$system.datetime`
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.system_data.set_clock(clock);
+        self
+    }
+
+    /// Replace the clock used for `$system.datetime`
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.system_data.set_clock(clock);
+    }
+
+    /// Get the clock used for `$system.datetime`
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        self.system_data.clock()
+    }
+
     /// Get system data
     pub fn get_system_data(&self) -> &SystemData {
         &self.system_data
@@ -264,7 +335,7 @@ impl Context {
                 if let Some(input) = &self.input_data {
                     if path.is_empty() {
                         Ok(input.clone())
-                    } else if let Some(value) = input.navigate(path) {
+                    } else if let Some(value) = resolve_path(input, path)? {
                         Ok(value.clone())
                     } else {
                         Err(Error::data_not_found(
@@ -283,7 +354,7 @@ impl Context {
                 if let Some(output) = self.node_outputs.get(node_id) {
                     if path.is_empty() {
                         Ok(output.clone())
-                    } else if let Some(value) = output.navigate(path) {
+                    } else if let Some(value) = resolve_path(output, path)? {
                         Ok(value.clone())
                     } else {
                         Err(Error::data_not_found(
@@ -302,7 +373,10 @@ impl Context {
                 }
             }
             DataSource::System => {
-                if let Some(value) = self.system_data.get(path) {
+                let system = self.system_data.as_value();
+                if path.is_empty() {
+                    Ok(system)
+                } else if let Some(value) = resolve_path(&system, path)? {
                     Ok(value.clone())
                 } else {
                     Err(Error::data_not_found(
@@ -354,6 +428,18 @@ impl Context {
                     ))
                 }
             }
+            DataSource::Local(name) => {
+                let Some(local) = self.locals.get(name) else {
+                    return Err(Error::data_not_found(name.clone(), self.local_names()));
+                };
+                if path.is_empty() {
+                    Ok(local.clone())
+                } else if let Some(value) = resolve_path(local, path)? {
+                    Ok(value.clone())
+                } else {
+                    Err(Error::data_not_found(format!("{}.{}", name, path), vec![name.clone()]))
+                }
+            }
         }
     }
 
@@ -377,6 +463,8 @@ impl Context {
             sources.push(format!("$env.{}", env_key));
         }
 
+        sources.extend(self.locals.keys().cloned());
+
         sources
     }
 
@@ -389,6 +477,7 @@ impl Context {
             DataSource::Execution => true, // Always available (might be empty)
             DataSource::Environment => true, // Always available (might be empty)
             DataSource::Workflow => true, // Always available (might be empty)
+            DataSource::Local(name) => self.locals.contains_key(name),
         }
     }
 }
@@ -399,6 +488,92 @@ impl Default for Context {
     }
 }
 
+/// Fluent builder for [`Context`], created with [`Context::builder`]
+///
+/// ```
+/// use nebula_template::Context;
+///
+/// let context = Context::builder()
+///     .input("hello")
+///     .node("fetch", 42)
+///     .env("REGION", "eu")
+///     .build();
+/// assert_eq!(context.get_env("REGION"), Some("eu"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ContextBuilder {
+    context: Context,
+}
+
+impl ContextBuilder {
+    /// Set the input data (`$input`)
+    pub fn input(mut self, data: impl Into<Value>) -> Self {
+        self.context.set_input(data.into());
+        self
+    }
+
+    /// Add a node's output (`$node('id')`)
+    pub fn node(mut self, node_id: impl Into<String>, data: impl Into<Value>) -> Self {
+        self.context.add_node_output(node_id, data.into());
+        self
+    }
+
+    /// Set an environment variable (`$env.KEY`)
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.context.set_env(key, value);
+        self
+    }
+
+    /// Set a secret
+    ///
+    /// The context has no separate secret store: secrets are environment
+    /// variables and are read as `$env.KEY`.
+    pub fn secret(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env(key, value)
+    }
+
+    /// Build the context
+    pub fn build(self) -> Context {
+        self.context
+    }
+}
+
+/// Resolve a data path such as `user.addresses[0].city` within a value
+///
+/// Numeric segments and bracketed indices both index into arrays, so
+/// `items.0.name` and `items[0].name` are equivalent. An index past the end of
+/// an array is reported as [`Error::IndexError`]; any other missing segment
+/// resolves to `None`.
+fn resolve_path<'a>(root: &'a Value, path: &str) -> Result<Option<&'a Value>> {
+    let mut current = root;
+
+    for segment in path_segments(path) {
+        current = match current {
+            Value::Array(items) => {
+                let Ok(index) = segment.parse::<usize>() else {
+                    return Ok(None);
+                };
+                match items.get(index) {
+                    Some(item) => item,
+                    None => return Err(Error::index(index as isize, items.len())),
+                }
+            }
+            Value::Object(map) => match map.get(segment) {
+                Some(value) => value,
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+    }
+
+    Ok(Some(current))
+}
+
+/// Split a data path into segments, treating each `[n]` as its own segment
+fn path_segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['.', '[', ']']).filter(|segment| !segment.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -455,6 +630,45 @@ mod tests {
         Ok(())
     }
 
+    fn items_input() -> Value {
+        let mut item = HashMap::new();
+        item.insert("name".to_string(), Value::string("first"));
+
+        let mut input = HashMap::new();
+        input.insert("items".to_string(), Value::array(vec![Value::object(item)]));
+        Value::object(input)
+    }
+
+    #[test]
+    fn test_array_index_paths() -> Result<()> {
+        let mut context = Context::new();
+        context.set_input(items_input());
+
+        let result = context.resolve_data_source(&DataSource::Input, "items.0.name")?;
+        assert_eq!(result, Value::string("first"));
+
+        let result = context.resolve_data_source(&DataSource::Input, "items[0].name")?;
+        assert_eq!(result, Value::string("first"));
+
+        context.add_node_output("fetch", items_input());
+        let result = context.resolve_data_source(&DataSource::node("fetch"), "items[0]")?;
+        assert!(result.is_object());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_index_out_of_range() {
+        let mut context = Context::new();
+        context.set_input(items_input());
+
+        let result = context.resolve_data_source(&DataSource::Input, "items[3].name");
+        assert!(matches!(result, Err(Error::IndexError { index: 3, size: 1 })));
+
+        let result = context.resolve_data_source(&DataSource::Input, "items.first");
+        assert!(matches!(result, Err(Error::DataNotFound { .. })));
+    }
+
     #[test]
     fn test_data_source_not_found() {
         let context = Context::new();
@@ -478,6 +692,43 @@ mod tests {
         assert!(system.get("datetime").is_some());
     }
 
+    #[test]
+    fn test_fixed_clock() -> Result<()> {
+        use crate::clock::FixedClock;
+        use std::time::{Duration, UNIX_EPOCH};
+
+        // 2024-01-02T03:04:05Z
+        let instant = UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+        let context = Context::new().with_clock(Arc::new(FixedClock::new(instant)));
+
+        let timestamp = context.resolve_data_source(&DataSource::System, "datetime.timestamp")?;
+        assert_eq!(timestamp, Value::integer(1_704_164_645));
+
+        let template = crate::Template::parse("Generated at {{ $system.datetime.now }}")?;
+        assert_eq!(template.render(&context)?, "Generated at 2024-01-02T03:04:05+00:00");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder() -> Result<()> {
+        let context = Context::builder()
+            .input(Value::object(HashMap::from([("name".to_string(), Value::from("Ada"))])))
+            .node("fetch", Value::object(HashMap::from([("status".to_string(), Value::from(200))])))
+            .node("parse", "ok")
+            .env("REGION", "eu-west")
+            .secret("API_TOKEN", "s3cr3t")
+            .build();
+
+        let template = crate::Template::parse(
+            "{{ $input.name }} {{ $node('fetch').status }} {{ $node('parse') }} {{ $env.REGION }}",
+        )?;
+        assert_eq!(template.render(&context)?, "Ada 200 ok eu-west");
+        assert_eq!(context.get_env("API_TOKEN"), Some("s3cr3t"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_data_source_enum() {
         assert_eq!(DataSource::Input.as_str(), "$input");
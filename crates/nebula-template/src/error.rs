@@ -75,6 +75,18 @@ pub enum Error {
         message: String,
     },
 
+    /// An expression failed while rendering a template
+    #[error("error in expression \"{expression}\" (element {element}): {error}")]
+    ExpressionError {
+        /// Source of the failing expression, without the surrounding braces
+        expression: String,
+        /// Index of the expression's element within its template
+        element: usize,
+        /// The error the expression failed with
+        #[source]
+        error: Box<Error>,
+    },
+
     /// Index out of bounds
     #[error("Index {index} out of bounds for collection of size {size}")]
     IndexError {
@@ -95,7 +107,6 @@ pub enum Error {
     JsonError(#[from] serde_json::Error),
 
     /// Date/time parsing error
-    #[cfg(feature = "chrono")]
     #[error("Date/time error: {0}")]
     ChronoError(#[from] chrono::ParseError),
 
@@ -200,6 +211,15 @@ impl Error {
         }
     }
 
+    /// Attach the source and element index of the expression that failed
+    pub fn in_expression(expression: impl Into<String>, element: usize, error: Error) -> Self {
+        Self::ExpressionError {
+            expression: expression.into(),
+            element,
+            error: Box::new(error),
+        }
+    }
+
     /// Create an index error
     pub fn index(index: isize, size: usize) -> Self {
         Self::IndexError { index, size }
@@ -255,13 +275,13 @@ impl Error {
             Self::DataNotFound { path, .. } => path,
             Self::SignatureError { message, .. } => message,
             Self::MathError { message } => message,
+            Self::ExpressionError { error, .. } => error.message(),
             Self::IndexError { .. } => "Index out of bounds",
             Self::CustomError { message, .. } => message,
             #[cfg(feature = "regex")]
             Self::RegexError(e) => e.as_str(),
             #[cfg(feature = "serde")]
             Self::JsonError(_) => "JSON error",
-            #[cfg(feature = "chrono")]
             Self::ChronoError(_) => "Date/time error",
             Self::IoError(_) => "IO error",
         }
@@ -332,6 +352,16 @@ mod tests {
         assert_eq!(err.message(), "Division by zero");
     }
 
+    #[test]
+    fn test_expression_error() {
+        let err = Error::in_expression("$input.x / 0", 3, Error::math("Division by zero"));
+        assert_eq!(err.message(), "Division by zero");
+        assert_eq!(
+            err.to_string(),
+            "error in expression \"$input.x / 0\" (element 3): Math error: Division by zero"
+        );
+    }
+
     #[test]
     fn test_index_error() {
         let err = Error::index(-1, 5);
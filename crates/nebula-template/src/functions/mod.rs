@@ -1,30 +1,449 @@
+//! Function definitions and registry for template expressions
+
+use crate::{
+    error::{Error, FunctionError},
+    value::Value,
+};
+use std::{collections::HashMap, fmt};
+
+mod object;
+
+/// A function that can be called from template expressions
 pub trait Function: Send + Sync {
+    /// Name the function is registered and called under
     fn name(&self) -> &str;
+
+    /// Declared input, parameter and return types
     fn signature(&self) -> &FunctionSignature;
+
+    /// Execute the function
+    ///
+    /// When called by the evaluator, `args` has already been checked against
+    /// [`Function::signature`]: the first element is the input value followed
+    /// by one value per declared parameter, with defaults filled in.
     fn execute(&self, args: Vec<Value>) -> Result<Value, FunctionError>;
+
+    /// Readable prototype such as `round(value: number, places?: integer) -> number`
+    fn prototype(&self) -> String {
+        format!("{}{}", self.name(), self.signature().describe())
+    }
+}
+
+/// Types that function inputs, parameters and return values can declare
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    /// Null value
+    Null,
+    /// Boolean value
+    Boolean,
+    /// Integer number
+    Integer,
+    /// Floating point number
+    Float,
+    /// Integer or floating point number
+    Number,
+    /// String value
+    String,
+    /// Array of values
+    Array,
+    /// Object/map of string keys to values
+    Object,
+    /// Any value
+    Any,
+}
+
+impl ValueType {
+    /// Get the type name as used in error messages
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Array => "array",
+            Self::Object => "object",
+            Self::Any => "any",
+        }
+    }
+
+    /// Check whether a value is exactly of this type
+    ///
+    /// [`ValueType::Number`] matches both integers and floats, and
+    /// [`ValueType::Any`] matches every value.
+    pub fn matches(&self, value: &Value) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Null => value.is_null(),
+            Self::Boolean => value.is_bool(),
+            Self::Integer => matches!(value, Value::Integer(_)),
+            Self::Float => matches!(value, Value::Float(_)),
+            Self::Number => value.is_number(),
+            Self::String => value.is_string(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+        }
+    }
+
+    /// Check whether a value is of this type or can be coerced to it
+    ///
+    /// Supported coercions:
+    /// - numeric strings to [`ValueType::Integer`], [`ValueType::Float`] and
+    ///   [`ValueType::Number`]
+    /// - integers to [`ValueType::Float`]
+    /// - numbers and booleans to [`ValueType::String`]
+    /// - `"true"`/`"false"`-style strings to [`ValueType::Boolean`]
+    pub fn accepts(&self, value: &Value) -> bool {
+        self.matches(value) || self.coerce(value).is_some()
+    }
+
+    /// Coerce a value that is not already of this type
+    fn coerce(&self, value: &Value) -> Option<Value> {
+        match (self, value) {
+            (Self::Integer, Value::String(s)) => s.trim().parse().ok().map(Value::Integer),
+            (Self::Float, Value::Integer(i)) => Some(Value::Float(*i as f64)),
+            (Self::Float, Value::String(s)) => s.trim().parse().ok().map(Value::Float),
+            (Self::Number, Value::String(s)) => {
+                let s = s.trim();
+                s.parse()
+                    .map(Value::Integer)
+                    .or_else(|_| s.parse().map(Value::Float))
+                    .ok()
+            }
+            (Self::String, Value::Integer(_) | Value::Float(_) | Value::Bool(_)) => {
+                value.as_string().ok().map(Value::String)
+            }
+            (Self::Boolean, Value::String(_)) => value.as_bool().ok().map(Value::Bool),
+            _ => None,
+        }
+    }
+
+    /// Convert an accepted value to this type, leaving matching values as-is
+    fn convert(&self, value: Value) -> Value {
+        if self.matches(&value) {
+            value
+        } else {
+            self.coerce(&value).unwrap_or(value)
+        }
+    }
 }
 
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Declared types of a function's input, parameters and return value
 #[derive(Debug, Clone)]
 pub struct FunctionSignature {
+    /// Type of the input value (the piped value or first argument)
     pub input_type: ValueType,
+    /// Parameters following the input value, in positional order
     pub parameters: Vec<Parameter>,
+    /// Type of the returned value
     pub return_type: ValueType,
 }
 
+impl FunctionSignature {
+    /// Render the parameter list and return type, e.g.
+    /// `(value: number, places?: integer) -> number`
+    ///
+    /// The input value is listed first as `value`; optional parameters are
+    /// marked with `?`. See [`Function::prototype`] for the named form.
+    pub fn describe(&self) -> String {
+        let mut params = vec![format!("value: {}", self.input_type)];
+        params.extend(self.parameters.iter().map(|parameter| {
+            let marker = if parameter.required { "" } else { "?" };
+            format!("{}{}: {}", parameter.name, marker, parameter.value_type)
+        }));
+        format!("({}) -> {}", params.join(", "), self.return_type)
+    }
+
+    /// Check arity and argument types against this signature
+    ///
+    /// `args` holds the input value followed by the positional arguments.
+    /// Omitted parameters are accepted when they are optional or declare a
+    /// default value.
+    pub fn validate_args(&self, args: &[Value]) -> Result<(), FunctionError> {
+        let Some((input, rest)) = args.split_first() else {
+            return Err(Error::evaluation("Missing input value"));
+        };
+
+        if !self.input_type.accepts(input) {
+            return Err(Error::type_error_with_context(
+                input.type_name(),
+                self.input_type.name(),
+                "input value",
+            ));
+        }
+
+        if rest.len() > self.parameters.len() {
+            return Err(Error::evaluation(format!(
+                "Expected at most {} argument(s), got {}",
+                self.parameters.len(),
+                rest.len()
+            )));
+        }
+
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            match rest.get(index) {
+                Some(arg) if !parameter.value_type.accepts(arg) => {
+                    return Err(Error::type_error_with_context(
+                        arg.type_name(),
+                        parameter.value_type.name(),
+                        format!("argument '{}'", parameter.name),
+                    ));
+                }
+                Some(_) => {}
+                None if parameter.required && parameter.default.is_none() => {
+                    return Err(Error::evaluation(format!(
+                        "Missing required argument '{}'",
+                        parameter.name
+                    )));
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate arguments, coerce them and fill in omitted parameters
+    ///
+    /// The returned list always holds the input value followed by exactly one
+    /// value per declared parameter, each converted to its declared type (see
+    /// [`ValueType::accepts`]). Omitted parameters take their declared
+    /// default, or null when none is declared.
+    pub fn prepare_args(&self, args: Vec<Value>) -> Result<Vec<Value>, FunctionError> {
+        self.validate_args(&args)?;
+
+        let mut args = args.into_iter();
+        let mut prepared = Vec::with_capacity(self.parameters.len() + 1);
+        if let Some(input) = args.next() {
+            prepared.push(self.input_type.convert(input));
+        }
+        for parameter in &self.parameters {
+            prepared.push(match args.next() {
+                Some(arg) => parameter.value_type.convert(arg),
+                None => parameter.default.clone().unwrap_or_else(Value::null),
+            });
+        }
+
+        Ok(prepared)
+    }
+}
+
+/// A declared function parameter
 #[derive(Debug, Clone)]
 pub struct Parameter {
+    /// Parameter name, used in error messages
     pub name: String,
+    /// Expected type of the argument
     pub value_type: ValueType,
+    /// Whether the argument must be supplied
     pub required: bool,
+    /// Value used when the argument is omitted
     pub default: Option<Value>,
 }
 
+/// Registry of functions available to template expressions
+#[derive(Default)]
 pub struct FunctionRegistry {
     functions: HashMap<String, Box<dyn Function>>,
 }
 
 impl FunctionRegistry {
-    pub fn with_builtins() -> Self;
-    pub fn register<F: Function + 'static>(&mut self, func: F);
-    pub fn get(&self, name: &str) -> Option<&dyn Function>;
-}
\ No newline at end of file
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with the built-in function library
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        object::register(&mut registry);
+        registry
+    }
+
+    /// Register a function under its own name, replacing any existing one
+    pub fn register<F: Function + 'static>(&mut self, func: F) {
+        self.functions.insert(func.name().to_string(), Box::new(func));
+    }
+
+    /// Get a function by name
+    pub fn get(&self, name: &str) -> Option<&dyn Function> {
+        self.functions.get(name).map(|f| f.as_ref())
+    }
+
+    /// Check whether a function is registered under `name`
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Names of all registered functions, sorted alphabetically
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.functions.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Iterate over registered functions and their signatures, sorted by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FunctionSignature)> {
+        self.names().into_iter().map(|name| (name, self.functions[name].signature()))
+    }
+}
+
+impl fmt::Debug for FunctionRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FunctionRegistry")
+            .field("functions", &self.functions.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signature() -> FunctionSignature {
+        FunctionSignature {
+            input_type: ValueType::String,
+            parameters: vec![
+                Parameter {
+                    name: "count".to_string(),
+                    value_type: ValueType::Integer,
+                    required: true,
+                    default: None,
+                },
+                Parameter {
+                    name: "separator".to_string(),
+                    value_type: ValueType::String,
+                    required: false,
+                    default: Some(Value::string(", ")),
+                },
+            ],
+            return_type: ValueType::String,
+        }
+    }
+
+    struct Repeat;
+
+    impl Function for Repeat {
+        fn name(&self) -> &str {
+            "repeat"
+        }
+
+        fn signature(&self) -> &FunctionSignature {
+            static SIGNATURE: std::sync::OnceLock<FunctionSignature> = std::sync::OnceLock::new();
+            SIGNATURE.get_or_init(signature)
+        }
+
+        fn execute(&self, args: Vec<Value>) -> Result<Value, FunctionError> {
+            Ok(args[0].clone())
+        }
+    }
+
+    #[test]
+    fn test_signature_describe() {
+        assert_eq!(
+            signature().describe(),
+            "(value: string, count: integer, separator?: string) -> string"
+        );
+        assert_eq!(
+            Repeat.prototype(),
+            "repeat(value: string, count: integer, separator?: string) -> string"
+        );
+    }
+
+    #[test]
+    fn test_registry_introspection() {
+        let mut registry = FunctionRegistry::with_builtins();
+        assert!(registry.contains("merge"));
+        assert!(registry.names().contains(&"merge_patch"));
+        let (_, merge) = registry.iter().find(|(name, _)| *name == "merge").unwrap();
+        assert_eq!(merge.return_type, ValueType::Object);
+
+        let builtins = registry.names().len();
+        registry.register(Repeat);
+
+        assert!(registry.contains("repeat"));
+        assert!(!registry.contains("missing"));
+        assert_eq!(registry.names().len(), builtins + 1);
+        assert!(registry.names().contains(&"repeat"));
+
+        let (name, signature) = registry.iter().find(|(name, _)| *name == "repeat").unwrap();
+        assert_eq!(name, "repeat");
+        assert_eq!(signature.return_type, ValueType::String);
+    }
+
+    #[test]
+    fn test_missing_required_arg() {
+        let result = signature().validate_args(&[Value::string("a")]);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().message().contains("count"));
+    }
+
+    #[test]
+    fn test_extra_arg() {
+        let args =
+            [Value::string("a"), Value::integer(2), Value::string("-"), Value::bool(true)];
+        assert!(signature().validate_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let result = signature().validate_args(&[Value::string("a"), Value::string("two")]);
+        assert!(result.unwrap_err().is_type_error());
+    }
+
+    #[test]
+    fn test_value_type_matches() {
+        assert!(ValueType::String.matches(&Value::string("a")));
+        assert!(ValueType::Number.matches(&Value::integer(1)));
+        assert!(ValueType::Number.matches(&Value::float(1.5)));
+        assert!(!ValueType::Integer.matches(&Value::float(1.5)));
+        assert!(!ValueType::Object.matches(&Value::array(vec![])));
+    }
+
+    #[test]
+    fn test_value_type_accepts_coercion() {
+        let numeric = Value::string("42");
+        assert!(!ValueType::Number.matches(&numeric));
+        assert!(ValueType::Number.accepts(&numeric));
+        assert!(ValueType::Float.accepts(&Value::integer(1)));
+        assert!(ValueType::String.accepts(&Value::bool(true)));
+        assert!(!ValueType::Number.accepts(&Value::string("abc")));
+        assert!(!ValueType::Array.accepts(&Value::string("[]")));
+    }
+
+    #[test]
+    fn test_value_type_any() {
+        for value in [
+            Value::null(),
+            Value::bool(false),
+            Value::integer(1),
+            Value::string("a"),
+            Value::array(vec![]),
+            Value::object(HashMap::new()),
+        ] {
+            assert!(ValueType::Any.matches(&value));
+            assert!(ValueType::Any.accepts(&value));
+        }
+    }
+
+    #[test]
+    fn test_args_coerced() -> crate::Result<()> {
+        let args = signature().prepare_args(vec![Value::integer(7), Value::string("2")])?;
+        assert_eq!(args, vec![Value::string("7"), Value::integer(2), Value::string(", ")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_filled_in() -> crate::Result<()> {
+        let args = signature().prepare_args(vec![Value::string("a"), Value::integer(2)])?;
+        assert_eq!(args, vec![Value::string("a"), Value::integer(2), Value::string(", ")]);
+        Ok(())
+    }
+}
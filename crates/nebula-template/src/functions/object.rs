@@ -0,0 +1,57 @@
+//! Built-in functions operating on objects
+
+use super::{Function, FunctionRegistry, FunctionSignature, Parameter, ValueType};
+use crate::{
+    error::{Error, FunctionError},
+    value::Value,
+};
+
+/// Register the object functions in `registry`
+pub(super) fn register(registry: &mut FunctionRegistry) {
+    registry.register(Merge::new("merge", Value::merge));
+    registry.register(Merge::new("merge_patch", Value::merge_patch));
+}
+
+/// `merge(base, patch)` and `merge_patch(base, patch)`, see [`Value::merge`]
+/// and [`Value::merge_patch`]
+struct Merge {
+    name: &'static str,
+    signature: FunctionSignature,
+    merge: fn(&Value, &Value) -> Value,
+}
+
+impl Merge {
+    fn new(name: &'static str, merge: fn(&Value, &Value) -> Value) -> Self {
+        Self {
+            name,
+            signature: FunctionSignature {
+                input_type: ValueType::Object,
+                parameters: vec![Parameter {
+                    name: "patch".to_string(),
+                    value_type: ValueType::Object,
+                    required: true,
+                    default: None,
+                }],
+                return_type: ValueType::Object,
+            },
+            merge,
+        }
+    }
+}
+
+impl Function for Merge {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn signature(&self) -> &FunctionSignature {
+        &self.signature
+    }
+
+    fn execute(&self, args: Vec<Value>) -> Result<Value, FunctionError> {
+        let [base, patch] = args.as_slice() else {
+            return Err(Error::evaluation("Expected base and patch objects"));
+        };
+        Ok((self.merge)(base, patch))
+    }
+}
@@ -65,6 +65,20 @@
 //! {{ if($input.active, 'Enabled', 'Disabled') }}
 //! ```
 //!
+//! ### Array and Object Literals
+//!
+//! ```text
+//! {{ [1, 2, $input.x] }}
+//! {{ { "name": $input.name, "age": $input.age } }}
+//! ```
+//!
+//! ### Variables
+//!
+//! ```text
+//! {{ let total = $input.price * $input.qty }}
+//! {{ total | currency }}
+//! ```
+//!
 //! ### Loops
 //!
 //! ```text
@@ -72,6 +86,16 @@
 //! - {{ item.name }}: {{ item.price | currency }}
 //! {{ endforeach }}
 //! ```
+//!
+//! ### Escaping
+//!
+//! Prefix braces with a backslash to output them literally:
+//!
+//! ```text
+//! \{{ not an expression \}}          // Renders as: {{ not an expression }}
+//! \\{{ 'text' }}                     // Renders as: \text
+//! a\\b                               // Renders as: a\\b
+//! ```
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(missing_docs)]
@@ -82,12 +106,16 @@
 #![allow(clippy::missing_errors_doc)]
 
 // Re-export main types for convenience
-pub use context::{Context, DataSource};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use context::{Context, ContextBuilder, DataSource};
 pub use error::{Error, Result};
-pub use template::Template;
+pub use template::{
+    DependencyReport, ErrorPolicy, EscapeMode, ParseCacheStats, Template, TemplateConfig,
+};
 pub use value::Value;
 
 // Core modules
+pub mod clock;
 pub mod context;
 pub mod error;
 pub mod template;
@@ -103,7 +131,7 @@ mod context;
 mod template;
 
 // Re-export function-related types
-pub use functions::{Function, FunctionRegistry, FunctionSignature};
+pub use functions::{Function, FunctionRegistry, FunctionSignature, Parameter, ValueType};
 
 // Optional feature re-exports
 #[cfg(feature = "serde")]
@@ -119,7 +147,7 @@ pub mod prelude {
 
     pub use crate::{
         Context, DataSource, Error, Result, Template, Value,
-        Function, FunctionRegistry, FunctionSignature,
+        Function, FunctionRegistry, FunctionSignature, ValueType,
     };
 
     #[cfg(feature = "serde")]
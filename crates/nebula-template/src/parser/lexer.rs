@@ -0,0 +1,246 @@
+//! Tokenizer for template expressions
+
+use crate::error::{Error, Result};
+
+/// A lexical token of an expression
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+    /// Number literal, kept as written so it can also serve as a path segment
+    Number(String),
+    /// String literal with escapes resolved
+    String(String),
+    /// Bare identifier: function names, keywords, path segments
+    Ident(String),
+    /// Data source reference without the `$`: `input`, `node`, ...
+    Variable(String),
+
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    /// `=` in a `let` assignment
+    Eq,
+    EqEq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    AndAnd,
+    OrOr,
+    /// `??` null-coalescing operator
+    QuestionQuestion,
+    /// `?.` optional-chaining separator
+    QuestionDot,
+    Bang,
+    Question,
+    Colon,
+    Pipe,
+    Comma,
+    Dot,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+}
+
+/// Split an expression into tokens paired with their byte offsets
+pub(crate) fn tokenize(source: &str) -> Result<Vec<(Token, usize)>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+
+    while let Some(&(position, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let token = match c {
+            '"' | '\'' => {
+                chars.next();
+                let unterminated = || Error::parse("Unterminated string literal", position, source);
+                let mut value = String::new();
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        (_, ch) if ch == c => break,
+                        (_, '\\') => match chars.next().ok_or_else(unterminated)? {
+                            (_, 'n') => value.push('\n'),
+                            (_, 't') => value.push('\t'),
+                            (_, escaped) => value.push(escaped),
+                        },
+                        (_, ch) => value.push(ch),
+                    }
+                }
+                Token::String(value)
+            },
+            '0'..='9' => {
+                let mut end = position;
+                let mut seen_dot = false;
+                while let Some(&(i, ch)) = chars.peek() {
+                    if ch.is_ascii_digit() {
+                        end = i + 1;
+                        chars.next();
+                    } else if ch == '.'
+                        && !seen_dot
+                        && source[i + 1..].starts_with(|d: char| d.is_ascii_digit())
+                    {
+                        seen_dot = true;
+                        end = i + 1;
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                Token::Number(source[position..end].to_string())
+            },
+            '$' => {
+                chars.next();
+                let name = take_identifier(source, &mut chars);
+                if name.is_empty() {
+                    return Err(Error::parse(
+                        "Expected data source name after '$'",
+                        position,
+                        source,
+                    ));
+                }
+                Token::Variable(name.to_string())
+            },
+            c if c.is_alphabetic() || c == '_' => {
+                Token::Ident(take_identifier(source, &mut chars).to_string())
+            },
+            _ => {
+                chars.next();
+                let next = chars.peek().map(|&(_, ch)| ch);
+                let (token, two_chars) = match (c, next) {
+                    ('=', Some('=')) => (Token::EqEq, true),
+                    ('!', Some('=')) => (Token::NotEq, true),
+                    ('<', Some('=')) => (Token::LtEq, true),
+                    ('>', Some('=')) => (Token::GtEq, true),
+                    ('&', Some('&')) => (Token::AndAnd, true),
+                    ('|', Some('|')) => (Token::OrOr, true),
+                    ('?', Some('?')) => (Token::QuestionQuestion, true),
+                    ('?', Some('.')) => (Token::QuestionDot, true),
+                    ('=', _) => (Token::Eq, false),
+                    ('+', _) => (Token::Plus, false),
+                    ('-', _) => (Token::Minus, false),
+                    ('*', _) => (Token::Star, false),
+                    ('/', _) => (Token::Slash, false),
+                    ('%', _) => (Token::Percent, false),
+                    ('<', _) => (Token::Lt, false),
+                    ('>', _) => (Token::Gt, false),
+                    ('!', _) => (Token::Bang, false),
+                    ('?', _) => (Token::Question, false),
+                    (':', _) => (Token::Colon, false),
+                    ('|', _) => (Token::Pipe, false),
+                    (',', _) => (Token::Comma, false),
+                    ('.', _) => (Token::Dot, false),
+                    ('(', _) => (Token::LParen, false),
+                    (')', _) => (Token::RParen, false),
+                    ('[', _) => (Token::LBracket, false),
+                    (']', _) => (Token::RBracket, false),
+                    ('{', _) => (Token::LBrace, false),
+                    ('}', _) => (Token::RBrace, false),
+                    _ => {
+                        return Err(Error::parse(
+                            format!("Unexpected character '{}'", c),
+                            position,
+                            source,
+                        ));
+                    },
+                };
+                if two_chars {
+                    chars.next();
+                }
+                token
+            },
+        };
+
+        tokens.push((token, position));
+    }
+
+    Ok(tokens)
+}
+
+/// Consume an identifier (letters, digits, `_`) starting at the current position
+fn take_identifier<'a>(
+    source: &'a str,
+    chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>,
+) -> &'a str {
+    let start = chars.peek().map_or(source.len(), |&(i, _)| i);
+    let mut end = start;
+    while let Some(&(i, ch)) = chars.peek() {
+        if ch.is_alphanumeric() || ch == '_' {
+            end = i + ch.len_utf8();
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    &source[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(source: &str) -> Vec<Token> {
+        tokenize(source).unwrap().into_iter().map(|(token, _)| token).collect()
+    }
+
+    #[test]
+    fn test_tokenize_operators() {
+        assert_eq!(
+            tokens("a || b | c >= 1.5"),
+            vec![
+                Token::Ident("a".to_string()),
+                Token::OrOr,
+                Token::Ident("b".to_string()),
+                Token::Pipe,
+                Token::Ident("c".to_string()),
+                Token::GtEq,
+                Token::Number("1.5".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_strings() {
+        assert_eq!(tokens("'a | b ? c'"), vec![Token::String("a | b ? c".to_string())]);
+        assert_eq!(tokens(r#""it\"s""#), vec![Token::String("it\"s".to_string())]);
+        assert!(tokenize("'unterminated").is_err());
+        assert!(tokenize("'trailing\\").is_err());
+    }
+
+    #[test]
+    fn test_tokenize_path() {
+        assert_eq!(
+            tokens("$input.items.0"),
+            vec![
+                Token::Variable("input".to_string()),
+                Token::Dot,
+                Token::Ident("items".to_string()),
+                Token::Dot,
+                Token::Number("0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_null_operators() {
+        assert_eq!(
+            tokens("$input.user?.name ?? 'x'"),
+            vec![
+                Token::Variable("input".to_string()),
+                Token::Dot,
+                Token::Ident("user".to_string()),
+                Token::QuestionDot,
+                Token::Ident("name".to_string()),
+                Token::QuestionQuestion,
+                Token::String("x".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,605 @@
+//! Expression parser
+//!
+//! Expressions are tokenized and then parsed by precedence climbing. From
+//! lowest to highest binding:
+//!
+//! 1. ternary `cond ? a : b` (right associative)
+//! 2. pipeline `value | func | func(arg)`
+//! 3. `??`
+//! 4. `||`
+//! 5. `&&`
+//! 6. `==`, `!=`
+//! 7. `<`, `<=`, `>`, `>=`
+//! 8. `+`, `-`
+//! 9. `*`, `/`, `%`
+//! 10. unary `!`, `-`
+//! 11. literals, data access, function calls, parentheses
+//!
+//! Bare names refer to template variables bound by an earlier
+//! `{{ let name = ... }}`; any other bare name is a parse error.
+
+mod lexer;
+
+use crate::{
+    context::DataSource,
+    error::{Error, Result},
+    template::{BinaryOperator, ExpressionAst, PipelineFunction, UnaryOperator},
+    value::Value,
+};
+use lexer::Token;
+use std::collections::HashSet;
+
+/// Names that cannot be bound with `let`
+const RESERVED_NAMES: [&str; 6] = ["true", "false", "null", "if", "include", "let"];
+
+/// Parse the content of a `{{ ... }}` block into an expression tree
+pub(crate) fn parse_expression(source: &str) -> Result<ExpressionAst> {
+    parse_expression_with_locals(source, &HashSet::new())
+}
+
+/// [`parse_expression`] where the bare names in `locals` refer to template
+/// variables
+pub(crate) fn parse_expression_with_locals(
+    source: &str,
+    locals: &HashSet<String>,
+) -> Result<ExpressionAst> {
+    let mut parser = Parser {
+        source,
+        tokens: lexer::tokenize(source)?,
+        pos: 0,
+        locals,
+    };
+
+    if parser.tokens.is_empty() {
+        return Err(Error::parse("Empty expression", 0, source));
+    }
+
+    let ast = parser.parse_ternary()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(parser.error("Unexpected token"));
+    }
+
+    Ok(ast)
+}
+
+/// Recognise an `include 'name'` directive, returning the partial name
+///
+/// Returns `None` for any other expression.
+pub(crate) fn parse_include(source: &str) -> Result<Option<String>> {
+    let tokens = lexer::tokenize(source)?;
+    match tokens.as_slice() {
+        [(Token::Ident(keyword), _), rest @ ..] if keyword == "include" => match rest {
+            [(Token::String(name), _)] => Ok(Some(name.clone())),
+            _ => Err(Error::parse(
+                "Expected a quoted partial name after 'include'",
+                rest.first().map_or(source.len(), |&(_, position)| position),
+                source,
+            )),
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Recognise a `let name = expression` assignment, returning the name and
+/// the source of the assigned expression
+///
+/// Returns `None` for any other expression.
+pub(crate) fn parse_assignment(source: &str) -> Result<Option<(String, &str)>> {
+    let tokens = lexer::tokenize(source)?;
+    let [(Token::Ident(keyword), _), rest @ ..] = tokens.as_slice() else {
+        return Ok(None);
+    };
+    if keyword != "let" {
+        return Ok(None);
+    }
+
+    match rest {
+        [(Token::Ident(name), position), ..] if RESERVED_NAMES.contains(&name.as_str()) => {
+            let message = format!("'{}' cannot be used as a variable name", name);
+            Err(Error::parse(message, *position, source))
+        }
+        [(Token::Ident(name), _), (Token::Eq, _), (_, start), ..] => {
+            Ok(Some((name.clone(), &source[*start..])))
+        }
+        [(Token::Ident(_), _), (Token::Eq, position)] => {
+            Err(Error::parse("Expected an expression after '='", *position + 1, source))
+        }
+        [(Token::Ident(_), _), rest @ ..] => Err(Error::parse(
+            "Expected '=' after variable name",
+            rest.first().map_or(source.len(), |&(_, position)| position),
+            source,
+        )),
+        _ => Err(Error::parse(
+            "Expected a variable name after 'let'",
+            rest.first().map_or(source.len(), |&(_, position)| position),
+            source,
+        )),
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    /// Template variables bound so far
+    locals: &'a HashSet<String>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(token, _)| token.clone());
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    /// Consume the next token if it equals `expected`
+    fn eat(&mut self, expected: &Token) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: &Token, what: &str) -> Result<()> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected {}", what)))
+        }
+    }
+
+    /// Parse error at the current token (or the end of the expression)
+    fn error(&self, message: impl Into<String>) -> Error {
+        let position = self.tokens.get(self.pos).map_or(self.source.len(), |(_, pos)| *pos);
+        Error::parse(message, position, self.source)
+    }
+
+    fn parse_ternary(&mut self) -> Result<ExpressionAst> {
+        let condition = self.parse_pipeline()?;
+        if !self.eat(&Token::Question) {
+            return Ok(condition);
+        }
+
+        let then_expr = self.parse_ternary()?;
+        self.expect(&Token::Colon, "':' in ternary expression")?;
+        let else_expr = self.parse_ternary()?;
+
+        Ok(ExpressionAst::Ternary {
+            condition: Box::new(condition),
+            then_expr: Box::new(then_expr),
+            else_expr: Box::new(else_expr),
+        })
+    }
+
+    fn parse_pipeline(&mut self) -> Result<ExpressionAst> {
+        let input = self.parse_binary(1)?;
+        let mut functions = Vec::new();
+
+        while self.eat(&Token::Pipe) {
+            let Some(Token::Ident(name)) = self.peek().cloned() else {
+                return Err(self.error("Expected function name after '|'"));
+            };
+            self.pos += 1;
+            let args = if self.eat(&Token::LParen) {
+                self.parse_list(&Token::RParen, "')'")?
+            } else {
+                Vec::new()
+            };
+            functions.push(PipelineFunction { name, args });
+        }
+
+        if functions.is_empty() {
+            Ok(input)
+        } else {
+            Ok(ExpressionAst::Pipeline {
+                input: Box::new(input),
+                functions,
+            })
+        }
+    }
+
+    fn parse_binary(&mut self, min_precedence: u8) -> Result<ExpressionAst> {
+        let mut left = self.parse_unary()?;
+
+        while let Some((precedence, operator)) = self.peek().and_then(binary_operator) {
+            if precedence < min_precedence {
+                break;
+            }
+            self.pos += 1;
+            let right = self.parse_binary(precedence + 1)?;
+            left = ExpressionAst::BinaryOp {
+                left: Box::new(left),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<ExpressionAst> {
+        let operator = match self.peek() {
+            Some(Token::Bang) => UnaryOperator::Not,
+            Some(Token::Minus) => UnaryOperator::Minus,
+            _ => return self.parse_primary(),
+        };
+        self.pos += 1;
+
+        let operand = self.parse_unary()?;
+        // Fold negative number literals so `-5` stays an integer literal
+        match (&operator, operand) {
+            (UnaryOperator::Minus, ExpressionAst::Literal(Value::Integer(i))) => {
+                Ok(ExpressionAst::Literal(Value::integer(-i)))
+            }
+            (UnaryOperator::Minus, ExpressionAst::Literal(Value::Float(f))) => {
+                Ok(ExpressionAst::Literal(Value::float(-f)))
+            }
+            (_, operand) => Ok(ExpressionAst::UnaryOp {
+                operator,
+                operand: Box::new(operand),
+            }),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<ExpressionAst> {
+        let Some(token) = self.next() else {
+            return Err(self.error("Unexpected end of expression"));
+        };
+
+        match token {
+            Token::Number(text) => {
+                let value = match text.parse::<i64>() {
+                    Ok(i) => Value::integer(i),
+                    Err(_) => Value::float(
+                        text.parse::<f64>().map_err(|_| self.error("Invalid number"))?,
+                    ),
+                };
+                Ok(ExpressionAst::Literal(value))
+            }
+            Token::String(s) => Ok(ExpressionAst::Literal(Value::string(s))),
+            Token::Variable(name) => self.parse_data_access(&name),
+            Token::Ident(name) => match name.as_str() {
+                "true" => Ok(ExpressionAst::Literal(Value::bool(true))),
+                "false" => Ok(ExpressionAst::Literal(Value::bool(false))),
+                "null" => Ok(ExpressionAst::Literal(Value::null())),
+                _ if self.eat(&Token::LParen) => {
+                    let args = self.parse_list(&Token::RParen, "')'")?;
+                    if name == "if" {
+                        if_function(args).ok_or_else(|| {
+                            Error::parse("If function requires 2 or 3 arguments", 0, self.source)
+                        })
+                    } else {
+                        Ok(ExpressionAst::FunctionCall { name, args })
+                    }
+                }
+                _ if self.locals.contains(&name) => self.parse_path(DataSource::Local(name)),
+                _ => {
+                    self.pos -= 1;
+                    Err(self.error(format!("Unknown identifier '{}'", name)))
+                }
+            },
+            Token::LParen => {
+                let inner = self.parse_ternary()?;
+                self.expect(&Token::RParen, "')'")?;
+                Ok(inner)
+            }
+            Token::LBracket => Ok(ExpressionAst::ArrayLiteral(
+                self.parse_list(&Token::RBracket, "']'")?,
+            )),
+            Token::LBrace => self.parse_object_literal(),
+            _ => {
+                self.pos -= 1;
+                Err(self.error("Unexpected token"))
+            }
+        }
+    }
+
+    /// Parse comma-separated expressions up to and including `close`
+    fn parse_list(&mut self, close: &Token, what: &str) -> Result<Vec<ExpressionAst>> {
+        let mut items = Vec::new();
+        if self.eat(close) {
+            return Ok(items);
+        }
+
+        loop {
+            items.push(self.parse_ternary()?);
+            if self.eat(close) {
+                return Ok(items);
+            }
+            self.expect(&Token::Comma, &format!("',' or {}", what))?;
+        }
+    }
+
+    fn parse_object_literal(&mut self) -> Result<ExpressionAst> {
+        let mut members = Vec::new();
+        if self.eat(&Token::RBrace) {
+            return Ok(ExpressionAst::ObjectLiteral(members));
+        }
+
+        loop {
+            let Some(Token::String(key) | Token::Ident(key)) = self.peek().cloned() else {
+                return Err(self.error("Expected object key"));
+            };
+            self.pos += 1;
+            self.expect(&Token::Colon, "':' after object key")?;
+            members.push((key, self.parse_ternary()?));
+
+            if self.eat(&Token::RBrace) {
+                return Ok(ExpressionAst::ObjectLiteral(members));
+            }
+            self.expect(&Token::Comma, "',' or '}'")?;
+        }
+    }
+
+    /// Parse a data source reference and its path
+    fn parse_data_access(&mut self, name: &str) -> Result<ExpressionAst> {
+        let source = match name {
+            "input" => DataSource::Input,
+            "node" => {
+                self.expect(&Token::LParen, "'(' after $node")?;
+                let Some(Token::String(id)) = self.next() else {
+                    return Err(Error::parse("Invalid node reference", 0, self.source));
+                };
+                self.expect(&Token::RParen, "')'")?;
+                DataSource::Node(id)
+            }
+            "env" => DataSource::Environment,
+            "system" => DataSource::System,
+            "execution" => DataSource::Execution,
+            "workflow" => DataSource::Workflow,
+            _ => {
+                self.pos -= 1;
+                return Err(self.error(format!("Unknown data source '${}'", name)));
+            }
+        };
+        self.parse_path(source)
+    }
+
+    /// Parse the `.key` / `?.key` / `[index]` path following a data source
+    fn parse_path(&mut self, source: DataSource) -> Result<ExpressionAst> {
+        let mut path = String::new();
+        let mut optional = false;
+        loop {
+            let dot = self.eat(&Token::Dot);
+            if dot || self.eat(&Token::QuestionDot) {
+                optional |= !dot;
+                let segment = match self.next() {
+                    Some(Token::Ident(segment) | Token::Number(segment)) => segment,
+                    _ => return Err(self.error("Expected property name after '.'")),
+                };
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(&segment);
+            } else if self.eat(&Token::LBracket) {
+                let Some(Token::Number(index)) = self.next() else {
+                    return Err(self.error("Expected array index"));
+                };
+                self.expect(&Token::RBracket, "']'")?;
+                path.push_str(&format!("[{}]", index));
+            } else {
+                break;
+            }
+        }
+
+        // `$node('id').json.field` addresses the node's output directly
+        if matches!(source, DataSource::Node(_)) {
+            if path == "json" {
+                path.clear();
+            } else if let Some(rest) = path.strip_prefix("json.") {
+                path = rest.to_string();
+            }
+        }
+
+        Ok(ExpressionAst::DataAccess { source, path, optional })
+    }
+}
+
+/// Precedence and operator for a binary operator token
+fn binary_operator(token: &Token) -> Option<(u8, BinaryOperator)> {
+    let operator = match token {
+        Token::QuestionQuestion => (1, BinaryOperator::Coalesce),
+        Token::OrOr => (2, BinaryOperator::Or),
+        Token::AndAnd => (3, BinaryOperator::And),
+        Token::EqEq => (4, BinaryOperator::Equal),
+        Token::NotEq => (4, BinaryOperator::NotEqual),
+        Token::Lt => (5, BinaryOperator::LessThan),
+        Token::LtEq => (5, BinaryOperator::LessEqual),
+        Token::Gt => (5, BinaryOperator::GreaterThan),
+        Token::GtEq => (5, BinaryOperator::GreaterEqual),
+        Token::Plus => (6, BinaryOperator::Add),
+        Token::Minus => (6, BinaryOperator::Subtract),
+        Token::Star => (7, BinaryOperator::Multiply),
+        Token::Slash => (7, BinaryOperator::Divide),
+        Token::Percent => (7, BinaryOperator::Modulo),
+        _ => return None,
+    };
+    Some(operator)
+}
+
+/// Build an `if(condition, then, else?)` node from its arguments
+fn if_function(args: Vec<ExpressionAst>) -> Option<ExpressionAst> {
+    let mut args = args.into_iter();
+    let (Some(condition), Some(then_expr), else_expr, None) =
+        (args.next(), args.next(), args.next(), args.next())
+    else {
+        return None;
+    };
+
+    Some(ExpressionAst::IfFunction {
+        condition: Box::new(condition),
+        then_expr: Box::new(then_expr),
+        else_expr: else_expr.map(Box::new),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn literal(value: i64) -> Box<ExpressionAst> {
+        Box::new(ExpressionAst::Literal(Value::integer(value)))
+    }
+
+    #[test]
+    fn test_precedence() -> Result<()> {
+        assert_eq!(
+            parse_expression("1 + 2 * 3")?,
+            ExpressionAst::BinaryOp {
+                left: literal(1),
+                operator: BinaryOperator::Add,
+                right: Box::new(ExpressionAst::BinaryOp {
+                    left: literal(2),
+                    operator: BinaryOperator::Multiply,
+                    right: literal(3),
+                }),
+            }
+        );
+
+        assert_eq!(
+            parse_expression("(1 + 2) * 3")?,
+            ExpressionAst::BinaryOp {
+                left: Box::new(ExpressionAst::BinaryOp {
+                    left: literal(1),
+                    operator: BinaryOperator::Add,
+                    right: literal(2),
+                }),
+                operator: BinaryOperator::Multiply,
+                right: literal(3),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pipeline_in_ternary_branch() -> Result<()> {
+        let ExpressionAst::Ternary { then_expr, else_expr, .. } =
+            parse_expression("$input.ok ? $input.name | upper : 'none' | lower")?
+        else {
+            panic!("expected ternary");
+        };
+
+        assert!(matches!(*then_expr, ExpressionAst::Pipeline { .. }));
+        assert!(matches!(*else_expr, ExpressionAst::Pipeline { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_data_access_paths() -> Result<()> {
+        assert_eq!(
+            parse_expression("$input.items[0].name")?,
+            ExpressionAst::DataAccess {
+                source: DataSource::Input,
+                path: "items[0].name".to_string(),
+                optional: false,
+            }
+        );
+        assert_eq!(
+            parse_expression("$node('fetch').json.body")?,
+            ExpressionAst::DataAccess {
+                source: DataSource::node("fetch"),
+                path: "body".to_string(),
+                optional: false,
+            }
+        );
+        assert_eq!(
+            parse_expression("$input.user?.name")?,
+            ExpressionAst::DataAccess {
+                source: DataSource::Input,
+                path: "user.name".to_string(),
+                optional: true,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_standalone_function_calls() -> Result<()> {
+        let tags = ExpressionAst::DataAccess {
+            source: DataSource::Input,
+            path: "tags".to_string(),
+            optional: false,
+        };
+        assert_eq!(
+            parse_expression("join($input.tags, ', ')")?,
+            ExpressionAst::FunctionCall {
+                name: "join".to_string(),
+                args: vec![tags.clone(), ExpressionAst::Literal(Value::string(", "))],
+            }
+        );
+
+        // Commas and parentheses inside quotes stay in their argument
+        assert_eq!(
+            parse_expression("join($input.tags, '), (')")?,
+            ExpressionAst::FunctionCall {
+                name: "join".to_string(),
+                args: vec![tags, ExpressionAst::Literal(Value::string("), ("))],
+            }
+        );
+
+        assert_eq!(
+            parse_expression("upper(trim($input.name))")?,
+            ExpressionAst::FunctionCall {
+                name: "upper".to_string(),
+                args: vec![ExpressionAst::FunctionCall {
+                    name: "trim".to_string(),
+                    args: vec![ExpressionAst::DataAccess {
+                        source: DataSource::Input,
+                        path: "name".to_string(),
+                        optional: false,
+                    }],
+                }],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for source in ["", "1 +", "(1", "$unknown", "foo", "1 2", "a ? b", "if(1)"] {
+            let result = parse_expression(source);
+            assert!(result.is_err(), "expected error for {:?}", source);
+            assert!(result.unwrap_err().is_parse_error());
+        }
+    }
+
+    #[test]
+    fn test_assignment() -> Result<()> {
+        assert_eq!(
+            parse_assignment("let total = $input.a * 2")?,
+            Some(("total".to_string(), "$input.a * 2"))
+        );
+        assert_eq!(parse_assignment("$input.a == 1")?, None);
+        assert_eq!(parse_assignment("letter")?, None);
+        for source in ["let", "let x", "let x 1", "let x =", "let true = 1", "let 1 = 2"] {
+            let result = parse_assignment(source);
+            assert!(result.is_err(), "expected error for {:?}", source);
+            assert!(result.unwrap_err().is_parse_error());
+        }
+
+        let locals = HashSet::from(["total".to_string()]);
+        assert_eq!(
+            parse_expression_with_locals("total.net", &locals)?,
+            ExpressionAst::DataAccess {
+                source: DataSource::Local("total".to_string()),
+                path: "net".to_string(),
+                optional: false,
+            }
+        );
+        assert!(parse_expression_with_locals("other", &locals).is_err());
+        assert!(parse_expression("total").is_err());
+        Ok(())
+    }
+}
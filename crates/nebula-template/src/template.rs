@@ -4,25 +4,33 @@ use crate::{
     context::{Context, DataSource},
     error::{Error, Result},
     functions::FunctionRegistry,
+    parser,
     value::Value,
 };
+use indexmap::IndexMap;
 use std::{
-    collections::HashSet,
+    borrow::Cow,
+    collections::{HashMap, HashSet},
     fmt,
-    sync::Arc,
+    io::Write,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
 };
 
 /// A parsed template that can be rendered with different contexts
+///
+/// The parsed form is shared, so cloning a template is cheap.
 #[derive(Debug, Clone)]
 pub struct Template {
     /// Original template string
-    source: String,
+    source: Arc<str>,
     /// Parsed template elements
-    elements: Vec<TemplateElement>,
+    elements: Arc<[TemplateElement]>,
     /// Dependencies found during parsing
-    dependencies: TemplateDependencies,
+    dependencies: Arc<TemplateDependencies>,
     /// Function registry to use for evaluation
     functions: Arc<FunctionRegistry>,
+    /// Escaping and whitespace policy the template was parsed with
+    config: TemplateConfig,
 }
 
 /// Elements that make up a template
@@ -32,8 +40,158 @@ pub enum TemplateElement {
     Text(String),
     /// Expression to be evaluated
     Expression(Expression),
+    /// Named partial rendered in place: `{{ include 'header' }}`
+    Include(String),
+    /// Template variable binding: `{{ let total = $input.price * $input.qty }}`
+    ///
+    /// Renders nothing; later elements refer to the value by its bare name.
+    Assignment {
+        /// Variable name
+        name: String,
+        /// Expression whose value is bound
+        value: Expression,
+    },
+}
+
+/// How [`Template::render_lenient`] substitutes elements that fail to render
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Render nothing in place of the element
+    Empty,
+    /// Render the given marker in place of the element
+    Placeholder(String),
+    /// Re-emit the element's `{{ ... }}` source
+    Keep,
+}
+
+/// How expression output is escaped when rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EscapeMode {
+    /// Output expression results verbatim
+    #[default]
+    None,
+    /// Escape `&`, `<`, `>`, `"` and `'` as HTML entities
+    Html,
+}
+
+impl EscapeMode {
+    /// Escape rendered expression output according to this mode
+    pub fn escape(self, text: &str) -> String {
+        match self {
+            Self::None => text.to_string(),
+            Self::Html => {
+                let mut escaped = String::with_capacity(text.len());
+                for c in text.chars() {
+                    match c {
+                        '&' => escaped.push_str("&amp;"),
+                        '<' => escaped.push_str("&lt;"),
+                        '>' => escaped.push_str("&gt;"),
+                        '"' => escaped.push_str("&quot;"),
+                        '\'' => escaped.push_str("&#39;"),
+                        _ => escaped.push(c),
+                    }
+                }
+                escaped
+            }
+        }
+    }
+}
+
+/// Parse-time policy for a [`Template`], set once with
+/// [`Template::parse_with_config`]
+///
+/// Block tags are directives such as `{{ include 'name' }}` and `{{ let x = ... }}`;
+/// expression tags are never trimmed. Partials are parsed with the including
+/// template's config.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TemplateConfig {
+    /// Escaping applied to every expression result
+    pub auto_escape: EscapeMode,
+    /// Remove the first newline after a block tag
+    pub trim_blocks: bool,
+    /// Remove spaces and tabs between the start of a line and a block tag
+    pub lstrip_blocks: bool,
 }
 
+/// Number of templates [`Template::parse_cached`] keeps by default
+const DEFAULT_PARSE_CACHE_CAPACITY: usize = 256;
+
+/// Counters for the cache behind [`Template::parse_cached`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseCacheStats {
+    /// Lookups served from the cache
+    pub hits: u64,
+    /// Lookups that had to parse the source
+    pub misses: u64,
+    /// Templates currently cached
+    pub len: usize,
+    /// Maximum number of cached templates
+    pub capacity: usize,
+}
+
+/// Least-recently-used cache of parsed templates
+struct ParseCache {
+    /// Templates keyed by source and function registry address, least
+    /// recently used first
+    entries: IndexMap<(String, usize), Template>,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl ParseCache {
+    fn get(&mut self, key: &(String, usize)) -> Option<Template> {
+        let Some(index) = self.entries.get_index_of(key) else {
+            self.misses += 1;
+            return None;
+        };
+        self.hits += 1;
+        let last = self.entries.len() - 1;
+        self.entries.move_index(index, last);
+        Some(self.entries[last].clone())
+    }
+
+    fn insert(&mut self, key: (String, usize), template: Template) {
+        self.entries.insert(key, template);
+        self.evict();
+    }
+
+    /// Drop least recently used entries until the cache fits its capacity
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            self.entries.shift_remove_index(0);
+        }
+    }
+}
+
+/// The process-wide cache used by [`Template::parse_cached`]
+fn parse_cache() -> &'static Mutex<ParseCache> {
+    static CACHE: OnceLock<Mutex<ParseCache>> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        Mutex::new(ParseCache {
+            entries: IndexMap::new(),
+            capacity: DEFAULT_PARSE_CACHE_CAPACITY,
+            hits: 0,
+            misses: 0,
+        })
+    })
+}
+
+/// Lock the parse cache; a panic while holding it can't leave it inconsistent
+fn lock_parse_cache() -> std::sync::MutexGuard<'static, ParseCache> {
+    parse_cache().lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Built-in functions shared by every [`Template::parse_cached`] template, so
+/// they all have the same registry identity
+fn shared_builtins() -> &'static Arc<FunctionRegistry> {
+    static BUILTINS: OnceLock<Arc<FunctionRegistry>> = OnceLock::new();
+    BUILTINS.get_or_init(|| Arc::new(FunctionRegistry::with_builtins()))
+}
+
+/// Maximum nesting of `{{ include }}` directives during rendering
+const MAX_INCLUDE_DEPTH: usize = 32;
+
 /// An expression within a template
 #[derive(Debug, Clone, PartialEq)]
 pub struct Expression {
@@ -50,9 +208,13 @@ pub enum ExpressionAst {
     Literal(Value),
 
     /// Data source access: $input.path, $node('id').path, etc.
+    ///
+    /// `optional` is set when the path uses `?.`, e.g. `$input.user?.name`;
+    /// a missing or null segment then yields null instead of an error.
     DataAccess {
         source: DataSource,
         path: String,
+        optional: bool,
     },
 
     /// Function call: uppercase(), default('fallback'), etc.
@@ -93,6 +255,12 @@ pub enum ExpressionAst {
         then_expr: Box<ExpressionAst>,
         else_expr: Option<Box<ExpressionAst>>,
     },
+
+    /// Array literal: [1, $input.x]
+    ArrayLiteral(Vec<ExpressionAst>),
+
+    /// Object literal: { "name": $input.name }
+    ObjectLiteral(Vec<(String, ExpressionAst)>),
 }
 
 /// Function call in a pipeline
@@ -111,6 +279,8 @@ pub enum BinaryOperator {
     Equal, NotEqual, LessThan, LessEqual, GreaterThan, GreaterEqual,
     // Logical
     And, Or,
+    // Null-coalescing: `left ?? right`
+    Coalesce,
     // String
     Contains, StartsWith, EndsWith,
 }
@@ -139,6 +309,77 @@ pub struct TemplateDependencies {
     pub uses_workflow: bool,
     /// Functions used in the template
     pub functions: HashSet<String>,
+    /// Partials included by the template (not including nested includes)
+    pub partials: HashSet<String>,
+}
+
+impl TemplateDependencies {
+    /// Add every dependency of `other` to this set
+    pub fn merge(&mut self, other: &TemplateDependencies) {
+        self.input_paths.extend(other.input_paths.iter().cloned());
+        self.node_ids.extend(other.node_ids.iter().cloned());
+        self.env_vars.extend(other.env_vars.iter().cloned());
+        self.uses_system |= other.uses_system;
+        self.uses_execution |= other.uses_execution;
+        self.uses_workflow |= other.uses_workflow;
+        self.functions.extend(other.functions.iter().cloned());
+        self.partials.extend(other.partials.iter().cloned());
+    }
+
+    /// Build a sorted report of these dependencies, checking functions
+    /// against `functions`
+    pub fn report(&self, functions: &FunctionRegistry) -> DependencyReport {
+        let sorted = |set: &HashSet<String>| {
+            let mut items: Vec<String> = set.iter().cloned().collect();
+            items.sort();
+            items
+        };
+
+        let functions_used = sorted(&self.functions);
+        let unknown_functions =
+            functions_used.iter().filter(|name| !functions.contains(name)).cloned().collect();
+
+        let node_ids = sorted(&self.node_ids);
+        let env_vars = sorted(&self.env_vars);
+        let mut required_sources = Vec::new();
+        if !self.input_paths.is_empty() {
+            required_sources.push("$input".to_string());
+        }
+        required_sources.extend(node_ids.iter().map(|id| format!("$node('{}')", id)));
+        required_sources.extend(env_vars.iter().map(|var| format!("$env.{}", var)));
+
+        DependencyReport {
+            input_paths: sorted(&self.input_paths),
+            node_ids,
+            env_vars,
+            functions: functions_used,
+            unknown_functions,
+            partials: sorted(&self.partials),
+            required_sources,
+        }
+    }
+}
+
+/// Static analysis of a template's (or a set of templates') dependencies
+///
+/// All lists are sorted so reports are stable across runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyReport {
+    /// Input data paths used
+    pub input_paths: Vec<String>,
+    /// Node IDs referenced
+    pub node_ids: Vec<String>,
+    /// Environment variables used
+    pub env_vars: Vec<String>,
+    /// Functions called
+    pub functions: Vec<String>,
+    /// Functions called that are missing from the registry
+    pub unknown_functions: Vec<String>,
+    /// Partials included directly
+    pub partials: Vec<String>,
+    /// Data sources the context must provide, as checked by
+    /// [`Template::validate_context`]: `$input`, `$node('id')` and `$env.NAME`
+    pub required_sources: Vec<String>,
 }
 
 impl Template {
@@ -149,35 +390,338 @@ impl Template {
 
     /// Parse a template with custom functions
     pub fn parse_with_functions(source: &str, functions: Arc<FunctionRegistry>) -> Result<Self> {
-        let mut parser = TemplateParser::new(source, functions.clone());
-        let elements = parser.parse()?;
+        Self::parse_with(source, functions, TemplateConfig::default())
+    }
+
+    /// Parse a template with an escaping and whitespace policy
+    ///
+    /// The config is kept on the template and applied on every render.
+    pub fn parse_with_config(source: &str, config: TemplateConfig) -> Result<Self> {
+        Self::parse_with(source, Arc::new(FunctionRegistry::with_builtins()), config)
+    }
+
+    /// Parse a template with custom functions and a config
+    pub fn parse_with(
+        source: &str,
+        functions: Arc<FunctionRegistry>,
+        config: TemplateConfig,
+    ) -> Result<Self> {
+        Self::parse_in_scope(source, functions, config, HashSet::new())
+    }
+
+    /// [`Template::parse_with`] where the variables in `locals` are already
+    /// bound
+    fn parse_in_scope(
+        source: &str,
+        functions: Arc<FunctionRegistry>,
+        config: TemplateConfig,
+        locals: HashSet<String>,
+    ) -> Result<Self> {
+        let mut parser = TemplateParser::new(source, functions.clone(), locals);
+        let mut elements = parser.parse()?;
+        trim_block_whitespace(&mut elements, &config);
         let dependencies = parser.extract_dependencies(&elements);
 
         Ok(Self {
-            source: source.to_string(),
-            elements,
-            dependencies,
+            source: source.into(),
+            elements: elements.into(),
+            dependencies: Arc::new(dependencies),
             functions,
+            config,
         })
     }
 
+    /// Parse a template with the built-in functions, reusing an earlier parse
+    /// of the same source
+    ///
+    /// Parsed templates are kept in a process-wide least-recently-used cache
+    /// (see [`Template::set_parse_cache_capacity`]); a hit returns a cheap
+    /// clone of the cached template. Parse errors are not cached.
+    pub fn parse_cached(source: &str) -> Result<Self> {
+        Self::parse_cached_with_functions(source, shared_builtins().clone())
+    }
+
+    /// [`Template::parse_cached`] with custom functions
+    ///
+    /// Entries are keyed by the registry's identity, not its contents, so
+    /// pass the same `Arc` on every call to get cache hits.
+    pub fn parse_cached_with_functions(
+        source: &str,
+        functions: Arc<FunctionRegistry>,
+    ) -> Result<Self> {
+        let key = (source.to_string(), Arc::as_ptr(&functions) as usize);
+        if let Some(template) = lock_parse_cache().get(&key) {
+            return Ok(template);
+        }
+
+        // Parse without holding the lock; a concurrent miss just parses twice
+        let template = Self::parse_with_functions(source, functions)?;
+        lock_parse_cache().insert(key, template.clone());
+        Ok(template)
+    }
+
+    /// Set how many templates [`Template::parse_cached`] keeps, evicting the
+    /// least recently used ones if there are more
+    ///
+    /// A capacity of zero disables caching.
+    pub fn set_parse_cache_capacity(capacity: usize) {
+        let mut cache = lock_parse_cache();
+        cache.capacity = capacity;
+        cache.evict();
+    }
+
+    /// Remove every template from the [`Template::parse_cached`] cache
+    ///
+    /// The hit and miss counters are kept.
+    pub fn clear_parse_cache() {
+        lock_parse_cache().entries.clear();
+    }
+
+    /// Counters and size of the [`Template::parse_cached`] cache
+    pub fn parse_cache_stats() -> ParseCacheStats {
+        let cache = lock_parse_cache();
+        ParseCacheStats {
+            hits: cache.hits,
+            misses: cache.misses,
+            len: cache.entries.len(),
+            capacity: cache.capacity,
+        }
+    }
+
+    /// Parse a template, rejecting calls to functions missing from `functions`
+    ///
+    /// Unlike [`Template::parse_with_functions`], which only fails at render
+    /// time when a function cannot be found, this reports every unknown
+    /// function name as a parse error. Partials are parsed when rendered and
+    /// are not checked.
+    pub fn parse_strict(source: &str, functions: Arc<FunctionRegistry>) -> Result<Self> {
+        let template = Self::parse_with_functions(source, functions)?;
+
+        let mut unknown: Vec<&str> = template
+            .dependencies
+            .functions
+            .iter()
+            .map(String::as_str)
+            .filter(|name| !template.functions.contains(name))
+            .collect();
+        if unknown.is_empty() {
+            return Ok(template);
+        }
+
+        unknown.sort_unstable();
+        let position = unknown
+            .iter()
+            .filter_map(|name| source.find(name))
+            .min()
+            .unwrap_or(0);
+        Err(Error::parse(
+            format!("Unknown function(s): {}", unknown.join(", ")),
+            position,
+            source,
+        ))
+    }
+
     /// Render the template with the given context
     pub fn render(&self, context: &Context) -> Result<String> {
         let mut output = String::new();
+        self.render_into(context, &mut Vec::new(), &mut output, &mut |_, error, _| Err(error))?;
+        Ok(output)
+    }
 
-        for element in &self.elements {
-            match element {
-                TemplateElement::Text(text) => {
-                    output.push_str(text);
-                }
-                TemplateElement::Expression(expr) => {
-                    let value = expr.evaluate(context, &self.functions)?;
-                    output.push_str(&value.as_string()?);
-                }
+    /// Render the template, substituting any element that fails instead of
+    /// aborting
+    ///
+    /// Every other element is rendered normally, including elements of
+    /// partials. Returns the output together with the errors that occurred,
+    /// in render order.
+    pub fn render_lenient(&self, context: &Context, on_error: ErrorPolicy) -> (String, Vec<Error>) {
+        let mut output = String::new();
+        let mut errors = Vec::new();
+
+        let mut substitute = |element: &TemplateElement, error: Error, output: &mut String| {
+            match &on_error {
+                ErrorPolicy::Empty => {}
+                ErrorPolicy::Placeholder(marker) => output.push_str(marker),
+                ErrorPolicy::Keep => match element {
+                    TemplateElement::Expression(expr) => {
+                        output.push_str(&format!("{{{{ {} }}}}", expr.source()));
+                    }
+                    TemplateElement::Include(name) => {
+                        output.push_str(&format!("{{{{ include '{}' }}}}", name));
+                    }
+                    TemplateElement::Assignment { name, value } => {
+                        output.push_str(&format!("{{{{ let {} = {} }}}}", name, value.source()));
+                    }
+                    TemplateElement::Text(_) => {}
+                },
             }
+            errors.push(error);
+            Ok(())
+        };
+        // The handler never fails, so neither does rendering
+        let _ = self.render_into(context, &mut Vec::new(), &mut output, &mut substitute);
+
+        (output, errors)
+    }
+
+    /// Render into `output`, tracking the chain of partials being included
+    ///
+    /// `on_error` decides what happens when an element fails: returning the
+    /// error aborts rendering, returning `Ok` continues with the next element.
+    /// Variables bound by the template are only visible to its own later
+    /// elements and the partials they include.
+    fn render_into(
+        &self,
+        context: &Context,
+        include_stack: &mut Vec<String>,
+        output: &mut String,
+        on_error: &mut dyn FnMut(&TemplateElement, Error, &mut String) -> Result<()>,
+    ) -> Result<()> {
+        let mut scope = Cow::Borrowed(context);
+        for (index, element) in self.elements.iter().enumerate() {
+            Self::render_element(
+                index,
+                element,
+                &self.functions,
+                &self.config,
+                &mut scope,
+                include_stack,
+                output,
+                on_error,
+            )?;
         }
 
-        Ok(output)
+        Ok(())
+    }
+
+    /// Render a single element into `output`; see [`Template::render_into`]
+    ///
+    /// The context is copied the first time an assignment binds a variable.
+    /// Errors from expressions are wrapped with the expression's source and
+    /// `index`, the element's position in its template.
+    fn render_element(
+        index: usize,
+        element: &TemplateElement,
+        functions: &Arc<FunctionRegistry>,
+        config: &TemplateConfig,
+        context: &mut Cow<'_, Context>,
+        include_stack: &mut Vec<String>,
+        output: &mut String,
+        on_error: &mut dyn FnMut(&TemplateElement, Error, &mut String) -> Result<()>,
+    ) -> Result<()> {
+        let rendered = match element {
+            TemplateElement::Text(text) => {
+                output.push_str(text);
+                Ok(())
+            }
+            TemplateElement::Expression(expr) => expr
+                .evaluate(context, functions)
+                .and_then(|value| value.as_string())
+                .map(|text| output.push_str(&config.auto_escape.escape(&text)))
+                .map_err(|error| Error::in_expression(expr.source(), index, error)),
+            TemplateElement::Include(name) => Self::render_partial(
+                name,
+                functions,
+                config,
+                context,
+                include_stack,
+                output,
+                on_error,
+            ),
+            TemplateElement::Assignment { name, value } => value
+                .evaluate(context, functions)
+                .map(|result| context.to_mut().set_local(name.clone(), result))
+                .map_err(|error| Error::in_expression(value.source(), index, error)),
+        };
+        match rendered {
+            Ok(()) => Ok(()),
+            Err(error) => on_error(element, error, output),
+        }
+    }
+
+    /// Parse a template and render it to `writer` element by element
+    ///
+    /// Produces the same output as [`Template::parse`] followed by
+    /// [`Template::render`], but never holds the parsed template in memory:
+    /// each piece of text or expression result is written as soon as it is
+    /// parsed and evaluated. Errors are the same as for parsing and
+    /// rendering; output written before an error is left in `writer`.
+    pub fn render_streaming<W: Write>(
+        source: &str,
+        context: &Context,
+        writer: &mut W,
+    ) -> Result<()> {
+        let functions = Arc::new(FunctionRegistry::with_builtins());
+        Self::render_streaming_with_functions(source, functions, context, writer)
+    }
+
+    /// [`Template::render_streaming`] with custom functions
+    pub fn render_streaming_with_functions<W: Write>(
+        source: &str,
+        functions: Arc<FunctionRegistry>,
+        context: &Context,
+        writer: &mut W,
+    ) -> Result<()> {
+        let mut output = String::new();
+        let mut scope = Cow::Borrowed(context);
+        for (index, element) in TemplateElements::new(source, HashSet::new()).enumerate() {
+            Self::render_element(
+                index,
+                &element?,
+                &functions,
+                &TemplateConfig::default(),
+                &mut scope,
+                &mut Vec::new(),
+                &mut output,
+                &mut |_, error, _| Err(error),
+            )?;
+            writer
+                .write_all(output.as_bytes())
+                .map_err(|e| Error::custom(format!("Failed to write template output: {}", e)))?;
+            output.clear();
+        }
+
+        Ok(())
+    }
+
+    /// Parse and render a partial from the context with the given function
+    /// registry and config
+    fn render_partial(
+        name: &str,
+        functions: &Arc<FunctionRegistry>,
+        config: &TemplateConfig,
+        context: &Context,
+        include_stack: &mut Vec<String>,
+        output: &mut String,
+        on_error: &mut dyn FnMut(&TemplateElement, Error, &mut String) -> Result<()>,
+    ) -> Result<()> {
+        if include_stack.iter().any(|included| included == name) {
+            let mut chain = include_stack.clone();
+            chain.push(name.to_string());
+            return Err(Error::evaluation_with_context(
+                format!("Include cycle detected: {}", chain.join(" -> ")),
+                format!("include '{}'", name),
+            ));
+        }
+        if include_stack.len() >= MAX_INCLUDE_DEPTH {
+            return Err(Error::evaluation_with_context(
+                format!("Maximum include depth of {} exceeded", MAX_INCLUDE_DEPTH),
+                format!("include '{}'", name),
+            ));
+        }
+
+        let source = context.get_partial(name).ok_or_else(|| {
+            Error::data_not_found(format!("partial '{}'", name), context.partial_names())
+        })?;
+        // Variables bound by the including template are in scope
+        let locals = context.local_names().into_iter().collect();
+        let partial = Self::parse_in_scope(source, functions.clone(), *config, locals)?;
+
+        include_stack.push(name.to_string());
+        let rendered = partial.render_into(context, include_stack, output, on_error);
+        include_stack.pop();
+
+        rendered
     }
 
     /// Get the original template source
@@ -185,11 +729,21 @@ impl Template {
         &self.source
     }
 
+    /// Get the config the template was parsed with
+    pub fn config(&self) -> &TemplateConfig {
+        &self.config
+    }
+
     /// Get template dependencies
     pub fn dependencies(&self) -> &TemplateDependencies {
         &self.dependencies
     }
 
+    /// Analyze the template's dependencies against its function registry
+    pub fn analyze(&self) -> DependencyReport {
+        self.dependencies.report(&self.functions)
+    }
+
     /// Check if the template is static (no expressions)
     pub fn is_static(&self) -> bool {
         self.elements.iter().all(|e| matches!(e, TemplateElement::Text(_)))
@@ -225,6 +779,16 @@ impl Template {
             }
         }
 
+        // Check included partials
+        for partial in &self.dependencies.partials {
+            if context.get_partial(partial).is_none() {
+                return Err(Error::data_not_found(
+                    format!("partial '{}'", partial),
+                    context.partial_names(),
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -298,8 +862,11 @@ impl ExpressionAst {
         match self {
             Self::Literal(value) => Ok(value.clone()),
 
-            Self::DataAccess { source, path } => {
-                context.resolve_data_source(source, path)
+            Self::DataAccess { source, path, optional } => {
+                match context.resolve_data_source(source, path) {
+                    Err(err) if *optional && is_missing(&err) => Ok(Value::Null),
+                    result => result,
+                }
             }
 
             Self::FunctionCall { name, args } => {
@@ -311,7 +878,7 @@ impl ExpressionAst {
                     .map(|arg| arg.evaluate(context, functions))
                     .collect();
 
-                function.execute(arg_values?)
+                function.execute(function.signature().prepare_args(arg_values?)?)
             }
 
             Self::Pipeline { input, functions: pipeline_functions } => {
@@ -330,12 +897,20 @@ impl ExpressionAst {
                         args.push(arg.evaluate(context, functions)?);
                     }
 
-                    value = function.execute(args)?;
+                    value = function.execute(function.signature().prepare_args(args)?)?;
                 }
 
                 Ok(value)
             }
 
+            Self::BinaryOp { left, operator: BinaryOperator::Coalesce, right } => {
+                match left.evaluate(context, functions) {
+                    Ok(Value::Null) => right.evaluate(context, functions),
+                    Err(err) if is_missing(&err) => right.evaluate(context, functions),
+                    result => result,
+                }
+            }
+
             Self::BinaryOp { left, operator, right } => {
                 let left_val = left.evaluate(context, functions)?;
                 let right_val = right.evaluate(context, functions)?;
@@ -372,6 +947,15 @@ impl ExpressionAst {
                             Ok(Value::float(left_f / right_f))
                         }
                     }
+                    BinaryOperator::Modulo => {
+                        let left_f = left_val.as_float()?;
+                        let right_f = right_val.as_float()?;
+                        if right_f == 0.0 {
+                            Err(Error::math("Division by zero"))
+                        } else {
+                            Ok(Value::float(left_f % right_f))
+                        }
+                    }
                     BinaryOperator::Equal => {
                         Ok(Value::bool(left_val.equals(&right_val)))
                     }
@@ -383,6 +967,21 @@ impl ExpressionAst {
                         let right_f = right_val.as_float()?;
                         Ok(Value::bool(left_f < right_f))
                     }
+                    BinaryOperator::LessEqual => {
+                        let left_f = left_val.as_float()?;
+                        let right_f = right_val.as_float()?;
+                        Ok(Value::bool(left_f <= right_f))
+                    }
+                    BinaryOperator::GreaterThan => {
+                        let left_f = left_val.as_float()?;
+                        let right_f = right_val.as_float()?;
+                        Ok(Value::bool(left_f > right_f))
+                    }
+                    BinaryOperator::GreaterEqual => {
+                        let left_f = left_val.as_float()?;
+                        let right_f = right_val.as_float()?;
+                        Ok(Value::bool(left_f >= right_f))
+                    }
                     BinaryOperator::And => {
                         Ok(Value::bool(left_val.is_truthy() && right_val.is_truthy()))
                     }
@@ -426,13 +1025,29 @@ impl ExpressionAst {
                     Ok(Value::null())
                 }
             }
+
+            Self::ArrayLiteral(items) => {
+                let values: Result<Vec<Value>> = items
+                    .iter()
+                    .map(|item| item.evaluate(context, functions))
+                    .collect();
+                Ok(Value::array(values?))
+            }
+
+            Self::ObjectLiteral(members) => {
+                let mut object = HashMap::with_capacity(members.len());
+                for (key, value) in members {
+                    object.insert(key.clone(), value.evaluate(context, functions)?);
+                }
+                Ok(Value::object(object))
+            }
         }
     }
 
     /// Collect dependencies from this AST node
     pub fn collect_dependencies(&self, deps: &mut TemplateDependencies) {
         match self {
-            Self::DataAccess { source, path } => {
+            Self::DataAccess { source, path, .. } => {
                 match source {
                     DataSource::Input => {
                         deps.input_paths.insert(path.clone());
@@ -452,6 +1067,9 @@ impl ExpressionAst {
                     DataSource::Workflow => {
                         deps.uses_workflow = true;
                     }
+                    DataSource::Local(_) => {
+                        // Bound by the template itself
+                    }
                 }
             }
             Self::FunctionCall { name, args } => {
@@ -488,6 +1106,16 @@ impl ExpressionAst {
                     else_expr.collect_dependencies(deps);
                 }
             }
+            Self::ArrayLiteral(items) => {
+                for item in items {
+                    item.collect_dependencies(deps);
+                }
+            }
+            Self::ObjectLiteral(members) => {
+                for (_, value) in members {
+                    value.collect_dependencies(deps);
+                }
+            }
             Self::Literal(_) => {
                 // No dependencies for literals
             }
@@ -499,297 +1127,231 @@ impl ExpressionAst {
 struct TemplateParser {
     source: String,
     functions: Arc<FunctionRegistry>,
+    /// Variables bound before the template starts
+    locals: HashSet<String>,
 }
 
 impl TemplateParser {
-    fn new(source: &str, functions: Arc<FunctionRegistry>) -> Self {
+    fn new(source: &str, functions: Arc<FunctionRegistry>, locals: HashSet<String>) -> Self {
         Self {
             source: source.to_string(),
             functions,
+            locals,
         }
     }
 
     fn parse(&mut self) -> Result<Vec<TemplateElement>> {
-        let mut elements = Vec::new();
-        let mut current_pos = 0;
-
-        while current_pos < self.source.len() {
-            // Look for expression start
-            if let Some(expr_start) = self.source[current_pos..].find("{{") {
-                let absolute_start = current_pos + expr_start;
-
-                // Add any text before the expression
-                if expr_start > 0 {
-                    elements.push(TemplateElement::Text(
-                        self.source[current_pos..absolute_start].to_string()
-                    ));
-                }
-
-                // Find expression end
-                if let Some(expr_end) = self.source[absolute_start + 2..].find("}}") {
-                    let absolute_end = absolute_start + 2 + expr_end;
-                    let expr_content = &self.source[absolute_start + 2..absolute_end].trim();
+        TemplateElements::new(&self.source, self.locals.clone()).collect()
+    }
 
-                    // Parse the expression
-                    let expression = self.parse_expression(expr_content)?;
-                    elements.push(TemplateElement::Expression(expression));
+    fn extract_dependencies(&self, elements: &[TemplateElement]) -> TemplateDependencies {
+        let mut deps = TemplateDependencies::default();
 
-                    current_pos = absolute_end + 2;
-                } else {
-                    return Err(Error::parse(
-                        "Unclosed expression".to_string(),
-                        absolute_start,
-                        self.source.clone(),
-                    ));
+        for element in elements {
+            match element {
+                TemplateElement::Expression(expr) => expr.ast.collect_dependencies(&mut deps),
+                TemplateElement::Include(name) => {
+                    deps.partials.insert(name.clone());
                 }
-            } else {
-                // No more expressions, add remaining text
-                if current_pos < self.source.len() {
-                    elements.push(TemplateElement::Text(
-                        self.source[current_pos..].to_string()
-                    ));
+                TemplateElement::Assignment { value, .. } => {
+                    value.ast.collect_dependencies(&mut deps);
                 }
-                break;
+                TemplateElement::Text(_) => {}
             }
         }
 
-        Ok(elements)
+        deps
     }
+}
 
-    fn parse_expression(&self, content: &str) -> Result<Expression> {
-        // This is a very basic parser - a full implementation would use a proper lexer/parser
-        let ast = if content.starts_with('$') {
-            // Data access
-            self.parse_data_access(content)?
-        } else if content.contains('|') {
-            // Pipeline
-            self.parse_pipeline(content)?
-        } else if content.contains('?') && content.contains(':') {
-            // Ternary
-            self.parse_ternary(content)?
-        } else if content.starts_with("if(") {
-            // If function
-            self.parse_if_function(content)?
-        } else {
-            // Try to parse as literal
-            self.parse_literal(content)?
-        };
+/// Parses template source into elements one at a time
+///
+/// Adjacent text, including escaped braces, is yielded as a single element.
+/// Text before a malformed block is yielded before its error, after which
+/// iteration stops.
+struct TemplateElements<'a> {
+    source: &'a str,
+    pos: usize,
+    /// Element (or error) parsed together with the text preceding it
+    pending: Option<Result<TemplateElement>>,
+    /// Variables bound so far, which later expressions may refer to
+    locals: HashSet<String>,
+}
 
-        Ok(Expression::new(content.to_string(), ast))
+impl<'a> TemplateElements<'a> {
+    fn new(source: &'a str, locals: HashSet<String>) -> Self {
+        Self { source, pos: 0, pending: None, locals }
     }
 
-    fn parse_data_access(&self, content: &str) -> Result<ExpressionAst> {
-        // Parse $input.path, $node('id').path, etc.
-        if content.starts_with("$input") {
-            let path = if content.len() > 6 && content.chars().nth(6) == Some('.') {
-                content[7..].to_string()
-            } else {
-                String::new()
-            };
-            Ok(ExpressionAst::DataAccess {
-                source: DataSource::Input,
-                path,
-            })
-        } else if content.starts_with("$node(") {
-            // Extract node ID from $node('id')
-            if let Some(start) = content.find('\'') {
-                if let Some(end) = content[start + 1..].find('\'') {
-                    let node_id = content[start + 1..start + 1 + end].to_string();
-                    let remaining = &content[start + 1 + end + 1..];
-                    let path = if remaining.starts_with(".json.") {
-                        remaining[6..].to_string()
-                    } else if remaining.starts_with('.') {
-                        remaining[1..].to_string()
-                    } else {
-                        String::new()
-                    };
-
-                    Ok(ExpressionAst::DataAccess {
-                        source: DataSource::Node(node_id),
-                        path,
-                    })
-                } else {
-                    Err(Error::parse("Invalid node reference".to_string(), 0, content.to_string()))
-                }
-            } else {
-                Err(Error::parse("Invalid node reference".to_string(), 0, content.to_string()))
-            }
-        } else if content.starts_with("$env.") {
-            Ok(ExpressionAst::DataAccess {
-                source: DataSource::Environment,
-                path: content[5..].to_string(),
-            })
-        } else if content.starts_with("$system.") {
-            Ok(ExpressionAst::DataAccess {
-                source: DataSource::System,
-                path: content[8..].to_string(),
-            })
-        } else if content.starts_with("$execution.") {
-            Ok(ExpressionAst::DataAccess {
-                source: DataSource::Execution,
-                path: content[11..].to_string(),
-            })
-        } else if content.starts_with("$workflow.") {
-            Ok(ExpressionAst::DataAccess {
-                source: DataSource::Workflow,
-                path: content[10..].to_string(),
-            })
-        } else {
-            Err(Error::parse("Unknown data source".to_string(), 0, content.to_string()))
-        }
-    }
+    /// Parse the `{{ ... }}` block starting at `start`
+    fn parse_block(&mut self, start: usize) -> Result<TemplateElement> {
+        let Some(expr_end) = find_block_end(&self.source[start + 2..]) else {
+            return Err(Error::parse("Unclosed expression".to_string(), start, self.source));
+        };
+        let end = start + 2 + expr_end;
+        let content = self.source[start + 2..end].trim();
+        self.pos = end + 2;
 
-    fn parse_pipeline(&self, content: &str) -> Result<ExpressionAst> {
-        let parts: Vec<&str> = content.split('|').map(|s| s.trim()).collect();
-        if parts.len() < 2 {
-            return Err(Error::parse("Invalid pipeline".to_string(), 0, content.to_string()));
+        // Parse the include directive, assignment or expression
+        if let Some(name) = parser::parse_include(content)? {
+            return Ok(TemplateElement::Include(name));
         }
-
-        let input = Box::new(self.parse_expression(parts[0])?.ast);
-        let mut functions = Vec::new();
-
-        for part in &parts[1..] {
-            if let Some(paren_pos) = part.find('(') {
-                let name = part[..paren_pos].trim().to_string();
-                // For now, assume no arguments in pipeline functions
-                functions.push(PipelineFunction {
-                    name,
-                    args: vec![],
-                });
-            } else {
-                functions.push(PipelineFunction {
-                    name: part.to_string(),
-                    args: vec![],
-                });
-            }
+        if let Some((name, value)) = parser::parse_assignment(content)? {
+            let ast = parser::parse_expression_with_locals(value, &self.locals)?;
+            self.locals.insert(name.clone());
+            let value = Expression::new(value.to_string(), ast);
+            return Ok(TemplateElement::Assignment { name, value });
         }
 
-        Ok(ExpressionAst::Pipeline { input, functions })
+        let ast = parser::parse_expression_with_locals(content, &self.locals)?;
+        Ok(TemplateElement::Expression(Expression::new(content.to_string(), ast)))
     }
+}
 
-    fn parse_ternary(&self, content: &str) -> Result<ExpressionAst> {
-        // This is a simplified ternary parser
-        if let Some(question_pos) = content.find('?') {
-            if let Some(colon_pos) = content[question_pos..].find(':') {
-                let colon_pos = question_pos + colon_pos;
-
-                let condition = self.parse_expression(content[..question_pos].trim())?.ast;
-                let then_expr = self.parse_expression(content[question_pos + 1..colon_pos].trim())?.ast;
-                let else_expr = self.parse_expression(content[colon_pos + 1..].trim())?.ast;
-
-                Ok(ExpressionAst::Ternary {
-                    condition: Box::new(condition),
-                    then_expr: Box::new(then_expr),
-                    else_expr: Box::new(else_expr),
-                })
-            } else {
-                Err(Error::parse("Missing ':' in ternary expression".to_string(), 0, content.to_string()))
-            }
-        } else {
-            Err(Error::parse("Missing '?' in ternary expression".to_string(), 0, content.to_string()))
-        }
-    }
+impl Iterator for TemplateElements<'_> {
+    type Item = Result<TemplateElement>;
 
-    fn parse_if_function(&self, content: &str) -> Result<ExpressionAst> {
-        // Simplified if function parser: if(condition, then, else?)
-        if !content.starts_with("if(") || !content.ends_with(')') {
-            return Err(Error::parse("Invalid if function".to_string(), 0, content.to_string()));
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(element) = self.pending.take() {
+            return Some(element);
         }
 
-        let inner = &content[3..content.len() - 1];
-        let parts: Vec<&str> = inner.split(',').map(|s| s.trim()).collect();
-
-        if parts.len() < 2 || parts.len() > 3 {
-            return Err(Error::parse("If function requires 2 or 3 arguments".to_string(), 0, content.to_string()));
+        if self.pos >= self.source.len() {
+            return None;
         }
 
-        let condition = Box::new(self.parse_expression(parts[0])?.ast);
-        let then_expr = Box::new(self.parse_expression(parts[1])?.ast);
-        let else_expr = if parts.len() == 3 {
-            Some(Box::new(self.parse_expression(parts[2])?.ast))
-        } else {
-            None
+        let (text, expr_start) = scan_text(&self.source[self.pos..]);
+        let Some(expr_start) = expr_start else {
+            // No more expressions, take the remaining text
+            self.pos = self.source.len();
+            return (!text.is_empty()).then_some(Ok(TemplateElement::Text(text)));
         };
 
-        Ok(ExpressionAst::IfFunction {
-            condition,
-            then_expr,
-            else_expr,
-        })
-    }
-
-    fn parse_literal(&self, content: &str) -> Result<ExpressionAst> {
-        // Try to parse as different literal types
-        if content == "null" {
-            Ok(ExpressionAst::Literal(Value::null()))
-        } else if content == "true" {
-            Ok(ExpressionAst::Literal(Value::bool(true)))
-        } else if content == "false" {
-            Ok(ExpressionAst::Literal(Value::bool(false)))
-        } else if let Ok(int_val) = content.parse::<i64>() {
-            Ok(ExpressionAst::Literal(Value::integer(int_val)))
-        } else if let Ok(float_val) = content.parse::<f64>() {
-            Ok(ExpressionAst::Literal(Value::float(float_val)))
-        } else if (content.starts_with('"') && content.ends_with('"')) ||
-            (content.starts_with('\'') && content.ends_with('\'')) {
-            let string_content = &content[1..content.len() - 1];
-            Ok(ExpressionAst::Literal(Value::string(string_content)))
-        } else {
-            Err(Error::parse("Unknown literal type".to_string(), 0, content.to_string()))
+        let element = self.parse_block(self.pos + expr_start);
+        if element.is_err() {
+            self.pos = self.source.len();
+        }
+        if text.is_empty() {
+            return Some(element);
         }
+        self.pending = Some(element);
+        Some(Ok(TemplateElement::Text(text)))
     }
+}
 
-    fn extract_dependencies(&self, elements: &[TemplateElement]) -> TemplateDependencies {
-        let mut deps = TemplateDependencies::default();
+/// Apply `trim_blocks` and `lstrip_blocks` to the text around block tags
+///
+/// Text trimmed to nothing stays in place, so element indices in errors match
+/// the untrimmed template and [`Template::render_streaming`].
+fn trim_block_whitespace(elements: &mut [TemplateElement], config: &TemplateConfig) {
+    for i in 0..elements.len() {
+        let is_block =
+            matches!(elements[i], TemplateElement::Include(_) | TemplateElement::Assignment { .. });
+        if !is_block {
+            continue;
+        }
 
-        for element in elements {
-            if let TemplateElement::Expression(expr) = element {
-                expr.ast.collect_dependencies(&mut deps);
+        if config.lstrip_blocks && i > 0 {
+            if let TemplateElement::Text(text) = &mut elements[i - 1] {
+                // Text before the first element starts a line even without a newline
+                let line_start = text.rfind('\n').map_or(0, |n| n + 1);
+                let at_template_start = i == 1;
+                let indent = &text[line_start..];
+                if (line_start > 0 || at_template_start)
+                    && indent.chars().all(|c| c == ' ' || c == '\t')
+                {
+                    text.truncate(line_start);
+                }
             }
         }
 
-        deps
+        if config.trim_blocks {
+            if let Some(TemplateElement::Text(text)) = elements.get_mut(i + 1) {
+                if let Some(rest) = text.strip_prefix("\r\n").or_else(|| text.strip_prefix('\n')) {
+                    *text = rest.to_string();
+                }
+            }
+        }
     }
 }
 
-impl fmt::Display for Template {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.source)
-    }
+/// Whether an evaluation error means the accessed data is absent
+///
+/// `??` and `?.` treat these like null rather than propagating them.
+fn is_missing(err: &Error) -> bool {
+    matches!(err, Error::DataNotFound { .. } | Error::IndexError { .. })
 }
 
-// Placeholder for functions module - will be implemented later
-mod temp_functions {
-    use crate::{error::Result, value::Value};
-    use std::collections::HashMap;
+/// Unescape static text up to the first unescaped `{{`
+///
+/// `\{{` and `\}}` yield literal braces. A doubled backslash right before
+/// braces is a literal backslash, so `\\{{` is a backslash followed by an
+/// expression; any other backslash, `a\\b` included, is kept as is. Returns
+/// the text and the offset of the expression start.
+fn scan_text(text: &str) -> (String, Option<usize>) {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find(['\\', '{']) {
+        unescaped.push_str(&rest[..i]);
+        let tail = &rest[i..];
+        if tail.starts_with("{{") {
+            return (unescaped, Some(text.len() - tail.len()));
+        }
 
-    pub struct FunctionRegistry {
-        _functions: HashMap<String, Box<dyn Fn(Vec<Value>) -> Result<Value>>>,
+        let (literal, len) = if tail.starts_with("\\\\{{") || tail.starts_with("\\\\}}") {
+            ("\\", 2)
+        } else if tail.starts_with("\\{{") {
+            ("{{", 3)
+        } else if tail.starts_with("\\}}") {
+            ("}}", 3)
+        } else {
+            (&tail[..1], 1)
+        };
+        unescaped.push_str(literal);
+        rest = &tail[len..];
     }
+    unescaped.push_str(rest);
+    (unescaped, None)
+}
 
-    impl FunctionRegistry {
-        pub fn with_builtins() -> Self {
-            Self {
-                _functions: HashMap::new(),
-            }
-        }
-
-        pub fn get(&self, _name: &str) -> Option<&dyn Fn(Vec<Value>) -> Result<Value>> {
-            None
+/// Offset of the `}}` closing a block whose content starts `content`
+///
+/// Braces opened inside the expression, as in `{{ {"a": {"b": 1}} }}`, have
+/// to close before a `}}` ends the block, and braces inside string literals
+/// such as `{{ 'x}}y' }}` are skipped.
+fn find_block_end(content: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut chars = content.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' | '\'' => loop {
+                match chars.next()?.1 {
+                    '\\' => {
+                        chars.next();
+                    },
+                    ch if ch == c => break,
+                    _ => {},
+                }
+            },
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            '}' if chars.peek().is_some_and(|&(_, next)| next == '}') => return Some(i),
+            _ => {},
         }
     }
+    None
+}
 
-    pub trait Function {
-        fn execute(&self, args: Vec<Value>) -> Result<Value>;
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
     }
 }
 
-// Temporary re-export until we implement the real functions module
-pub use temp_functions::*;
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::functions::{Function, FunctionSignature, ValueType};
     use crate::value::Value;
 
     #[test]
@@ -852,6 +1414,552 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_escaped_braces() -> Result<()> {
+        let template = Template::parse(r"Write \{{ name \}} for {{ 'variables' }}")?;
+        assert_eq!(template.expression_count(), 1);
+        assert_eq!(template.render(&Context::new())?, "Write {{ name }} for variables");
+
+        let template = Template::parse(r"\{{ literal }}")?;
+        assert!(template.is_static());
+        assert_eq!(template.render(&Context::new())?, "{{ literal }}");
+
+        // An escaped backslash leaves the braces after it to start an expression
+        let template = Template::parse(r"C:\\{{ 'dir' }} \{{ x }} a\b")?;
+        assert_eq!(template.expression_count(), 1);
+        assert_eq!(template.render(&Context::new())?, r"C:\dir {{ x }} a\b");
+
+        // Only a doubled backslash right before braces is unescaped
+        let template = Template::parse(r"a\\b \\}} c\\\\d")?;
+        assert!(template.is_static());
+        assert_eq!(template.render(&Context::new())?, r"a\\b \}} c\\\\d");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_object_literal() -> Result<()> {
+        let mut input = HashMap::new();
+        input.insert("name".to_string(), Value::string("Alice"));
+        let mut context = Context::new();
+        context.set_input(Value::object(input));
+
+        let template = Template::parse(r#"{{ { "name": $input.name, "age": 30 } }}"#)?;
+        assert!(template.dependencies().input_paths.contains("name"));
+
+        let value = template.expressions()[0].evaluate(&context, &FunctionRegistry::new())?;
+        let mut expected = HashMap::new();
+        expected.insert("name".to_string(), Value::string("Alice"));
+        expected.insert("age".to_string(), Value::integer(30));
+        assert_eq!(value, Value::object(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_object_literal() -> Result<()> {
+        let template = Template::parse(r#"{{ {"a": {"b": 1}} }} and {{ {"c": 2}}}"#)?;
+        assert_eq!(template.expression_count(), 2);
+
+        let value =
+            template.expressions()[0].evaluate(&Context::new(), &FunctionRegistry::new())?;
+        let mut inner = HashMap::new();
+        inner.insert("b".to_string(), Value::integer(1));
+        let mut expected = HashMap::new();
+        expected.insert("a".to_string(), Value::object(inner));
+        assert_eq!(value, Value::object(expected));
+
+        assert!(Template::parse(r#"{{ {"a": 1 }}"#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_array_literal() -> Result<()> {
+        let template = Template::parse("{{ [1, [2, 'a, b'], []] }}")?;
+        let value = template.expressions()[0].evaluate(&Context::new(), &FunctionRegistry::new())?;
+
+        assert_eq!(
+            value,
+            Value::array(vec![
+                Value::integer(1),
+                Value::array(vec![Value::integer(2), Value::string("a, b")]),
+                Value::array(vec![]),
+            ])
+        );
+
+        Ok(())
+    }
+
+    /// Test function that uppercases its string input
+    struct Upper(FunctionSignature);
+
+    impl Upper {
+        fn new() -> Self {
+            Self(FunctionSignature {
+                input_type: ValueType::String,
+                parameters: vec![],
+                return_type: ValueType::String,
+            })
+        }
+    }
+
+    impl Function for Upper {
+        fn name(&self) -> &str {
+            "upper"
+        }
+
+        fn signature(&self) -> &FunctionSignature {
+            &self.0
+        }
+
+        fn execute(&self, args: Vec<Value>) -> std::result::Result<Value, crate::error::FunctionError> {
+            Ok(Value::string(args[0].as_str()?.to_uppercase()))
+        }
+    }
+
+    #[test]
+    fn test_operator_precedence() -> Result<()> {
+        let template = Template::parse("{{ 1 + 2 * 3 }} {{ (1 + 2) * 3 }} {{ 2 > 1 && 1 >= 2 }}")?;
+        assert_eq!(template.render(&Context::new())?, "7 9 false");
+        Ok(())
+    }
+
+    #[test]
+    fn test_pipeline_in_ternary_branch() -> Result<()> {
+        let mut functions = FunctionRegistry::new();
+        functions.register(Upper::new());
+        let template = Template::parse_with_functions(
+            "{{ $input.vip ? $input.name | upper : 'guest' }}",
+            Arc::new(functions),
+        )?;
+
+        let mut input = HashMap::new();
+        input.insert("vip".to_string(), Value::bool(true));
+        input.insert("name".to_string(), Value::string("alice"));
+        let mut context = Context::new();
+        context.set_input(Value::object(input.clone()));
+        assert_eq!(template.render(&context)?, "ALICE");
+
+        input.insert("vip".to_string(), Value::bool(false));
+        context.set_input(Value::object(input));
+        assert_eq!(template.render(&context)?, "guest");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_builtins() -> Result<()> {
+        let object = |pairs: Vec<(&str, Value)>| {
+            Value::object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+        };
+        let mut context = Context::new();
+        context.add_node_output(
+            "defaults",
+            object(vec![
+                ("method", Value::string("GET")),
+                (
+                    "http",
+                    object(vec![("timeout", Value::integer(30)), ("retries", Value::integer(3))]),
+                ),
+            ]),
+        );
+        context.set_input(object(vec![(
+            "overrides",
+            object(vec![(
+                "http",
+                object(vec![("timeout", Value::integer(5)), ("retries", Value::Null)]),
+            )]),
+        )]));
+
+        let functions = FunctionRegistry::with_builtins();
+        let evaluate = |source: &str| {
+            Template::parse(source)?.expressions()[0].evaluate(&context, &functions)
+        };
+
+        let merged = evaluate("{{ merge($node('defaults').json, $input.overrides) }}")?;
+        assert_eq!(merged.navigate("method"), Some(&Value::string("GET")));
+        assert_eq!(merged.navigate("http.timeout"), Some(&Value::integer(5)));
+        assert_eq!(merged.navigate("http.retries"), Some(&Value::Null));
+        assert_eq!(evaluate("{{ $node('defaults').json | merge($input.overrides) }}")?, merged);
+
+        let patched = evaluate("{{ merge_patch($node('defaults').json, $input.overrides) }}")?;
+        assert_eq!(patched.navigate("http.timeout"), Some(&Value::integer(5)));
+        assert_eq!(patched.navigate("http.retries"), None);
+
+        assert!(evaluate("{{ merge($node('defaults').json, 'x') }}").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_literal_with_operators() -> Result<()> {
+        let template = Template::parse("{{ 'a | b ? c : d' }}")?;
+        assert_eq!(template.render(&Context::new())?, "a | b ? c : d");
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_literal_with_braces() -> Result<()> {
+        let template = Template::parse(r#"{{ 'x}}y' }}, {{ "{" }} and {{ 'it\'s }}' }}"#)?;
+        assert_eq!(template.expression_count(), 3);
+        assert_eq!(template.render(&Context::new())?, "x}}y, { and it's }}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_coalescing_and_optional_chaining() -> Result<()> {
+        let functions = FunctionRegistry::new();
+        let evaluate = |source: &str, context: &Context| {
+            Template::parse(&format!("{{{{ {} }}}}", source))?.expressions()[0]
+                .evaluate(context, &functions)
+        };
+
+        let mut context = Context::new();
+        context.set_input(Value::object(HashMap::from([
+            ("name".to_string(), Value::string("alice")),
+            ("nickname".to_string(), Value::Null),
+        ])));
+
+        assert_eq!(evaluate("$input.missing ?? 'default'", &context)?, Value::string("default"));
+        assert_eq!(evaluate("$input.nickname ?? $input.name", &context)?, Value::string("alice"));
+        assert_eq!(evaluate("$input.name ?? 'default'", &context)?, Value::string("alice"));
+        assert_eq!(evaluate("$input.user?.name", &context)?, Value::Null);
+        assert_eq!(evaluate("$input.user?.name ?? 'guest'", &context)?, Value::string("guest"));
+        assert!(evaluate("$input.user.name", &context).is_err());
+        // Only missing data falls through; other errors still surface
+        assert!(evaluate("1 / 0 ?? 'default'", &context).is_err());
+
+        context.set_input(Value::object(HashMap::from([(
+            "user".to_string(),
+            Value::object(HashMap::from([("name".to_string(), Value::string("bob"))])),
+        )])));
+        assert_eq!(evaluate("$input.user?.name", &context)?, Value::string("bob"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_streaming_matches_render() -> Result<()> {
+        let mut context = Context::new();
+        context.set_input(Value::object(HashMap::from([
+            ("name".to_string(), Value::string("Ada")),
+            ("count".to_string(), Value::integer(3)),
+        ])));
+        context.register_partial("footer", "-- {{ $input.name }}\n");
+
+        let row = "Row for {{ $input.name }}: {{ $input.count * 2 }} \\{{ literal }} }}\n";
+        let source = format!("{}{{{{ include 'footer' }}}}", row.repeat(5_000));
+
+        let mut streamed = Vec::new();
+        Template::render_streaming(&source, &context, &mut streamed)?;
+        let rendered = Template::parse(&source)?.render(&context)?;
+        assert_eq!(streamed, rendered.into_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_streaming_parse_error() {
+        let mut output = Vec::new();
+        let error = Template::render_streaming("Hello {{ $input.name", &Context::new(), &mut output)
+            .unwrap_err();
+        assert!(matches!(error, Error::ParseError { position: 6, .. }));
+        assert_eq!(output, b"Hello ");
+    }
+
+    #[test]
+    fn test_parse_cached() -> Result<()> {
+        let source = "cached {{ $input.name }} for test_parse_cached";
+        let before = Template::parse_cache_stats();
+
+        let first = Template::parse_cached(source)?;
+        let second = Template::parse_cached(source)?;
+        let after = Template::parse_cache_stats();
+        assert_eq!(after.misses - before.misses, 1);
+        assert_eq!(after.hits - before.hits, 1);
+
+        // The second call shares the first call's parsed elements
+        assert!(Arc::ptr_eq(&first.elements, &second.elements));
+        assert_eq!(first.source(), second.source());
+        assert_eq!(first.expressions(), second.expressions());
+
+        let mut context = Context::new();
+        context.set_input(Value::object(HashMap::from([(
+            "name".to_string(),
+            Value::string("Ann"),
+        )])));
+        assert_eq!(second.render(&context)?, first.render(&context)?);
+
+        // A different registry is a different cache key
+        let functions = Arc::new(FunctionRegistry::with_builtins());
+        let other = Template::parse_cached_with_functions(source, functions)?;
+        assert!(!Arc::ptr_eq(&first.elements, &other.elements));
+
+        assert!(Template::parse_cached("{{ unclosed").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_include_partial() -> Result<()> {
+        let template = Template::parse("{{ include 'header' }}Body{{ include \"footer\" }}")?;
+        assert!(template.dependencies().partials.contains("header"));
+
+        let mut context = Context::new();
+        context.set_input(Value::object(HashMap::from([(
+            "title".to_string(),
+            Value::string("Report"),
+        )])));
+        context.register_partial("header", "# {{ $input.title }}\n");
+        assert!(template.validate_context(&context).is_err());
+
+        context.register_partial("footer", "\n-- {{ include 'signature' }}");
+        context.register_partial("signature", "{{ $input.title | upper }}");
+
+        let mut functions = FunctionRegistry::new();
+        functions.register(Upper::new());
+        let template = Template::parse_with_functions(
+            "{{ include 'header' }}Body{{ include 'footer' }}",
+            Arc::new(functions),
+        )?;
+        assert_eq!(template.render(&context)?, "# Report\nBody\n-- REPORT");
+        Ok(())
+    }
+
+    #[test]
+    fn test_template_config() -> Result<()> {
+        let mut context = Context::new();
+        context.set_input(Value::object(HashMap::from([(
+            "name".to_string(),
+            Value::string("<b>Tom & \"Jerry\"</b>"),
+        )])));
+        context.register_partial("header", "<h1>{{ $input.name }}</h1>");
+
+        let html = TemplateConfig { auto_escape: EscapeMode::Html, ..TemplateConfig::default() };
+        let template = Template::parse_with_config(
+            "<p>{{ $input.name }}</p>{{ include 'header' }}",
+            html,
+        )?;
+        assert_eq!(template.config().auto_escape, EscapeMode::Html);
+        assert_eq!(
+            template.render(&context)?,
+            "<p>&lt;b&gt;Tom &amp; &quot;Jerry&quot;&lt;/b&gt;</p>\
+             <h1>&lt;b&gt;Tom &amp; &quot;Jerry&quot;&lt;/b&gt;</h1>"
+        );
+
+        let source = "Top\n  {{ include 'header' }}\nBody {{ $input.name }}\n";
+        context.set_input(Value::object(HashMap::from([(
+            "name".to_string(),
+            Value::string("x"),
+        )])));
+        assert_eq!(Template::parse(source)?.render(&context)?, "Top\n  <h1>x</h1>\nBody x\n");
+
+        let trim = TemplateConfig { trim_blocks: true, ..TemplateConfig::default() };
+        let rendered = Template::parse_with_config(source, trim)?.render(&context)?;
+        assert_eq!(rendered, "Top\n  <h1>x</h1>Body x\n");
+
+        let both = TemplateConfig { trim_blocks: true, lstrip_blocks: true, ..trim };
+        let rendered = Template::parse_with_config(source, both)?.render(&context)?;
+        assert_eq!(rendered, "Top\n<h1>x</h1>Body x\n");
+
+        // Expression tags are not blocks, so their newlines are kept
+        let rendered = Template::parse_with_config("{{ $input.name }}\nEnd", both)?;
+        assert_eq!(rendered.render(&context)?, "x\nEnd");
+        Ok(())
+    }
+
+    #[test]
+    fn test_assignment() -> Result<()> {
+        let mut context = Context::new();
+        context.set_input(Value::object(HashMap::from([
+            ("price".to_string(), Value::float(2.5)),
+            ("qty".to_string(), Value::integer(4)),
+            ("user".to_string(), Value::object(HashMap::from([(
+                "name".to_string(),
+                Value::string("ann"),
+            )]))),
+        ])));
+        context.register_partial("summary", "[{{ total }}]");
+
+        let mut functions = FunctionRegistry::new();
+        functions.register(Upper::new());
+        let template = Template::parse_with_functions(
+            "{{ let total = $input.price * $input.qty }}Total: {{ total }}, \
+             {{ let total = total + 1 }}{{ total }} {{ include 'summary' }} \
+             {{ let user = $input.user }}{{ user.name | upper }}",
+            Arc::new(functions),
+        )?;
+        assert_eq!(template.render(&context)?, "Total: 10, 11 [11] ANN");
+        assert_eq!(template.expression_count(), 3);
+        assert!(template.dependencies().input_paths.contains("price"));
+        // Bindings live in a copy of the context
+        assert!(context.get_local("total").is_none());
+
+        // A variable is undefined before its assignment, and in partials
+        // included before it
+        let error = Template::parse("{{ total }}{{ let total = 1 }}").unwrap_err();
+        assert!(error.is_parse_error());
+        assert!(Template::parse("{{ let total = total }}").is_err());
+        let early = Template::parse("{{ include 'summary' }}{{ let total = 1 }}")?;
+        assert!(early.render(&context).is_err());
+
+        let trim = TemplateConfig { trim_blocks: true, ..TemplateConfig::default() };
+        let template = Template::parse_with_config("{{ let n = 2 }}\n{{ n * 3 }}", trim)?;
+        assert_eq!(template.render(&context)?, "6");
+
+        let template = Template::parse("{{ let x = $input.missing }}{{ x }}!")?;
+        let (output, errors) = template.render_lenient(&context, ErrorPolicy::Keep);
+        assert_eq!(output, "{{ let x = $input.missing }}{{ x }}!");
+        assert_eq!(errors.len(), 2);
+
+        let mut output = Vec::new();
+        Template::render_streaming("{{ let a = 'b' }}{{ a }}{{ a }}", &context, &mut output)?;
+        assert_eq!(output, b"bb");
+        Ok(())
+    }
+
+    #[test]
+    fn test_include_cycle() -> Result<()> {
+        let mut context = Context::new();
+        context.register_partial("loop", "x{{ include 'loop' }}");
+        context.register_partial("a", "{{ include 'b' }}");
+        context.register_partial("b", "{{ include 'a' }}");
+
+        let error = Template::parse("{{ include 'loop' }}")?.render(&context).unwrap_err();
+        assert!(error.message().contains("cycle"));
+
+        let error = Template::parse("{{ include 'a' }}")?.render(&context).unwrap_err();
+        assert!(error.message().contains("a -> b -> a"));
+
+        assert!(Template::parse("{{ include 'missing' }}")?.render(&context).is_err());
+        assert!(Template::parse("{{ include header }}").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_lenient() -> Result<()> {
+        let template = Template::parse("a={{ $input.a }} b={{ $node('missing').x }}!")?;
+        let mut context = Context::new();
+        context.set_input(Value::object(HashMap::from([("a".to_string(), Value::integer(1))])));
+        assert!(template.render(&context).is_err());
+
+        let (output, errors) = template.render_lenient(&context, ErrorPolicy::Empty);
+        assert_eq!(output, "a=1 b=!");
+        assert_eq!(errors.len(), 1);
+
+        let (output, errors) =
+            template.render_lenient(&context, ErrorPolicy::Placeholder("<?>".to_string()));
+        assert_eq!(output, "a=1 b=<?>!");
+        assert_eq!(errors.len(), 1);
+
+        let (output, errors) = template.render_lenient(&context, ErrorPolicy::Keep);
+        assert_eq!(output, "a=1 b={{ $node('missing').x }}!");
+        assert_eq!(errors.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_expression_error_source() -> Result<()> {
+        let mut context = Context::new();
+        context.set_input(Value::object(HashMap::from([("x".to_string(), Value::integer(1))])));
+        let source = "a {{ $input.x }} b {{ $input.x / 0 }} c {{ $input.x * 2 }}";
+
+        let error = Template::parse(source)?.render(&context).unwrap_err();
+        match &error {
+            Error::ExpressionError { expression, element, error } => {
+                assert_eq!(expression, "$input.x / 0");
+                assert_eq!(*element, 3);
+                assert!(matches!(**error, Error::MathError { .. }));
+            }
+            other => panic!("expected an expression error, got {:?}", other),
+        }
+        assert_eq!(
+            error.to_string(),
+            "error in expression \"$input.x / 0\" (element 3): Math error: Division by zero"
+        );
+
+        let mut output = Vec::new();
+        let error = Template::render_streaming(source, &context, &mut output).unwrap_err();
+        assert!(matches!(error, Error::ExpressionError { element: 3, .. }));
+
+        // Trimming the text after a block leaves the indices unchanged
+        let source = "{{ let y = 2 }}\n{{ $input.x / 0 }}";
+        let trim = TemplateConfig { trim_blocks: true, ..TemplateConfig::default() };
+        let error = Template::parse_with_config(source, trim)?.render(&context).unwrap_err();
+        assert!(matches!(error, Error::ExpressionError { element: 2, .. }));
+        let error = Template::render_streaming(source, &context, &mut output).unwrap_err();
+        assert!(matches!(error, Error::ExpressionError { element: 2, .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_lenient_in_partial() -> Result<()> {
+        let mut context = Context::new();
+        context.register_partial("footer", "[{{ $input.missing }}]");
+        let template = Template::parse("{{ include 'footer' }}{{ include 'nope' }}.")?;
+
+        let (output, errors) = template.render_lenient(&context, ErrorPolicy::Keep);
+        assert_eq!(output, "[{{ $input.missing }}]{{ include 'nope' }}.");
+        assert_eq!(errors.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_strict() -> Result<()> {
+        let mut functions = FunctionRegistry::new();
+        functions.register(Upper::new());
+        let functions = Arc::new(functions);
+
+        let typo = "{{ $input.x | uppercaes }}";
+        let error = Template::parse_strict(typo, functions.clone()).unwrap_err();
+        assert!(error.is_parse_error());
+        assert!(error.message().contains("uppercaes"));
+
+        // Lenient parsing still accepts the typo and fails only when rendered
+        assert!(Template::parse_with_functions(typo, functions.clone()).is_ok());
+
+        let unknown = "{{ foo($input.a) }} {{ bar() | upper }}";
+        let error = Template::parse_strict(unknown, functions.clone()).unwrap_err();
+        assert!(error.message().contains("bar, foo"));
+
+        Template::parse_strict("{{ $input.x | upper }} {{ if($input.y, 1, 2) }}", functions)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_dependencies() -> Result<()> {
+        let first = Template::parse("{{ $input.name }} {{ $env.API_KEY }}")?;
+        let second = Template::parse("{{ $node('fetch').status }} {{ $execution.id }}")?;
+
+        let mut deps = first.dependencies().clone();
+        deps.merge(second.dependencies());
+
+        assert!(deps.input_paths.contains("name"));
+        assert!(deps.env_vars.contains("API_KEY"));
+        assert!(deps.node_ids.contains("fetch"));
+        assert!(deps.uses_execution);
+        assert!(!deps.uses_workflow);
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze() -> Result<()> {
+        let mut functions = FunctionRegistry::new();
+        functions.register(Upper::new());
+        let template = Template::parse_with_functions(
+            "{{ $node('b').x | upper }} {{ $node('a').y | shout }} {{ $env.TOKEN }}",
+            Arc::new(functions),
+        )?;
+
+        let report = template.analyze();
+        assert_eq!(report.node_ids, vec!["a", "b"]);
+        assert_eq!(report.env_vars, vec!["TOKEN"]);
+        assert_eq!(report.functions, vec!["shout", "upper"]);
+        assert_eq!(report.unknown_functions, vec!["shout"]);
+        assert_eq!(report.required_sources, vec!["$node('a')", "$node('b')", "$env.TOKEN"]);
+        Ok(())
+    }
+
     #[test]
     fn test_invalid_template() {
         let result = Template::parse("{{ unclosed expression");
@@ -865,6 +1973,7 @@ mod tests {
             ExpressionAst::DataAccess {
                 source: DataSource::Input,
                 path: "name".to_string(),
+                optional: false,
             }
         );
 
@@ -294,6 +294,53 @@ impl Value {
         Some(current)
     }
 
+    /// Deep merge `patch` over this value, with `patch` winning
+    ///
+    /// Objects are merged key by key, recursing into keys present in both;
+    /// any other `patch` value, null included, replaces this value outright.
+    /// See [`Value::merge_patch`] for null-deletion semantics.
+    pub fn merge(&self, patch: &Value) -> Value {
+        match (self, patch) {
+            (Self::Object(base), Self::Object(patch)) => {
+                let mut merged = base.clone();
+                for (key, value) in patch {
+                    let value = match merged.get(key) {
+                        Some(existing) => existing.merge(value),
+                        None => value.clone(),
+                    };
+                    merged.insert(key.clone(), value);
+                }
+                Self::Object(merged)
+            }
+            _ => patch.clone(),
+        }
+    }
+
+    /// Apply `patch` as a JSON Merge Patch (RFC 7396)
+    ///
+    /// Like [`Value::merge`], except that null members of `patch` remove the
+    /// key instead of storing null. An object patch applied to a non-object
+    /// starts from an empty object.
+    pub fn merge_patch(&self, patch: &Value) -> Value {
+        let Self::Object(patch) = patch else {
+            return patch.clone();
+        };
+
+        let mut merged = match self {
+            Self::Object(base) => base.clone(),
+            _ => HashMap::new(),
+        };
+        for (key, value) in patch {
+            if value.is_null() {
+                merged.remove(key);
+            } else {
+                let value = merged.get(key).unwrap_or(&Value::Null).merge_patch(value);
+                merged.insert(key.clone(), value);
+            }
+        }
+        Self::Object(merged)
+    }
+
     /// Compare values for equality
     pub fn equals(&self, other: &Value) -> bool {
         self == other
@@ -367,6 +414,35 @@ impl From<HashMap<String, Value>> for Value {
 }
 
 // Optional serde integration
+
+#[cfg(feature = "serde")]
+impl Value {
+    /// Convert to JSON, failing instead of silently replacing NaN or
+    /// infinite floats with `null`
+    pub fn try_into_json(self) -> Result<serde_json::Value> {
+        match self {
+            Value::Float(f) => serde_json::Number::from_f64(f)
+                .map(serde_json::Value::Number)
+                .ok_or_else(|| {
+                    let reason = format!("{f} is not finite");
+                    Error::type_error_with_context("float", "JSON number", reason)
+                }),
+            Value::Array(a) => a
+                .into_iter()
+                .map(Value::try_into_json)
+                .collect::<Result<_>>()
+                .map(serde_json::Value::Array),
+            Value::Object(o) => o
+                .into_iter()
+                .map(|(k, v)| Ok((k, v.try_into_json()?)))
+                .collect::<Result<_>>()
+                .map(serde_json::Value::Object),
+            other => Ok(serde_json::Value::from(other)),
+        }
+    }
+}
+
+/// Integers outside the `i64` range become floats and may lose precision
 #[cfg(feature = "serde")]
 impl From<serde_json::Value> for Value {
     fn from(value: serde_json::Value) -> Self {
@@ -393,6 +469,8 @@ impl From<serde_json::Value> for Value {
     }
 }
 
+/// NaN and infinite floats have no JSON representation and become `null`;
+/// use [`Value::try_into_json`] to reject them instead
 #[cfg(feature = "serde")]
 impl From<Value> for serde_json::Value {
     fn from(value: Value) -> Self {
@@ -506,6 +584,34 @@ mod tests {
         assert_eq!(value.navigate("name"), Some(&Value::string("Alice")));
     }
 
+    #[test]
+    fn test_merge() {
+        let object = |pairs: Vec<(&str, Value)>| {
+            Value::object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+        };
+        let base = object(vec![
+            ("retries", Value::integer(3)),
+            ("http", object(vec![("timeout", Value::integer(30)), ("proxy", Value::string("a"))])),
+        ]);
+        let patch = object(vec![
+            ("http", object(vec![("timeout", Value::integer(5)), ("proxy", Value::Null)])),
+            ("retries", Value::Null),
+        ]);
+
+        let merged = base.merge(&patch);
+        assert_eq!(merged.navigate("http.timeout"), Some(&Value::integer(5)));
+        assert_eq!(merged.navigate("http.proxy"), Some(&Value::Null));
+        assert_eq!(merged.get("retries"), Some(&Value::Null));
+
+        let patched = base.merge_patch(&patch);
+        assert_eq!(patched.navigate("http.timeout"), Some(&Value::integer(5)));
+        assert_eq!(patched.navigate("http.proxy"), None);
+        assert_eq!(patched.get("retries"), None);
+
+        assert_eq!(base.merge(&Value::integer(1)), Value::integer(1));
+        assert_eq!(Value::integer(1).merge_patch(&patch).get("retries"), None);
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(Value::null().to_string(), "null");
@@ -525,4 +631,23 @@ mod tests {
 
         assert_eq!(json_val, back_to_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_nested_round_trip() {
+        use serde_json::json;
+
+        let json_val = json!({
+            "order": {"id": 7, "total": 12.5, "paid": true, "note": null},
+            "items": [{"sku": "a-1", "qty": 2}, {"sku": "b-2", "tags": ["x", "y"]}],
+        });
+        let value = Value::from(json_val.clone());
+        assert_eq!(value.navigate("order.total"), Some(&Value::Float(12.5)));
+        assert_eq!(value.clone().try_into_json().unwrap(), json_val);
+        assert_eq!(serde_json::Value::from(value), json_val);
+
+        let nan = Value::Array(vec![Value::Float(f64::NAN)]);
+        assert!(nan.clone().try_into_json().unwrap_err().is_type_error());
+        assert_eq!(serde_json::Value::from(nan), json!([null]));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+//! Cloning large values, which shares storage instead of copying it
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use nebula_value::{ArrayValue, BinaryValue, ObjectValue, Value};
+
+fn large_object() -> Value {
+    let object: ObjectValue =
+        (0..10_000).map(|i| (format!("key{i}"), Value::string(format!("value {i}")))).collect();
+    Value::Object(object)
+}
+
+fn large_array() -> Value {
+    let array: ArrayValue = (0..10_000).map(Value::number).collect();
+    Value::Array(array)
+}
+
+fn large_binary() -> Value {
+    Value::Binary(BinaryValue::zeros(1 << 20))
+}
+
+fn clone_benchmarks(c: &mut Criterion) {
+    let object = large_object();
+    let array = large_array();
+    let binary = large_binary();
+
+    c.bench_function("clone object 10k keys", |b| b.iter(|| black_box(&object).clone()));
+    c.bench_function("clone array 10k items", |b| b.iter(|| black_box(&array).clone()));
+    c.bench_function("clone binary 1 MiB", |b| b.iter(|| black_box(&binary).clone()));
+
+    c.bench_function("clone and mutate object 10k keys", |b| {
+        b.iter(|| {
+            let mut copy = black_box(&object).clone();
+            if let Value::Object(object) = &mut copy {
+                object.insert("extra".to_string(), Value::boolean(true));
+            }
+            copy
+        })
+    });
+}
+
+criterion_group!(benches, clone_benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+//! `cargo fuzz run binary_from_str`: hex and base64 decoding must return
+//! `Err`, never panic
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nebula_value::BinaryValue;
+
+fuzz_target!(|input: &str| {
+    let _ = input.parse::<BinaryValue>();
+    let _ = BinaryValue::from_hex(input);
+    let _ = BinaryValue::from_base64(input);
+    let _ = BinaryValue::from_base64_url(input);
+});
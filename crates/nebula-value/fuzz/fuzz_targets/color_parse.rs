@@ -0,0 +1,11 @@
+//! `cargo fuzz run color_parse`: color parsing must return `Err`, never panic
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nebula_value::ColorValue;
+
+fuzz_target!(|input: &str| {
+    let _ = ColorValue::parse(input);
+    let _ = ColorValue::from_hex(input);
+});
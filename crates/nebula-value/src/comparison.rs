@@ -0,0 +1,373 @@
+//! Ordering and comparison rules between [`Value`]s
+
+use std::cmp::Ordering;
+
+use crate::{Value, ValueError, ValueResult};
+
+/// Tri-state result of a comparison that may not be defined for its operands
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComparisonResult {
+    /// The comparison holds
+    True,
+    /// The comparison does not hold
+    False,
+    /// The operands cannot be compared
+    Error(ValueError),
+}
+
+impl ComparisonResult {
+    /// Returns true only for [`ComparisonResult::True`]
+    #[inline]
+    #[must_use]
+    pub fn is_true(&self) -> bool {
+        matches!(self, Self::True)
+    }
+
+    #[inline]
+    fn from_bool(holds: bool) -> Self {
+        if holds { Self::True } else { Self::False }
+    }
+}
+
+impl From<ValueResult<bool>> for ComparisonResult {
+    fn from(result: ValueResult<bool>) -> Self {
+        match result {
+            Ok(true) => Self::True,
+            Ok(false) => Self::False,
+            Err(e) => Self::Error(e),
+        }
+    }
+}
+
+/// Comparison operations over [`Value`]
+#[derive(Debug)]
+pub struct ValueComparison;
+
+impl ValueComparison {
+    /// Compares two values, erroring if they have no meaningful order
+    ///
+    /// - numbers compare numerically, integers and floats alike; NaN is
+    ///   incomparable
+    /// - strings compare lexically by code point
+    /// - booleans order `false` before `true`
+    /// - datetimes and durations compare chronologically
+    /// - `null` equals `null`
+    ///
+    /// Any other pairing, including `null` against a non-null value, is an
+    /// [`ValueError::IncompatibleComparison`].
+    pub fn compare(a: &Value, b: &Value) -> ValueResult<Ordering> {
+        let ordering = match (a, b) {
+            (Value::Number(x), Value::Number(y)) => x.exact_cmp(y),
+            (Value::String(x), Value::String(y)) => Some(x.cmp(y)),
+            (Value::Boolean(x), Value::Boolean(y)) => Some(x.cmp(y)),
+            (Value::DateTime(x), Value::DateTime(y)) => Some(x.cmp(y)),
+            (Value::Duration(x), Value::Duration(y)) => Some(x.cmp(y)),
+            (Value::Null, Value::Null) => Some(Ordering::Equal),
+            _ => None,
+        };
+
+        ordering.ok_or_else(|| ValueError::incompatible_comparison(a.type_name(), b.type_name()))
+    }
+
+    /// Checks two values for equality, treating `1` and `1.0` as equal
+    #[must_use]
+    pub fn equals(a: &Value, b: &Value) -> bool {
+        match Self::compare(a, b) {
+            Ok(ordering) => ordering == Ordering::Equal,
+            Err(_) => a == b,
+        }
+    }
+
+    /// Checks `a > b`
+    #[must_use]
+    pub fn greater_than(a: &Value, b: &Value) -> ComparisonResult {
+        Self::compare(a, b).map(Ordering::is_gt).into()
+    }
+
+    /// Checks `a < b`
+    #[must_use]
+    pub fn less_than(a: &Value, b: &Value) -> ComparisonResult {
+        Self::compare(a, b).map(Ordering::is_lt).into()
+    }
+
+    /// Checks `a >= b`
+    #[must_use]
+    pub fn greater_than_or_equal(a: &Value, b: &Value) -> ComparisonResult {
+        Self::compare(a, b).map(Ordering::is_ge).into()
+    }
+
+    /// Checks `a <= b`
+    #[must_use]
+    pub fn less_than_or_equal(a: &Value, b: &Value) -> ComparisonResult {
+        Self::compare(a, b).map(Ordering::is_le).into()
+    }
+
+    /// Checks `min <= value <= max`
+    #[must_use]
+    pub fn between(value: &Value, min: &Value, max: &Value) -> ComparisonResult {
+        Self::compare(value, min)
+            .and_then(|low| Ok(low.is_ge() && Self::compare(value, max)?.is_le()))
+            .into()
+    }
+
+    /// Checks two values for inequality; the negation of [`Self::equals`]
+    #[must_use]
+    pub fn not_equals(a: &Value, b: &Value) -> bool {
+        !Self::equals(a, b)
+    }
+
+    // === Infallible ordering ===
+    //
+    // For callers such as UI display rules that treat an incomparable pair
+    // as "does not hold" rather than an error.
+
+    /// Checks `a > b`, false if the values cannot be compared
+    #[must_use]
+    pub fn gt_simple(a: &Value, b: &Value) -> bool {
+        Self::greater_than(a, b).is_true()
+    }
+
+    /// Checks `a < b`, false if the values cannot be compared
+    #[must_use]
+    pub fn lt_simple(a: &Value, b: &Value) -> bool {
+        Self::less_than(a, b).is_true()
+    }
+
+    /// Checks `a >= b`, false if the values cannot be compared
+    #[must_use]
+    pub fn gte_simple(a: &Value, b: &Value) -> bool {
+        Self::greater_than_or_equal(a, b).is_true()
+    }
+
+    /// Checks `a <= b`, false if the values cannot be compared
+    #[must_use]
+    pub fn lte_simple(a: &Value, b: &Value) -> bool {
+        Self::less_than_or_equal(a, b).is_true()
+    }
+
+    // === Membership ===
+
+    /// Checks whether `value` [equals](Self::equals) any item of `list`
+    #[must_use]
+    pub fn in_list(value: &Value, list: &[Value]) -> bool {
+        list.iter().any(|item| Self::equals(value, item))
+    }
+
+    /// Checks that `value` equals no item of `list`
+    #[must_use]
+    pub fn not_in_list(value: &Value, list: &[Value]) -> bool {
+        !Self::in_list(value, list)
+    }
+
+    /// Checks whether a string holds a substring, or an array an element
+    ///
+    /// Array elements are matched with [`Self::equals`]. Any other pairing is
+    /// an [`ValueError::IncompatibleComparison`].
+    #[must_use]
+    pub fn contains(value: &Value, needle: &Value) -> ComparisonResult {
+        match (value, needle) {
+            (Value::String(s), Value::String(n)) => {
+                ComparisonResult::from_bool(s.contains(n.as_str()))
+            },
+            (Value::Array(items), _) => ComparisonResult::from_bool(Self::in_list(needle, items)),
+            _ => Self::incompatible(value, needle),
+        }
+    }
+
+    // === String matching ===
+
+    /// Checks whether a string starts with a string prefix
+    #[must_use]
+    pub fn starts_with(value: &Value, prefix: &Value) -> ComparisonResult {
+        match (value, prefix) {
+            (Value::String(s), Value::String(p)) => {
+                ComparisonResult::from_bool(s.starts_with(p.as_str()))
+            },
+            _ => Self::incompatible(value, prefix),
+        }
+    }
+
+    /// Checks whether a string ends with a string suffix
+    #[must_use]
+    pub fn ends_with(value: &Value, suffix: &Value) -> ComparisonResult {
+        match (value, suffix) {
+            (Value::String(s), Value::String(p)) => {
+                ComparisonResult::from_bool(s.ends_with(p.as_str()))
+            },
+            _ => Self::incompatible(value, suffix),
+        }
+    }
+
+    /// Checks whether a string matches a regular expression anywhere
+    ///
+    /// An invalid pattern is reported as [`ValueError::InvalidRegex`].
+    #[must_use]
+    pub fn matches_regex(value: &Value, pattern: &str) -> ComparisonResult {
+        let Value::String(s) = value else {
+            return ComparisonResult::Error(ValueError::incompatible_comparison(
+                value.type_name(),
+                "regex",
+            ));
+        };
+        match regex::Regex::new(pattern) {
+            Ok(re) => ComparisonResult::from_bool(re.is_match(s)),
+            Err(e) => ComparisonResult::Error(ValueError::invalid_regex(pattern, e.to_string())),
+        }
+    }
+
+    // === Emptiness ===
+
+    /// Checks whether a value is `null`, an empty string, an empty array, an
+    /// empty object or empty binary data
+    #[must_use]
+    pub fn is_empty(value: &Value) -> bool {
+        match value {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            Value::Object(o) => o.is_empty(),
+            Value::Binary(b) => b.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// The negation of [`Self::is_empty`]
+    #[must_use]
+    pub fn is_not_empty(value: &Value) -> bool {
+        !Self::is_empty(value)
+    }
+
+    // === Display ===
+
+    /// Formats a value for comparison messages
+    ///
+    /// Strings are quoted so `"1"` and `1` read differently; everything else
+    /// uses [`Value::display_string`].
+    #[must_use]
+    pub fn format_for_display(value: &Value) -> String {
+        match value {
+            Value::String(s) => format!("{:?}", s.as_str()),
+            _ => value.display_string(),
+        }
+    }
+
+    fn incompatible(a: &Value, b: &Value) -> ComparisonResult {
+        ComparisonResult::Error(ValueError::incompatible_comparison(a.type_name(), b.type_name()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DateTimeValue, ObjectValue};
+
+    #[test]
+    fn test_compare_numbers() {
+        assert_eq!(
+            ValueComparison::compare(&Value::number(1), &Value::number(1.5)).unwrap(),
+            Ordering::Less
+        );
+        assert_eq!(
+            ValueComparison::compare(&Value::number(2.0), &Value::number(2)).unwrap(),
+            Ordering::Equal
+        );
+        assert!(ValueComparison::equals(&Value::number(2.0), &Value::number(2)));
+        assert!(ValueComparison::compare(&Value::number(f64::NAN), &Value::number(1)).is_err());
+
+        // Integers beyond 2^53 do not round to the nearest float
+        let big = Value::number(9_007_199_254_740_993_i64);
+        assert_eq!(
+            ValueComparison::compare(&big, &Value::number(9_007_199_254_740_992.0)).unwrap(),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_compare_strings_and_datetimes() {
+        assert_eq!(
+            ValueComparison::compare(&Value::string("apple"), &Value::string("banana")).unwrap(),
+            Ordering::Less
+        );
+        assert_eq!(
+            ValueComparison::compare(&Value::string("b"), &Value::string("B")).unwrap(),
+            Ordering::Greater
+        );
+
+        let earlier = Value::DateTime(DateTimeValue::from_timestamp(1_000).unwrap());
+        let later = Value::DateTime(DateTimeValue::from_timestamp(2_000).unwrap());
+        assert_eq!(ValueComparison::compare(&later, &earlier).unwrap(), Ordering::Greater);
+        assert!(ValueComparison::less_than(&earlier, &later).is_true());
+    }
+
+    #[test]
+    fn test_compare_incomparable() {
+        let object = Value::Object(ObjectValue::new());
+        assert!(matches!(
+            ValueComparison::compare(&object, &Value::number(1)),
+            Err(ValueError::IncompatibleComparison { .. })
+        ));
+        assert!(matches!(
+            ValueComparison::greater_than(&Value::string("1"), &Value::number(1)),
+            ComparisonResult::Error(_)
+        ));
+        assert!(!ValueComparison::equals(&Value::string("1"), &Value::number(1)));
+    }
+
+    #[test]
+    fn test_between_and_membership() {
+        let five = Value::number(5);
+        assert!(ValueComparison::between(&five, &Value::number(1), &Value::number(5.0)).is_true());
+        assert_eq!(
+            ValueComparison::between(&five, &Value::number(6), &Value::number(9)),
+            ComparisonResult::False
+        );
+        assert!(matches!(
+            ValueComparison::between(&five, &Value::number(1), &Value::string("9")),
+            ComparisonResult::Error(_)
+        ));
+
+        let list = [Value::string("a"), Value::number(5.0)];
+        assert!(ValueComparison::in_list(&five, &list));
+        assert!(ValueComparison::not_in_list(&Value::string("b"), &list));
+        assert!(ValueComparison::not_equals(&five, &Value::string("5")));
+
+        assert!(ValueComparison::gt_simple(&five, &Value::number(4)));
+        assert!(!ValueComparison::gt_simple(&five, &Value::string("4")));
+        assert!(ValueComparison::lte_simple(&five, &Value::number(5)));
+    }
+
+    #[test]
+    fn test_string_matching() {
+        let hello = Value::string("hello world");
+        assert!(ValueComparison::contains(&hello, &Value::string("lo w")).is_true());
+        assert!(ValueComparison::starts_with(&hello, &Value::string("hello")).is_true());
+        assert_eq!(
+            ValueComparison::ends_with(&hello, &Value::string("hello")),
+            ComparisonResult::False
+        );
+        assert!(matches!(
+            ValueComparison::starts_with(&Value::number(1), &Value::string("1")),
+            ComparisonResult::Error(ValueError::IncompatibleComparison { .. })
+        ));
+
+        let items = Value::array(vec![Value::number(1), Value::string("x")]);
+        assert!(ValueComparison::contains(&items, &Value::number(1.0)).is_true());
+
+        assert!(ValueComparison::matches_regex(&hello, r"^h\w+\s").is_true());
+        assert!(matches!(
+            ValueComparison::matches_regex(&hello, "("),
+            ComparisonResult::Error(ValueError::InvalidRegex { .. })
+        ));
+    }
+
+    #[test]
+    fn test_emptiness_and_display() {
+        assert!(ValueComparison::is_empty(&Value::Null));
+        assert!(ValueComparison::is_empty(&Value::string("")));
+        assert!(ValueComparison::is_empty(&Value::Object(ObjectValue::new())));
+        assert!(ValueComparison::is_not_empty(&Value::number(0)));
+
+        assert_eq!(ValueComparison::format_for_display(&Value::string("1")), "\"1\"");
+        assert_eq!(ValueComparison::format_for_display(&Value::number(1)), "1");
+    }
+}
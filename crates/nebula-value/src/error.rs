@@ -121,6 +121,10 @@ pub enum ValueError {
     #[error("File operation failed: {reason}")]
     FileOperationFailed { reason: String },
 
+    /// I/O error, keeping its kind so retryable failures can be told apart
+    #[error("I/O error: {reason}")]
+    Io { kind: std::io::ErrorKind, reason: String },
+
     /// Mode parameter error
     #[error("Mode parameter error: {reason}")]
     ModeParameterError { reason: String },
@@ -131,6 +135,22 @@ pub enum ValueError {
 }
 
 impl ValueError {
+    /// Returns true if the failure is temporary and the operation may succeed on retry
+    ///
+    /// Only I/O errors that were interrupted, timed out or would block are
+    /// considered transient; everything else fails the same way again.
+    pub fn is_transient(&self) -> bool {
+        use std::io::ErrorKind;
+
+        matches!(
+            self,
+            Self::Io {
+                kind: ErrorKind::Interrupted | ErrorKind::TimedOut | ErrorKind::WouldBlock,
+                ..
+            }
+        )
+    }
+
     // Constructors for common error patterns
 
     /// Creates an invalid regex error
@@ -241,6 +261,11 @@ impl ValueError {
         Self::FileOperationFailed { reason: reason.into() }
     }
 
+    /// Creates an I/O error of the given kind
+    pub fn io(kind: std::io::ErrorKind, reason: impl Into<String>) -> Self {
+        Self::Io { kind, reason: reason.into() }
+    }
+
     /// Creates a mode parameter error
     pub fn mode_parameter_error(reason: impl Into<String>) -> Self {
         Self::ModeParameterError { reason: reason.into() }
@@ -297,6 +322,12 @@ impl From<std::string::FromUtf8Error> for ValueError {
     }
 }
 
+impl From<std::io::Error> for ValueError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io { kind: err.kind(), reason: err.to_string() }
+    }
+}
+
 impl From<base64::DecodeError> for ValueError {
     fn from(err: base64::DecodeError) -> Self {
         Self::BinaryDecodingFailed { reason: err.to_string() }
@@ -376,8 +407,19 @@ mod tests {
 
         let file_err = ValueError::file_operation_failed("Cannot read file");
         assert!(matches!(file_err, ValueError::FileOperationFailed { .. }));
+        assert!(!file_err.is_transient());
 
         let mode_err = ValueError::mode_parameter_error("Invalid mode");
         assert!(matches!(mode_err, ValueError::ModeParameterError { .. }));
     }
+
+    #[test]
+    fn test_is_transient() {
+        use std::io::{Error, ErrorKind};
+
+        assert!(ValueError::from(Error::from(ErrorKind::TimedOut)).is_transient());
+        assert!(ValueError::io(ErrorKind::Interrupted, "read interrupted").is_transient());
+        assert!(!ValueError::from(Error::from(ErrorKind::NotFound)).is_transient());
+        assert!(!ValueError::custom("bad value").is_transient());
+    }
 }
@@ -25,6 +25,12 @@
 //! - `std` (default): Standard library support
 //! - `json`: JSON serialization via serde_json
 //! - `collections`: Enhanced collection operations via indexmap
+//! - `csv`: CSV conversion for [`ArrayValue`], parsed in-crate
+//! - `intern`: Shared storage for repeated strings via `StringValue::interned`,
+//!   pooled in a `dashmap` set
+//! - `hash`: CRC-32 and SHA-256 digests of [`BinaryValue`] via `sha2` and
+//!   `crc32fast`
+//! - `rand`: Seeded `shuffle` and `sample` for [`ArrayValue`] via `rand`
 //! - `full`: All features enabled
 //!
 //! ## Examples
@@ -52,13 +58,17 @@
 #![deny(unsafe_code)]
 
 pub mod value;
+pub mod comparison;
 pub mod validation;
 mod types;
 pub mod error;
 
 // Re-exports - Main API
 // Value type
-pub use value::Value;
+pub use value::{CompactOptions, NonFinitePolicy, ParseOptions, Value, ValueKey};
+pub use comparison::{ComparisonResult, ValueComparison};
+#[cfg(feature = "json")]
+pub use value::JsonConversionOptions;
 pub use error::*;
 pub use types::*;
 
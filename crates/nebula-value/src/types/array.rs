@@ -1,53 +1,169 @@
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::sync::Arc;
 
 #[cfg(feature = "collections")]
 use indexmap::IndexSet;
+#[cfg(feature = "rand")]
+use rand::seq::SliceRandom;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{Value, ValueError, ValueResult};
+use crate::{ObjectValue, Value, ValueError, ValueResult};
+
+/// Which unmatched rows [`ArrayValue::join_on`] keeps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinKind {
+    /// Only rows whose key appears on both sides
+    #[default]
+    Inner,
+    /// Every left row; unmatched ones get null right fields
+    Left,
+    /// Every row from both sides; unmatched ones get null fields for the
+    /// other side
+    Full,
+}
 
 /// Array value type with efficient operations and functional programming
 /// support
-#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+///
+/// Elements live behind an [`Arc`], so cloning is cheap: clones share storage
+/// until one of them is mutated, which copies the elements first. Arrays built
+/// by the `const` constructors [`from_vec`](Self::from_vec) and
+/// [`empty`](Self::empty) own their elements instead, so only their first
+/// clone copies them.
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
-pub struct ArrayValue(Vec<Value>);
+pub struct ArrayValue(Elements);
+
+/// Element storage of an [`ArrayValue`]
+///
+/// An `Arc` cannot be allocated in a const context, so `const` constructors
+/// keep the elements [`Owned`](Self::Owned). Cloning always yields
+/// [`Shared`](Self::Shared) storage.
+enum Elements {
+    Owned(Vec<Value>),
+    Shared(Arc<Vec<Value>>),
+}
+
+impl Elements {
+    #[inline]
+    fn shared(values: Vec<Value>) -> Self {
+        Self::Shared(Arc::new(values))
+    }
+
+    /// Mutable access to the elements, copying them first if shared
+    #[inline]
+    fn make_mut(&mut self) -> &mut Vec<Value> {
+        match self {
+            Self::Owned(values) => values,
+            Self::Shared(values) => Arc::make_mut(values),
+        }
+    }
+
+    /// Mutable access to the elements unless a clone shares them
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut Vec<Value>> {
+        match self {
+            Self::Owned(values) => Some(values),
+            Self::Shared(values) => Arc::get_mut(values),
+        }
+    }
+
+    /// The elements, copied only if a clone shares them
+    #[inline]
+    fn into_vec(self) -> Vec<Value> {
+        match self {
+            Self::Owned(values) => values,
+            Self::Shared(values) => Arc::unwrap_or_clone(values),
+        }
+    }
+}
+
+impl Clone for Elements {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Owned(values) => Self::shared(values.clone()),
+            Self::Shared(values) => Self::Shared(Arc::clone(values)),
+        }
+    }
+}
+
+impl Default for Elements {
+    #[inline]
+    fn default() -> Self {
+        Self::Owned(Vec::new())
+    }
+}
+
+impl Deref for Elements {
+    type Target = Vec<Value>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Owned(values) => values,
+            Self::Shared(values) => values,
+        }
+    }
+}
+
+impl fmt::Debug for Elements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Elements {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Elements {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::shared)
+    }
+}
 
 impl ArrayValue {
     /// Creates a new array value from a vector
     #[inline]
     #[must_use]
     pub fn new(values: impl Into<Vec<Value>>) -> Self {
-        Self(values.into())
+        Self(Elements::shared(values.into()))
     }
 
-    /// Creates a new array value from a Vec<Value> directly (const-friendly)
+    /// Creates a new array value from a Vec<Value> directly
     #[inline]
     #[must_use]
     pub const fn from_vec(values: Vec<Value>) -> Self {
-        Self(values)
+        Self(Elements::Owned(values))
     }
 
     /// Creates an empty array
     #[inline]
     #[must_use]
     pub const fn empty() -> Self {
-        Self(Vec::new())
+        Self::from_vec(Vec::new())
     }
 
     /// Creates an array with specified capacity
     #[inline]
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(Vec::with_capacity(capacity))
+        Self::new(Vec::with_capacity(capacity))
     }
 
     /// Creates an array filled with n copies of the given value
     #[must_use]
     pub fn filled(value: &Value, count: usize) -> Self {
-        Self(vec![value.clone(); count])
+        Self::new(vec![value.clone(); count])
     }
 
     /// Creates an array from a range of numbers
@@ -72,7 +188,20 @@ impl ArrayValue {
             }
         }
 
-        Ok(Self(values))
+        Ok(Self::new(values))
+    }
+
+    /// Mutable access to the elements, copying them first if shared
+    #[inline]
+    fn vec_mut(&mut self) -> &mut Vec<Value> {
+        self.0.make_mut()
+    }
+
+    /// Moves the elements into `out` unless a clone shares them
+    pub(crate) fn take_unique_into(&mut self, out: &mut Vec<Value>) {
+        if let Some(values) = self.0.get_mut() {
+            out.append(values);
+        }
     }
 
     // === Basic Operations ===
@@ -101,19 +230,19 @@ impl ArrayValue {
     /// Reserves capacity for at least additional more elements
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
-        self.0.reserve(additional);
+        self.vec_mut().reserve(additional);
     }
 
     /// Shrinks the capacity as much as possible
     #[inline]
     pub fn shrink_to_fit(&mut self) {
-        self.0.shrink_to_fit();
+        self.vec_mut().shrink_to_fit();
     }
 
     /// Clears all elements from the array
     #[inline]
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.vec_mut().clear();
     }
 
     // === Element Access ===
@@ -129,7 +258,7 @@ impl ArrayValue {
     #[inline]
     #[must_use]
     pub fn get_mut(&mut self, index: usize) -> Option<&mut Value> {
-        self.0.get_mut(index)
+        self.vec_mut().get_mut(index)
     }
 
     /// Safe element access with error handling
@@ -163,14 +292,14 @@ impl ArrayValue {
     #[inline]
     #[must_use]
     pub fn first_mut(&mut self) -> Option<&mut Value> {
-        self.0.first_mut()
+        self.vec_mut().first_mut()
     }
 
     /// Gets the last element mutably
     #[inline]
     #[must_use]
     pub fn last_mut(&mut self) -> Option<&mut Value> {
-        self.0.last_mut()
+        self.vec_mut().last_mut()
     }
 
     // === Modification Operations ===
@@ -178,14 +307,14 @@ impl ArrayValue {
     /// Appends an element to the back of the array
     #[inline]
     pub fn push(&mut self, value: Value) {
-        self.0.push(value);
+        self.vec_mut().push(value);
     }
 
     /// Removes and returns the last element
     #[inline]
     #[must_use]
     pub fn pop(&mut self) -> Option<Value> {
-        self.0.pop()
+        self.vec_mut().pop()
     }
 
     /// Inserts an element at position index
@@ -194,7 +323,7 @@ impl ArrayValue {
         if index > self.len() {
             return Err(ValueError::index_out_of_bounds(index, self.len()));
         }
-        self.0.insert(index, element);
+        self.vec_mut().insert(index, element);
         Ok(())
     }
 
@@ -204,34 +333,62 @@ impl ArrayValue {
         if index >= self.len() {
             return Err(ValueError::index_out_of_bounds(index, self.len()));
         }
-        Ok(self.0.remove(index))
+        Ok(self.vec_mut().remove(index))
+    }
+
+    /// Removes the elements from start to end (exclusive), returning them
+    pub fn remove_range(&mut self, start: usize, end: usize) -> ValueResult<ArrayValue> {
+        if start > end {
+            return Err(ValueError::custom(format!(
+                "Invalid remove range: start ({start}) > end ({end})"
+            )));
+        }
+        if end > self.len() {
+            return Err(ValueError::index_out_of_bounds(end, self.len()));
+        }
+        Ok(self.vec_mut().drain(start..end).collect())
+    }
+
+    /// Removes `delete_count` elements starting at `start` and inserts `items`
+    /// in their place, returning the removed elements
+    ///
+    /// Mirrors JavaScript's `Array.prototype.splice`: a `delete_count` past
+    /// the end removes everything from `start`. Unlike JavaScript, a `start`
+    /// past the end is an error rather than being clamped.
+    pub fn splice(
+        &mut self,
+        start: usize,
+        delete_count: usize,
+        items: Vec<Value>,
+    ) -> ValueResult<ArrayValue> {
+        if start > self.len() {
+            return Err(ValueError::index_out_of_bounds(start, self.len()));
+        }
+        let end = start.saturating_add(delete_count).min(self.len());
+        Ok(self.vec_mut().splice(start..end, items).collect())
     }
 
     /// Removes an element and returns it, or None if not found
     #[must_use]
     pub fn remove_item(&mut self, item: &Value) -> Option<Value> {
-        if let Some(pos) = self.0.iter().position(|x| x == item) {
-            Some(self.0.remove(pos))
-        } else {
-            None
-        }
+        self.0.iter().position(|x| x == item).map(|pos| self.vec_mut().remove(pos))
     }
 
     /// Retains only the elements specified by the predicate
     pub fn retain<F>(&mut self, mut f: F)
     where F: FnMut(&Value) -> bool {
-        self.0.retain(|value| f(value));
+        self.vec_mut().retain(|value| f(value));
     }
 
     /// Removes consecutive repeated elements
     pub fn dedup(&mut self) {
-        self.0.dedup();
+        self.vec_mut().dedup();
     }
 
     /// Removes consecutive repeated elements using a custom comparison
     pub fn dedup_by<F>(&mut self, same_bucket: F)
     where F: FnMut(&mut Value, &mut Value) -> bool {
-        self.0.dedup_by(same_bucket);
+        self.vec_mut().dedup_by(same_bucket);
     }
 
     // === Slicing and Subranges ===
@@ -275,7 +432,7 @@ impl ArrayValue {
     pub fn take_while<P>(&self, mut predicate: P) -> ArrayValue
     where P: FnMut(&Value) -> bool {
         let mut result = Vec::new();
-        for value in &self.0 {
+        for value in self.0.iter() {
             if predicate(value) {
                 result.push(value.clone());
             } else {
@@ -311,7 +468,7 @@ impl ArrayValue {
     pub fn map<F>(&self, mut f: F) -> ValueResult<ArrayValue>
     where F: FnMut(&Value) -> ValueResult<Value> {
         let mut result = Vec::with_capacity(self.len());
-        for value in &self.0 {
+        for value in self.0.iter() {
             result.push(f(value)?);
         }
         Ok(ArrayValue::new(result))
@@ -340,6 +497,13 @@ impl ArrayValue {
         self.0.iter().find(|value| predicate(value))
     }
 
+    /// Returns the first non-`None` result of applying `f` to the elements
+    #[must_use]
+    pub fn find_map<'a, T, F>(&'a self, f: F) -> Option<T>
+    where F: FnMut(&'a Value) -> Option<T> {
+        self.0.iter().find_map(f)
+    }
+
     /// Finds the index of the first element matching a predicate
     #[must_use]
     pub fn find_index<P>(&self, predicate: P) -> Option<usize>
@@ -361,29 +525,31 @@ impl ArrayValue {
         self.0.iter().all(predicate)
     }
 
-    /// Reduces the array to a single value using an accumulator function
-    pub fn reduce<F>(&self, mut f: F) -> ValueResult<Option<Value>>
+    /// Counts the elements matching a predicate
+    #[must_use]
+    pub fn count<P>(&self, mut predicate: P) -> usize
+    where P: FnMut(&Value) -> bool {
+        self.0.iter().filter(|value| predicate(value)).count()
+    }
+
+    /// Reduces the array to a single value, seeding the accumulator with the
+    /// first element
+    ///
+    /// Returns an error for an empty array; use [`ArrayValue::fold`] when an
+    /// initial value is available. An error from `f` aborts the reduction.
+    pub fn reduce<F>(&self, f: F) -> ValueResult<Value>
     where F: FnMut(Value, &Value) -> ValueResult<Value> {
         let mut iter = self.0.iter();
-        if let Some(first) = iter.next() {
-            let mut acc = first.clone();
-            for value in iter {
-                acc = f(acc, value)?;
-            }
-            Ok(Some(acc))
-        } else {
-            Ok(None)
-        }
+        let first = iter.next().ok_or_else(|| ValueError::custom("Cannot reduce an empty array"))?;
+        iter.try_fold(first.clone(), f)
     }
 
-    /// Folds the array with an initial value
-    pub fn fold<T, F>(&self, init: T, mut f: F) -> ValueResult<T>
+    /// Folds the array into an accumulator starting from `init`
+    ///
+    /// An error from `f` aborts the fold and is returned as is.
+    pub fn fold<T, F>(&self, init: T, f: F) -> ValueResult<T>
     where F: FnMut(T, &Value) -> ValueResult<T> {
-        let mut acc = init;
-        for value in &self.0 {
-            acc = f(acc, value)?;
-        }
-        Ok(acc)
+        self.0.iter().try_fold(init, f)
     }
 
     // === Search and Contains ===
@@ -418,8 +584,11 @@ impl ArrayValue {
     // === Sorting and Ordering ===
 
     /// Sorts the array in-place
+    ///
+    /// Numbers follow [`NumberValue::total_cmp`](crate::NumberValue::total_cmp),
+    /// so NaN sorts last instead of leaving the order undefined.
     pub fn sort(&mut self) -> ValueResult<()> {
-        self.0.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        self.vec_mut().sort_by(total_order);
         Ok(())
     }
 
@@ -430,6 +599,23 @@ impl ArrayValue {
         Ok(result)
     }
 
+    /// Binary searches this sorted array for a value
+    ///
+    /// Mirrors [`slice::binary_search`]: returns `Ok(index)` of a matching
+    /// element, or `Err(index)` where the value could be inserted to keep the
+    /// array sorted. The array must be sorted with the same ordering as
+    /// [`ArrayValue::sort`]; on unsorted input the result is meaningless but
+    /// still a valid index.
+    pub fn binary_search(&self, target: &Value) -> Result<usize, usize> {
+        self.0.binary_search_by(|probe| total_order(probe, target))
+    }
+
+    /// Inserts a value into this sorted array, keeping it sorted
+    pub fn insert_sorted(&mut self, value: Value) {
+        let (Ok(index) | Err(index)) = self.binary_search(&value);
+        self.vec_mut().insert(index, value);
+    }
+
     /// Sorts the array by a key function
     pub fn sort_by<F, K>(&mut self, mut f: F) -> ValueResult<()>
     where
@@ -451,13 +637,13 @@ impl ArrayValue {
             sorted.push(self.0[original_index].clone());
         }
 
-        self.0 = sorted;
+        self.0 = Elements::shared(sorted);
         Ok(())
     }
 
     /// Reverses the array in-place
     pub fn reverse(&mut self) {
-        self.0.reverse();
+        self.vec_mut().reverse();
     }
 
     /// Returns a reversed copy of the array
@@ -473,7 +659,7 @@ impl ArrayValue {
     /// Concatenates with another array
     #[must_use]
     pub fn concat(&self, other: &ArrayValue) -> ArrayValue {
-        let mut result = self.0.clone();
+        let mut result = self.to_vec();
         result.extend_from_slice(&other.0);
         ArrayValue::new(result)
     }
@@ -481,7 +667,7 @@ impl ArrayValue {
     /// Appends another array to this one
     #[inline]
     pub fn extend(&mut self, other: &ArrayValue) {
-        self.0.extend_from_slice(&other.0);
+        self.vec_mut().extend_from_slice(&other.0);
     }
 
     /// Joins array elements into a string
@@ -502,7 +688,7 @@ impl ArrayValue {
     #[must_use]
     pub fn flatten(&self) -> ArrayValue {
         let mut result = Vec::new();
-        for value in &self.0 {
+        for value in self.0.iter() {
             if let Some(arr) = value.as_array() {
                 result.extend_from_slice(&arr.0);
             } else {
@@ -516,7 +702,7 @@ impl ArrayValue {
     #[must_use]
     pub fn flatten_deep(&self) -> ArrayValue {
         let mut result = Vec::new();
-        for value in &self.0 {
+        for value in self.0.iter() {
             if let Some(arr) = value.as_array() {
                 result.extend_from_slice(&arr.flatten_deep().0);
             } else {
@@ -526,6 +712,125 @@ impl ArrayValue {
         ArrayValue::new(result)
     }
 
+    /// Transposes an array of equal-length row arrays into columns
+    ///
+    /// Errors if any element is not an array or if the rows differ in length.
+    /// An empty array transposes to an empty array.
+    pub fn transpose(&self) -> ValueResult<ArrayValue> {
+        let rows = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.as_array()
+                    .ok_or_else(|| ValueError::custom(format!("Row {i} is not an array")))
+            })
+            .collect::<ValueResult<Vec<_>>>()?;
+
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some((i, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(ValueError::custom(format!(
+                "Cannot transpose ragged rows: row {i} has {} elements, expected {width}",
+                row.len()
+            )));
+        }
+
+        let columns = (0..width)
+            .map(|column| {
+                let cells = rows.iter().map(|row| row.0[column].clone()).collect::<Vec<_>>();
+                Value::Array(ArrayValue::new(cells))
+            })
+            .collect::<Vec<_>>();
+        Ok(ArrayValue::new(columns))
+    }
+
+    /// Joins two arrays of objects on a shared `key` field
+    ///
+    /// Matched rows are shallow merges of the left and right objects, with
+    /// right fields overriding left fields of the same name. A left row
+    /// matching several right rows produces one row per match. Unmatched rows
+    /// kept by `kind` get a null for every field the other side's objects
+    /// have. Rows come out in left order, followed by unmatched right rows for
+    /// [`JoinKind::Full`].
+    ///
+    /// Key values match when they compare equal, so `1` joins with `1.0`.
+    ///
+    /// Errors if an element of either array is not an object or lacks `key`.
+    pub fn join_on(
+        &self,
+        other: &ArrayValue,
+        key: &str,
+        kind: JoinKind,
+    ) -> ValueResult<ArrayValue> {
+        let left = Self::join_rows(self, key, "left")?;
+        let right = Self::join_rows(other, key, "right")?;
+
+        let mut right_by_key = std::collections::HashMap::<u64, Vec<usize>>::new();
+        for (i, (value, _)) in right.iter().enumerate() {
+            right_by_key.entry(normalized_hash(value)).or_default().push(i);
+        }
+
+        // Null placeholders for every field of the other side
+        let nulls = |side: &[(&Value, &ObjectValue)]| {
+            let mut nulls = ObjectValue::new();
+            for (_, object) in side {
+                for field in object.keys().filter(|field| field.as_str() != key) {
+                    nulls.insert(field.clone(), Value::null());
+                }
+            }
+            nulls
+        };
+        let right_nulls = nulls(&right);
+
+        let mut matched = vec![false; right.len()];
+        let mut result = Vec::new();
+        for (value, object) in &left {
+            let mut found = false;
+            let candidates = right_by_key.get(&normalized_hash(value)).into_iter().flatten();
+            for &i in candidates.filter(|&&i| right[i].0 == *value) {
+                found = true;
+                matched[i] = true;
+                result.push(Value::Object(object.merged(right[i].1)));
+            }
+            if !found && kind != JoinKind::Inner {
+                result.push(Value::Object(right_nulls.merged(object)));
+            }
+        }
+
+        if kind == JoinKind::Full {
+            let left_nulls = nulls(&left);
+            let unmatched = right.iter().zip(matched).filter(|(_, matched)| !matched);
+            for ((_, object), _) in unmatched {
+                result.push(Value::Object(left_nulls.merged(object)));
+            }
+        }
+
+        Ok(ArrayValue::new(result))
+    }
+
+    /// Pairs each element of a join side with its key value
+    fn join_rows<'a>(
+        array: &'a ArrayValue,
+        key: &str,
+        side: &str,
+    ) -> ValueResult<Vec<(&'a Value, &'a ObjectValue)>> {
+        array
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let object = row.as_object().ok_or_else(|| {
+                    ValueError::custom(format!("Element {i} of the {side} array is not an object"))
+                })?;
+                let value = object.get(key).ok_or_else(|| {
+                    let message = format!("Element {i} of the {side} array has no '{key}' field");
+                    ValueError::custom(message)
+                })?;
+                Ok((value, object))
+            })
+            .collect()
+    }
+
     // === Utility Methods ===
 
     /// Creates chunks of specified size
@@ -540,6 +845,54 @@ impl ArrayValue {
         Ok(chunks)
     }
 
+    /// Applies `f` to every sliding window of `window` consecutive elements
+    ///
+    /// The result holds `len - window + 1` values, one per window in order.
+    /// Returns an error if `window` is zero or larger than the array; an
+    /// error from `f` aborts the reduction.
+    pub fn windowed_reduce<F>(&self, window: usize, mut f: F) -> ValueResult<ArrayValue>
+    where F: FnMut(&[Value]) -> ValueResult<Value> {
+        self.check_window(window)?;
+        self.0.windows(window).map(&mut f).collect::<ValueResult<Vec<_>>>().map(ArrayValue::new)
+    }
+
+    /// Simple moving average over windows of `window` numeric elements
+    ///
+    /// Averages are returned as floats. Returns an error if any element is
+    /// not a number, or if `window` is zero or larger than the array.
+    pub fn moving_average(&self, window: usize) -> ValueResult<ArrayValue> {
+        self.check_window(window)?;
+        let numbers = self
+            .0
+            .iter()
+            .map(|value| {
+                value
+                    .as_number()
+                    .map(|number| number.as_f64())
+                    .ok_or_else(|| ValueError::type_conversion(value.type_name(), "number"))
+            })
+            .collect::<ValueResult<Vec<f64>>>()?;
+
+        Ok(numbers
+            .windows(window)
+            .map(|values| Value::number(values.iter().sum::<f64>() / window as f64))
+            .collect())
+    }
+
+    /// Validates a sliding window size against the array length
+    fn check_window(&self, window: usize) -> ValueResult<()> {
+        if window == 0 {
+            return Err(ValueError::custom("Window size cannot be zero"));
+        }
+        if window > self.len() {
+            return Err(ValueError::custom(format!(
+                "Window size {window} exceeds array length {}",
+                self.len()
+            )));
+        }
+        Ok(())
+    }
+
     /// Removes duplicate values (preserving order when collections feature is
     /// enabled)
     #[must_use]
@@ -549,7 +902,7 @@ impl ArrayValue {
             let mut seen = IndexSet::new();
             let mut result = Vec::new();
 
-            for value in &self.0 {
+            for value in self.0.iter() {
                 if seen.insert(value) {
                     result.push(value.clone());
                 }
@@ -563,7 +916,7 @@ impl ArrayValue {
             let mut seen = std::collections::HashSet::<String>::new();
             let mut result = Vec::new();
 
-            for value in &self.0 {
+            for value in self.0.iter() {
                 let key = value.to_string();
                 if seen.insert(key) {
                     result.push(value.clone());
@@ -574,11 +927,52 @@ impl ArrayValue {
         }
     }
 
+    /// Removes elements whose key was already produced by an earlier element,
+    /// keeping the first occurrence and preserving order
+    ///
+    /// Keys are compared with `==`, so `1` and `1.0` are the same key. They
+    /// are hashed and retained until the end of the call, so object or array
+    /// keys cost a full traversal each and memory grows with the number of
+    /// distinct keys.
+    #[must_use]
+    pub fn dedup_by_key<F>(&self, mut key_fn: F) -> ArrayValue
+    where
+        F: FnMut(&Value) -> Value,
+    {
+        let mut seen = std::collections::HashMap::<u64, Vec<Value>>::new();
+        self.0
+            .iter()
+            .filter(|value| {
+                let key = key_fn(value);
+                let bucket = seen.entry(normalized_hash(&key)).or_default();
+                let first = !bucket.contains(&key);
+                if first {
+                    bucket.push(key);
+                }
+                first
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Removes adjacent duplicates only, returning a new array
+    ///
+    /// Cheaper than [`ArrayValue::unique`], and equivalent to it for sorted
+    /// input.
+    #[must_use]
+    pub fn dedup_consecutive(&self) -> ArrayValue {
+        let mut result = self.to_vec();
+        result.dedup();
+        ArrayValue::new(result)
+    }
+
     /// Converts to Vec<Value> consuming self
+    ///
+    /// Copies the elements only if the storage is shared with a clone.
     #[inline]
     #[must_use]
-    pub fn into_vec(self) -> Vec<Value> {
-        self.0
+    pub fn into_vec(mut self) -> Vec<Value> {
+        std::mem::take(&mut self.0).into_vec()
     }
 
     /// Returns an iterator over the values
@@ -590,12 +984,285 @@ impl ArrayValue {
     /// Returns a mutable iterator over the values
     #[inline]
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Value> {
-        self.0.iter_mut()
+        self.vec_mut().iter_mut()
     }
 }
 
+// === Ordering ===
+
+/// Total order used by [`ArrayValue::sort`] and [`ArrayValue::binary_search`]
+fn total_order(a: &Value, b: &Value) -> std::cmp::Ordering {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.total_cmp(b),
+        // Value's PartialOrd only leaves number pairs unordered
+        _ => a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal),
+    }
+}
+
+// === Key Hashing ===
+
+/// Hash of a value that agrees with `==`
+///
+/// The derived hash keeps `Integer(1)` and `Float(1.0)` apart even though
+/// they compare equal, and hashes object fields in storage order. Equal
+/// values always hash alike here; callers still confirm a match with `==`.
+fn normalized_hash(value: &Value) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    hash_normalized(value, &mut hasher);
+    hasher.finish()
+}
+
+fn hash_normalized<H: Hasher>(value: &Value, state: &mut H) {
+    std::mem::discriminant(value).hash(state);
+    match value {
+        Value::Number(number) => {
+            // Mixed integer/float equality goes through f64, and -0.0 == 0.0
+            let float = number.as_f64();
+            let float = if float == 0.0 { 0.0 } else { float };
+            float.to_bits().hash(state);
+        },
+        Value::Array(items) => {
+            items.len().hash(state);
+            for item in items.iter() {
+                hash_normalized(item, state);
+            }
+        },
+        Value::Object(object) => {
+            // Sum per-field hashes so field order does not matter
+            let fields = object.iter().fold(0u64, |sum, (key, value)| {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                key.hash(&mut hasher);
+                hash_normalized(value, &mut hasher);
+                sum.wrapping_add(hasher.finish())
+            });
+            object.len().hash(state);
+            fields.hash(state);
+        },
+        _ => value.hash(state),
+    }
+}
+
+// === CSV Conversion ===
+
+#[cfg(feature = "csv")]
+impl ArrayValue {
+    /// Parses RFC 4180 CSV into an array of objects
+    ///
+    /// With `has_header` the first record supplies the keys; otherwise each
+    /// field is keyed by its column index (`"0"`, `"1"`, ...). All fields are
+    /// parsed as strings. Every record must have as many fields as the header,
+    /// or without one as the first record.
+    pub fn from_csv(input: &str, has_header: bool) -> ValueResult<ArrayValue> {
+        let mut records = parse_csv_records(input)?.into_iter();
+
+        let headers: Vec<String> = if has_header {
+            match records.next() {
+                Some(header) => header,
+                None => return Ok(ArrayValue::empty()),
+            }
+        } else {
+            Vec::new()
+        };
+
+        // Without a header the first record fixes the width
+        let mut width = has_header.then_some(headers.len());
+        let mut rows = Vec::new();
+        for (index, record) in records.enumerate() {
+            let expected = *width.get_or_insert(record.len());
+            if record.len() != expected {
+                return Err(ValueError::invalid_format(
+                    "csv",
+                    format!(
+                        "record {} has {} fields, expected {}",
+                        index + 1,
+                        record.len(),
+                        expected
+                    ),
+                ));
+            }
+
+            let mut object = ObjectValue::new();
+            for (column, field) in record.into_iter().enumerate() {
+                let key = if has_header { headers[column].clone() } else { column.to_string() };
+                object.insert(key, Value::string(field));
+            }
+            rows.push(Value::Object(object));
+        }
+
+        Ok(ArrayValue::new(rows))
+    }
+
+    /// Serializes an array of objects to RFC 4180 CSV
+    ///
+    /// When `headers` is `None` the header row is taken from the keys of the
+    /// first object (in insertion order with the `collections` feature).
+    /// Missing keys and nulls become empty fields; arrays, objects and binary
+    /// values cannot be represented and produce an error.
+    pub fn to_csv(&self, headers: Option<&[&str]>) -> ValueResult<String> {
+        let headers: Vec<String> = match headers {
+            Some(headers) => headers.iter().map(|h| (*h).to_string()).collect(),
+            None => match self.0.first() {
+                Some(Value::Object(first)) => first.key_names(),
+                Some(other) => {
+                    return Err(ValueError::type_conversion(other.type_name(), "csv record"));
+                },
+                None => return Ok(String::new()),
+            },
+        };
+
+        let mut output = String::new();
+        write_csv_record(&mut output, headers.iter().map(String::as_str));
+
+        for value in self.0.iter() {
+            let Value::Object(object) = value else {
+                return Err(ValueError::type_conversion(value.type_name(), "csv record"));
+            };
+
+            let fields = headers
+                .iter()
+                .map(|header| match object.get(header) {
+                    None | Some(Value::Null) => Ok(String::new()),
+                    Some(value @ (Value::Array(_) | Value::Object(_) | Value::Binary(_))) => {
+                        Err(ValueError::unsupported_operation("to_csv", value.type_name()))
+                    },
+                    Some(value) => Ok(value.to_string()),
+                })
+                .collect::<ValueResult<Vec<_>>>()?;
+            write_csv_record(&mut output, fields.iter().map(String::as_str));
+        }
+
+        Ok(output)
+    }
+}
+
+/// Splits CSV input into records of unquoted fields
+#[cfg(feature = "csv")]
+fn parse_csv_records(input: &str) -> ValueResult<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut chars = input.chars().peekable();
+    let mut in_quotes = false;
+    // Tracks whether the current record has any content, so a trailing line
+    // break does not produce an extra empty record
+    let mut pending = false;
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                },
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => {
+                in_quotes = true;
+                pending = true;
+            },
+            ',' => {
+                record.push(std::mem::take(&mut field));
+                pending = true;
+            },
+            '\r' if chars.peek() == Some(&'\n') => {},
+            '\n' | '\r' => {
+                if pending {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                    pending = false;
+                }
+            },
+            _ => {
+                field.push(c);
+                pending = true;
+            },
+        }
+    }
+
+    if in_quotes {
+        return Err(ValueError::invalid_format("csv", "unterminated quoted field"));
+    }
+    if pending {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Appends one CSV record terminated by CRLF, quoting fields as needed
+#[cfg(feature = "csv")]
+fn write_csv_record<'a>(output: &mut String, fields: impl Iterator<Item = &'a str>) {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            output.push('"');
+            output.push_str(&field.replace('"', "\"\""));
+            output.push('"');
+        } else {
+            output.push_str(field);
+        }
+    }
+    output.push_str("\r\n");
+}
+
+// === Random Sampling ===
+
+#[cfg(feature = "rand")]
+impl ArrayValue {
+    /// Shuffles the elements in place (Fisher-Yates)
+    ///
+    /// The same seed always produces the same order, on every platform.
+    pub fn shuffle(&mut self, seed: u64) {
+        self.vec_mut().shuffle(&mut seeded_rng(seed));
+    }
+
+    /// Returns `n` elements picked at random without replacement, capped at
+    /// the array's length
+    ///
+    /// Elements at distinct positions are picked; the same seed always picks
+    /// the same elements in the same order.
+    #[must_use]
+    pub fn sample(&self, n: usize, seed: u64) -> ArrayValue {
+        let n = n.min(self.0.len());
+        rand::seq::index::sample(&mut seeded_rng(seed), self.0.len(), n)
+            .into_iter()
+            .map(|i| self.0[i].clone())
+            .collect()
+    }
+}
+
+/// ChaCha8 is portable and value-stable, so seeded results never change
+#[cfg(feature = "rand")]
+fn seeded_rng(seed: u64) -> rand_chacha::ChaCha8Rng {
+    rand::SeedableRng::seed_from_u64(seed)
+}
+
 // === Trait Implementations ===
 
+impl PartialEq for ArrayValue {
+    // Compare contents: `Arc`'s own `PartialEq` treats shared storage as equal
+    // without looking, which would make a NaN element equal to itself
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl Eq for ArrayValue {}
+
+impl Hash for ArrayValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 impl Default for ArrayValue {
     #[inline]
     fn default() -> Self {
@@ -603,6 +1270,18 @@ impl Default for ArrayValue {
     }
 }
 
+impl Drop for ArrayValue {
+    fn drop(&mut self) {
+        // Only nested containers can make the default drop recurse
+        let nested = |values: &&mut Vec<Value>| {
+            values.iter().any(|value| value.is_array() || value.is_object())
+        };
+        if let Some(values) = self.0.get_mut().filter(nested) {
+            crate::value::drop_nested(std::mem::take(values));
+        }
+    }
+}
+
 impl Deref for ArrayValue {
     type Target = Vec<Value>;
 
@@ -615,7 +1294,7 @@ impl Deref for ArrayValue {
 impl DerefMut for ArrayValue {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        self.vec_mut()
     }
 }
 
@@ -631,7 +1310,7 @@ impl Index<usize> for ArrayValue {
 impl IndexMut<usize> for ArrayValue {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.0[index]
+        &mut self.vec_mut()[index]
     }
 }
 
@@ -652,7 +1331,7 @@ where T: Into<Value>
 
 impl From<ArrayValue> for Vec<Value> {
     fn from(array: ArrayValue) -> Self {
-        array.0
+        array.into_vec()
     }
 }
 
@@ -665,7 +1344,7 @@ impl FromIterator<Value> for ArrayValue {
 
 impl Extend<Value> for ArrayValue {
     fn extend<T: IntoIterator<Item = Value>>(&mut self, iter: T) {
-        self.0.extend(iter);
+        self.vec_mut().extend(iter);
     }
 }
 
@@ -674,7 +1353,7 @@ impl IntoIterator for ArrayValue {
     type IntoIter = std::vec::IntoIter<Value>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.into_vec().into_iter()
     }
 }
 
@@ -692,7 +1371,7 @@ impl<'a> IntoIterator for &'a mut ArrayValue {
     type IntoIter = std::slice::IterMut<'a, Value>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter_mut()
+        self.vec_mut().iter_mut()
     }
 }
 
@@ -700,7 +1379,7 @@ impl<'a> IntoIterator for &'a mut ArrayValue {
 #[cfg(feature = "json")]
 impl From<ArrayValue> for serde_json::Value {
     fn from(array: ArrayValue) -> Self {
-        serde_json::Value::Array(array.0.into_iter().map(|value| value.into()).collect())
+        serde_json::Value::Array(array.into_iter().map(|value| value.into()).collect())
     }
 }
 
@@ -727,6 +1406,7 @@ impl TryFrom<serde_json::Value> for ArrayValue {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ObjectValue, ValueComparison};
 
     #[test]
     fn test_array_creation() {
@@ -735,6 +1415,36 @@ mod tests {
         assert!(!arr.is_empty());
     }
 
+    #[test]
+    fn test_clone_shares_storage_until_mutated() {
+        let original = ArrayValue::filled(&Value::string("x"), 10_000);
+        let mut copy = original.clone();
+        assert_eq!(original.as_ptr(), copy.as_ptr());
+
+        copy[0] = Value::null();
+        assert_ne!(original.as_ptr(), copy.as_ptr());
+        assert_eq!(original[0], Value::string("x"));
+        assert_eq!(original, ArrayValue::filled(&Value::string("x"), 10_000));
+
+        // Shared storage still compares element by element
+        let nan = Value::array(vec![Value::number(f64::NAN)]);
+        assert_ne!(nan, nan.clone());
+    }
+
+    #[test]
+    fn test_const_constructors() {
+        const EMPTY: ArrayValue = ArrayValue::empty();
+        assert!(EMPTY.is_empty());
+
+        // An owned array is copied on its first clone; clones of that clone share
+        let original = ArrayValue::from_vec(vec![Value::number(1), Value::number(2)]);
+        let copy = original.clone();
+        assert_ne!(original.as_ptr(), copy.as_ptr());
+        assert_eq!(copy.as_ptr(), copy.clone().as_ptr());
+        assert_eq!(original, copy);
+        assert_eq!(original.into_vec(), vec![Value::number(1), Value::number(2)]);
+    }
+
     #[test]
     fn test_array_range() {
         let arr = ArrayValue::range(0, 5, None).unwrap();
@@ -755,6 +1465,55 @@ mod tests {
         assert_eq!(arr.len(), 3);
     }
 
+    #[test]
+    fn test_array_range_editing() {
+        let mut arr = ArrayValue::from(vec![1, 2, 5]);
+        arr.insert(2, Value::number(3)).unwrap();
+        arr.insert(3, Value::number(4)).unwrap();
+        assert_eq!(arr, ArrayValue::from(vec![1, 2, 3, 4, 5]));
+
+        let removed = arr.remove_range(1, 3).unwrap();
+        assert_eq!(removed, ArrayValue::from(vec![2, 3]));
+        assert_eq!(arr, ArrayValue::from(vec![1, 4, 5]));
+        assert!(arr.remove_range(1, 1).unwrap().is_empty());
+
+        let removed = arr.splice(1, 1, vec![Value::number(2), Value::number(3)]).unwrap();
+        assert_eq!(removed, ArrayValue::from(vec![4]));
+        assert_eq!(arr, ArrayValue::from(vec![1, 2, 3, 5]));
+
+        // A delete count past the end removes the rest; start == len appends
+        assert_eq!(arr.splice(3, 10, vec![]).unwrap(), ArrayValue::from(vec![5]));
+        arr.splice(3, 0, vec![Value::number(4)]).unwrap();
+        assert_eq!(arr, ArrayValue::from(vec![1, 2, 3, 4]));
+
+        let error = arr.remove_range(2, 9).unwrap_err();
+        assert_eq!(error, ValueError::index_out_of_bounds(9, 4));
+        assert!(arr.remove_range(3, 2).unwrap_err().to_string().contains("start (3) > end (2)"));
+        assert!(arr.splice(5, 0, vec![]).is_err());
+        assert!(arr.insert(5, Value::null()).is_err());
+        assert_eq!(arr.len(), 4);
+    }
+
+    #[test]
+    fn test_windowed() {
+        let arr = ArrayValue::from(vec![1, 2, 3, 4, 5]);
+        let averages = arr.moving_average(3).unwrap();
+        assert_eq!(averages, ArrayValue::from(vec![2.0, 3.0, 4.0]));
+        assert_eq!(arr.moving_average(5).unwrap(), ArrayValue::from(vec![3.0]));
+
+        let error = arr.moving_average(6).unwrap_err();
+        assert!(error.to_string().contains("Window size 6 exceeds array length 5"));
+        assert!(arr.moving_average(0).is_err());
+        let mixed = ArrayValue::new(vec![Value::number(1), Value::string("x")]);
+        assert!(mixed.moving_average(1).is_err());
+
+        let windows = arr.windowed_reduce(4, |window| Ok(Value::array(window.to_vec()))).unwrap();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[1], Value::array(vec![2, 3, 4, 5]));
+        assert!(arr.windowed_reduce(2, |_| Err(ValueError::custom("stop"))).is_err());
+        assert!(ArrayValue::empty().windowed_reduce(1, |w| Ok(w[0].clone())).is_err());
+    }
+
     #[test]
     fn test_array_functional() {
         let arr = ArrayValue::new(vec![Value::number(1), Value::number(2), Value::number(3)]);
@@ -774,6 +1533,177 @@ mod tests {
         assert_eq!(doubled[2], Value::number(6));
     }
 
+    #[test]
+    fn test_array_predicates() {
+        let user = |name: &str, active: bool| {
+            let mut object = ObjectValue::new();
+            object.insert("name".to_string(), Value::string(name));
+            object.insert("active".to_string(), Value::boolean(active));
+            Value::object(object)
+        };
+        let users = ArrayValue::new(vec![user("ann", false), user("bob", true), user("cy", true)]);
+        let is_active = |v: &Value| {
+            v.as_object().and_then(|o| o.get("active")) == Some(&Value::boolean(true))
+        };
+
+        assert_eq!(users.find(is_active), Some(&users[1]));
+        assert_eq!(users.find_index(is_active), Some(1));
+        assert_eq!(users.count(is_active), 2);
+        assert_eq!(
+            users.find_map(|v| v.as_object()?.get("name")?.as_string().filter(|n| n.len() == 2)),
+            Some("cy")
+        );
+
+        let numbers = ArrayValue::new(vec![Value::number(2), Value::number(4), Value::number(7)]);
+        let is_even =
+            |v: &Value| v.as_number().and_then(|n| n.as_i64().ok()).is_some_and(|n| n % 2 == 0);
+        assert!(numbers.any(is_even));
+        assert!(!numbers.all(is_even));
+        assert!(numbers.all(Value::is_number));
+    }
+
+    #[test]
+    fn test_array_fold_reduce() {
+        let arr = ArrayValue::new(vec![Value::number(3), Value::number(7), Value::number(5)]);
+
+        let sum = arr
+            .fold(Value::number(0), |acc, v| {
+                let total = acc.as_number().unwrap().as_i64().unwrap();
+                Ok(Value::number(total + v.as_number().unwrap().as_i64().unwrap()))
+            })
+            .unwrap();
+        assert_eq!(sum, Value::number(15));
+
+        let max = arr
+            .reduce(|acc, v| {
+                let bigger = ValueComparison::greater_than(v, &acc).is_true();
+                Ok(if bigger { v.clone() } else { acc })
+            })
+            .unwrap();
+        assert_eq!(max, Value::number(7));
+
+        let aborted = arr.fold(0, |acc, v| {
+            if *v == Value::number(7) { Err(ValueError::custom("stop")) } else { Ok(acc + 1) }
+        });
+        assert_eq!(aborted, Err(ValueError::custom("stop")));
+
+        let empty = ArrayValue::new(Vec::<Value>::new());
+        assert!(empty.reduce(|acc, _| Ok(acc)).is_err());
+    }
+
+    #[test]
+    fn test_array_borrowing_iterators() {
+        let mut arr = ArrayValue::new(vec![Value::number(1), Value::string("x"), Value::number(3)]);
+
+        let mut seen = 0;
+        for value in &arr {
+            assert!(!value.is_null());
+            seen += 1;
+        }
+        assert_eq!(seen, 3);
+
+        for value in arr.iter_mut() {
+            if let Some(n) = value.as_number() {
+                *value = Value::number(n.as_i64().unwrap() * 10);
+            }
+        }
+        assert_eq!(arr[0], Value::number(10));
+        assert_eq!(arr[2], Value::number(30));
+
+        let numbers: Vec<i64> = (&arr)
+            .into_iter()
+            .filter(|v| v.is_number())
+            .map(|v| v.as_number().unwrap().as_i64().unwrap())
+            .collect();
+        assert_eq!(numbers, vec![10, 30]);
+        assert_eq!(arr.len(), 3);
+    }
+
+    #[test]
+    fn test_array_binary_search() {
+        let arr = ArrayValue::new(vec![Value::number(1), Value::number(3), Value::number(5)]);
+        assert_eq!(arr.binary_search(&Value::number(3)), Ok(1));
+        assert_eq!(arr.binary_search(&Value::number(4)), Err(2));
+        assert_eq!(arr.binary_search(&Value::number(0)), Err(0));
+        assert_eq!(arr.binary_search(&Value::number(9)), Err(3));
+        assert_eq!(arr.binary_search(&Value::number(3.0)), Ok(1));
+        assert_eq!(arr.binary_search(&Value::number(3.5)), Err(2));
+    }
+
+    #[test]
+    fn test_array_sort_total_order() {
+        let mut arr = ArrayValue::new(vec![
+            Value::number(f64::NAN),
+            Value::number(3),
+            Value::number(i64::MAX),
+            Value::number(-1.5),
+            Value::number(9_007_199_254_740_993_i64),
+            Value::number(9_007_199_254_740_992.0),
+        ]);
+        arr.sort().unwrap();
+
+        assert_eq!(arr[0], Value::number(-1.5));
+        assert_eq!(arr[1], Value::number(3));
+        // 2^53 + 1 rounds to 2^53 as a float but still sorts above it
+        assert_eq!(arr[2], Value::number(9_007_199_254_740_992.0));
+        assert_eq!(arr[3], Value::number(9_007_199_254_740_993_i64));
+        assert_eq!(arr[4], Value::number(i64::MAX));
+        assert!(arr[5].as_number().is_some_and(|n| n.is_nan()));
+        assert_eq!(arr.binary_search(&Value::number(9_007_199_254_740_993_i64)), Ok(3));
+    }
+
+    #[test]
+    fn test_array_insert_sorted() {
+        let mut arr = ArrayValue::empty();
+        for n in [5, 1, 4, 2, 3, 0] {
+            arr.insert_sorted(Value::number(n));
+        }
+
+        let expected: Vec<Value> = (0..6).map(Value::number).collect();
+        assert_eq!(arr.into_vec(), expected);
+    }
+
+    #[test]
+    fn test_array_dedup_by_key() {
+        let record = |id: i64, name: &str| {
+            let mut object = ObjectValue::new();
+            object.insert("id".to_string(), Value::number(id));
+            object.insert("name".to_string(), Value::string(name));
+            Value::object(object)
+        };
+        let arr = ArrayValue::new(vec![
+            record(1, "first"),
+            record(2, "second"),
+            record(1, "duplicate"),
+            record(3, "third"),
+        ]);
+
+        let deduped = arr.dedup_by_key(|v| {
+            v.as_object().and_then(|o| o.get("id")).cloned().unwrap_or(Value::Null)
+        });
+        assert_eq!(deduped.len(), 3);
+        assert_eq!(deduped[0], record(1, "first"));
+        assert_eq!(deduped[2], record(3, "third"));
+
+        // Integer and float keys that compare equal are duplicates
+        let mixed = ArrayValue::new(vec![
+            Value::number(1),
+            Value::number(1.0),
+            Value::number(-0.0),
+            Value::number(0),
+            Value::array(vec![Value::number(2)]),
+            Value::array(vec![Value::number(2.0)]),
+        ]);
+        assert_eq!(mixed.dedup_by_key(Value::clone).len(), 3);
+    }
+
+    #[test]
+    fn test_array_dedup_consecutive() {
+        let arr: ArrayValue = vec![1, 1, 2, 3, 3, 3, 1].into_iter().map(Value::number).collect();
+        let expected: ArrayValue = vec![1, 2, 3, 1].into_iter().map(Value::number).collect();
+        assert_eq!(arr.dedup_consecutive(), expected);
+    }
+
     #[test]
     fn test_array_unique() {
         let arr = ArrayValue::new(vec![
@@ -791,6 +1721,112 @@ mod tests {
         assert!(unique.contains(&Value::number(3)));
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_shuffle_and_sample() {
+        let original: ArrayValue = (0..20).map(|i: i64| Value::number(i)).collect();
+
+        let mut a = original.clone();
+        let mut b = original.clone();
+        a.shuffle(42);
+        b.shuffle(42);
+        assert_eq!(a, b);
+        assert_ne!(a, original);
+        assert_eq!(a.len(), original.len());
+        assert!(original.iter().all(|value| a.contains(value)));
+
+        let sample = original.sample(5, 7);
+        assert_eq!(sample.len(), 5);
+        assert_eq!(sample.unique().len(), 5);
+        assert!(sample.iter().all(|value| original.contains(value)));
+        assert_eq!(sample, original.sample(5, 7));
+
+        assert_eq!(original.sample(100, 7).len(), 20);
+        assert!(ArrayValue::empty().sample(3, 7).is_empty());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let row = |items: &[i64]| {
+            let cells = items.iter().map(|&i| Value::number(i)).collect::<Vec<_>>();
+            Value::Array(ArrayValue::new(cells))
+        };
+        let matrix = ArrayValue::new(vec![row(&[1, 2, 3]), row(&[4, 5, 6])]);
+
+        let transposed = matrix.transpose().unwrap();
+        assert_eq!(transposed, ArrayValue::new(vec![row(&[1, 4]), row(&[2, 5]), row(&[3, 6])]));
+        assert_eq!(transposed.transpose().unwrap(), matrix);
+
+        assert!(ArrayValue::new(vec![]).transpose().unwrap().is_empty());
+
+        let ragged = ArrayValue::new(vec![row(&[1, 2]), row(&[3])]);
+        assert!(ragged.transpose().unwrap_err().to_string().contains("row 1"));
+
+        let not_rows = ArrayValue::new(vec![row(&[1]), Value::number(2)]);
+        assert!(not_rows.transpose().is_err());
+    }
+
+    #[test]
+    fn test_join_on() {
+        let row = |fields: &[(&str, Value)]| {
+            Value::Object(fields.iter().map(|(k, v)| (k.to_string(), v.clone())).collect())
+        };
+        let users = ArrayValue::new(vec![
+            row(&[("id", Value::number(1)), ("name", Value::string("ann"))]),
+            row(&[("id", Value::number(2)), ("name", Value::string("bob"))]),
+        ]);
+        let orders = ArrayValue::new(vec![
+            row(&[("id", Value::number(1)), ("total", Value::number(10))]),
+            row(&[("id", Value::number(3)), ("total", Value::number(30))]),
+        ]);
+
+        let inner = users.join_on(&orders, "id", JoinKind::Inner).unwrap();
+        assert_eq!(
+            inner,
+            ArrayValue::new(vec![row(&[
+                ("id", Value::number(1)),
+                ("name", Value::string("ann")),
+                ("total", Value::number(10)),
+            ])])
+        );
+
+        let left = users.join_on(&orders, "id", JoinKind::Left).unwrap();
+        assert_eq!(left.len(), 2);
+        assert_eq!(left[0], inner[0]);
+        assert_eq!(
+            left[1],
+            row(&[
+                ("id", Value::number(2)),
+                ("name", Value::string("bob")),
+                ("total", Value::null()),
+            ])
+        );
+
+        let full = users.join_on(&orders, "id", JoinKind::Full).unwrap();
+        assert_eq!(full.len(), 3);
+        assert_eq!(
+            full[2],
+            row(&[
+                ("id", Value::number(3)),
+                ("name", Value::null()),
+                ("total", Value::number(30)),
+            ])
+        );
+
+        let keyless = ArrayValue::new(vec![row(&[("name", Value::string("cy"))])]);
+        let error = keyless.join_on(&orders, "id", JoinKind::Inner).unwrap_err();
+        assert!(error.to_string().contains("Element 0 of the left array has no 'id' field"));
+        let scalars = ArrayValue::new(vec![Value::number(1)]);
+        assert!(users.join_on(&scalars, "id", JoinKind::Left).is_err());
+
+        // Integer and float keys that compare equal match
+        let float_orders =
+            ArrayValue::new(vec![row(&[("id", Value::number(1.0)), ("total", Value::number(10))])]);
+        let joined = users.join_on(&float_orders, "id", JoinKind::Inner).unwrap();
+        assert_eq!(joined.len(), 1);
+        assert_eq!(joined[0].as_object().unwrap().get("name"), Some(&Value::string("ann")));
+    }
+
     #[cfg(all(feature = "json", feature = "serde"))]
     #[test]
     fn test_json_conversion() {
@@ -807,4 +1843,43 @@ mod tests {
         let back: ArrayValue = json.try_into().unwrap();
         assert_eq!(back, arr);
     }
+
+    #[cfg(all(feature = "csv", feature = "collections"))]
+    #[test]
+    fn test_csv_round_trip() {
+        let input = "name,note\r\n\"Smith, John\",\"said \"\"hi\"\"\"\r\nDoe,\"line1\nline2\"\r\n";
+        let arr = ArrayValue::from_csv(input, true).unwrap();
+
+        assert_eq!(arr.len(), 2);
+        let first = arr[0].as_object().unwrap();
+        assert_eq!(first.get("name"), Some(&Value::string("Smith, John")));
+        assert_eq!(first.get("note"), Some(&Value::string("said \"hi\"")));
+        let second = arr[1].as_object().unwrap();
+        assert_eq!(second.get("note"), Some(&Value::string("line1\nline2")));
+
+        let output = arr.to_csv(None).unwrap();
+        assert_eq!(output, input);
+        assert_eq!(ArrayValue::from_csv(&output, true).unwrap(), arr);
+
+        let subset = arr.to_csv(Some(&["note"])).unwrap();
+        assert!(subset.starts_with("note\r\n\"said"));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_headerless() {
+        let arr = ArrayValue::from_csv("a,b,c\n1,,3\n", false).unwrap();
+
+        assert_eq!(arr.len(), 2);
+        let row = arr[1].as_object().unwrap();
+        assert_eq!(row.get("0"), Some(&Value::string("1")));
+        assert_eq!(row.get("1"), Some(&Value::string("")));
+        assert_eq!(row.get("2"), Some(&Value::string("3")));
+
+        assert!(ArrayValue::from_csv("a,b\n1\n", true).is_err());
+        assert!(ArrayValue::from_csv("a,b\n1\n", false).is_err());
+        assert!(ArrayValue::from_csv("a,b\n1,2,3\n", false).is_err());
+        assert!(ArrayValue::from_csv("\"open", false).is_err());
+        assert!(ArrayValue::from(vec![1, 2]).to_csv(None).is_err());
+    }
 }
@@ -1,19 +1,48 @@
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use std::str::FromStr;
+use std::sync::Arc;
 
 use base64::Engine as _;
 use base64::engine::general_purpose;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "hash")]
+use sha2::Digest as _;
 
 use crate::{ValueError, ValueResult};
 
 /// Binary data value type with comprehensive operations and encodings
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Bytes live behind an [`Arc`], so cloning is cheap: clones share storage
+/// until one of them is mutated, which copies the bytes first.
+#[derive(Debug, Clone, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
-pub struct BinaryValue(#[cfg_attr(feature = "serde", serde(with = "serde_bytes"))] Vec<u8>);
+pub struct BinaryValue(#[cfg_attr(feature = "serde", serde(with = "shared_bytes"))] Arc<Vec<u8>>);
+
+/// `serde_bytes` encoding for the shared byte buffer
+#[cfg(feature = "serde")]
+mod shared_bytes {
+    use std::sync::Arc;
+
+    use serde::{Deserializer, Serializer};
+
+    pub(super) fn serialize<S>(bytes: &Arc<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serde_bytes::serialize(bytes.as_slice(), serializer)
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Arc<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_bytes::deserialize(deserializer).map(Arc::new)
+    }
+}
 
 impl BinaryValue {
     // === Constructors ===
@@ -22,39 +51,39 @@ impl BinaryValue {
     #[inline]
     #[must_use]
     pub fn new(data: Vec<u8>) -> Self {
-        Self(data)
+        Self(Arc::new(data))
     }
 
     /// Creates an empty binary value
     #[inline]
     #[must_use]
     pub fn empty() -> Self {
-        Self(Vec::new())
+        Self::new(Vec::new())
     }
 
     /// Creates a binary value with specific capacity
     #[inline]
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(Vec::with_capacity(capacity))
+        Self::new(Vec::with_capacity(capacity))
     }
 
     /// Creates a binary value filled with zeros
     #[must_use]
     pub fn zeros(size: usize) -> Self {
-        Self(vec![0; size])
+        Self::new(vec![0; size])
     }
 
     /// Creates a binary value filled with ones (0xFF)
     #[must_use]
     pub fn ones(size: usize) -> Self {
-        Self(vec![0xFF; size])
+        Self::new(vec![0xFF; size])
     }
 
     /// Creates a binary value filled with a specific byte
     #[must_use]
     pub fn filled(byte: u8, size: usize) -> Self {
-        Self(vec![byte; size])
+        Self::new(vec![byte; size])
     }
 
     // === Basic Properties ===
@@ -83,19 +112,19 @@ impl BinaryValue {
     /// Reserves capacity for at least additional more bytes
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
-        self.0.reserve(additional);
+        self.bytes_mut().reserve(additional);
     }
 
     /// Shrinks the capacity as much as possible
     #[inline]
     pub fn shrink_to_fit(&mut self) {
-        self.0.shrink_to_fit();
+        self.bytes_mut().shrink_to_fit();
     }
 
     /// Clears all data
     #[inline]
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.bytes_mut().clear();
     }
 
     // === Data Access ===
@@ -111,14 +140,22 @@ impl BinaryValue {
     #[inline]
     #[must_use]
     pub fn as_bytes_mut(&mut self) -> &mut [u8] {
-        &mut self.0
+        self.bytes_mut()
     }
 
     /// Consumes self and returns inner Vec<u8>
+    ///
+    /// Copies the bytes only if the storage is shared with a clone.
     #[inline]
     #[must_use]
     pub fn into_bytes(self) -> Vec<u8> {
-        self.0
+        Arc::unwrap_or_clone(self.0)
+    }
+
+    /// Mutable access to the bytes, copying them first if shared
+    #[inline]
+    fn bytes_mut(&mut self) -> &mut Vec<u8> {
+        Arc::make_mut(&mut self.0)
     }
 
     /// Gets a byte at the specified index
@@ -148,7 +185,7 @@ impl BinaryValue {
                 self.len()
             )));
         }
-        self.0[index] = byte;
+        self.bytes_mut()[index] = byte;
         Ok(())
     }
 
@@ -157,14 +194,14 @@ impl BinaryValue {
     /// Appends a byte to the end
     #[inline]
     pub fn push(&mut self, byte: u8) {
-        self.0.push(byte);
+        self.bytes_mut().push(byte);
     }
 
     /// Removes and returns the last byte
     #[inline]
     #[must_use]
     pub fn pop(&mut self) -> Option<u8> {
-        self.0.pop()
+        self.bytes_mut().pop()
     }
 
     /// Inserts a byte at the specified position
@@ -172,7 +209,7 @@ impl BinaryValue {
         if index > self.len() {
             return Err(ValueError::custom(format!("Index {index} out of bounds for insertion")));
         }
-        self.0.insert(index, byte);
+        self.bytes_mut().insert(index, byte);
         Ok(())
     }
 
@@ -181,25 +218,25 @@ impl BinaryValue {
         if index >= self.len() {
             return Err(ValueError::custom(format!("Index {index} out of bounds for removal")));
         }
-        Ok(self.0.remove(index))
+        Ok(self.bytes_mut().remove(index))
     }
 
     /// Extends with bytes from another BinaryValue
     #[inline]
     pub fn extend(&mut self, other: &Self) {
-        self.0.extend_from_slice(&other.0);
+        self.bytes_mut().extend_from_slice(&other.0);
     }
 
     /// Extends with bytes from a slice
     #[inline]
     pub fn extend_from_slice(&mut self, bytes: &[u8]) {
-        self.0.extend_from_slice(bytes);
+        self.bytes_mut().extend_from_slice(bytes);
     }
 
     /// Appends another BinaryValue
     #[inline]
     pub fn append(&mut self, other: &mut Self) {
-        self.0.append(&mut other.0);
+        self.bytes_mut().append(other.bytes_mut());
     }
 
     // === Slicing and Subranges ===
@@ -248,19 +285,19 @@ impl BinaryValue {
     /// Converts to base64 string using standard encoding
     #[must_use]
     pub fn to_base64(&self) -> String {
-        general_purpose::STANDARD.encode(&self.0)
+        general_purpose::STANDARD.encode(self.as_bytes())
     }
 
     /// Converts to base64 string using URL-safe encoding
     #[must_use]
     pub fn to_base64_url(&self) -> String {
-        general_purpose::URL_SAFE.encode(&self.0)
+        general_purpose::URL_SAFE.encode(self.as_bytes())
     }
 
     /// Converts to base64 string without padding
     #[must_use]
     pub fn to_base64_no_pad(&self) -> String {
-        general_purpose::STANDARD_NO_PAD.encode(&self.0)
+        general_purpose::STANDARD_NO_PAD.encode(self.as_bytes())
     }
 
     /// Creates from base64 string (standard encoding)
@@ -295,6 +332,11 @@ impl BinaryValue {
     pub fn from_hex(hex: &str) -> ValueResult<Self> {
         let hex = hex.trim().replace(" ", "").replace(":", "").replace("-", "");
 
+        // Validate before slicing: a multi-byte character would split a byte
+        // pair off a char boundary, and `from_str_radix` accepts a sign
+        if let Some(c) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ValueError::custom(format!("Invalid hex character: '{c}'")));
+        }
         if hex.len() % 2 != 0 {
             return Err(ValueError::custom("Hex string must have even length"));
         }
@@ -316,7 +358,7 @@ impl BinaryValue {
 
     /// Attempts to convert to UTF-8 string
     pub fn to_utf8(&self) -> ValueResult<String> {
-        String::from_utf8(self.0.clone())
+        String::from_utf8(self.0.to_vec())
             .map_err(|e| ValueError::custom(format!("Invalid UTF-8: {e}")))
     }
 
@@ -326,6 +368,54 @@ impl BinaryValue {
         String::from_utf8_lossy(&self.0).into_owned()
     }
 
+    /// Decodes UTF-16 text in the given byte order
+    ///
+    /// A leading byte order mark is stripped and takes precedence over
+    /// `little_endian`. Errors on odd-length input or unpaired surrogates.
+    pub fn to_utf16_string(&self, little_endian: bool) -> ValueResult<String> {
+        let (bytes, little_endian) = match self.0.as_slice() {
+            [0xFF, 0xFE, rest @ ..] => (rest, true),
+            [0xFE, 0xFF, rest @ ..] => (rest, false),
+            bytes => (bytes, little_endian),
+        };
+
+        if bytes.len() % 2 != 0 {
+            return Err(ValueError::custom(format!(
+                "Invalid UTF-16: odd byte length {}",
+                bytes.len()
+            )));
+        }
+
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| {
+                let pair = [pair[0], pair[1]];
+                if little_endian { u16::from_le_bytes(pair) } else { u16::from_be_bytes(pair) }
+            })
+            .collect();
+
+        String::from_utf16(&units).map_err(|e| ValueError::custom(format!("Invalid UTF-16: {e}")))
+    }
+
+    /// Decodes Latin-1 (ISO-8859-1) text, where each byte is one code point
+    #[must_use]
+    pub fn to_latin1_string(&self) -> String {
+        self.0.iter().map(|&byte| char::from(byte)).collect()
+    }
+
+    /// Decodes text, choosing the encoding from its byte order mark
+    ///
+    /// Recognizes UTF-8 and UTF-16 (LE/BE) marks and strips them; input
+    /// without a mark is decoded as UTF-8.
+    pub fn decode_text(&self) -> ValueResult<String> {
+        match self.0.as_slice() {
+            [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8(rest.to_vec())
+                .map_err(|e| ValueError::custom(format!("Invalid UTF-8: {e}"))),
+            [0xFF, 0xFE, ..] | [0xFE, 0xFF, ..] => self.to_utf16_string(true),
+            _ => self.to_utf8(),
+        }
+    }
+
     // === Bitwise Operations ===
 
     /// Performs bitwise AND with another BinaryValue
@@ -336,7 +426,7 @@ impl BinaryValue {
             ));
         }
 
-        let result: Vec<u8> = self.0.iter().zip(&other.0).map(|(a, b)| a & b).collect();
+        let result: Vec<u8> = self.0.iter().zip(other.0.iter()).map(|(a, b)| a & b).collect();
 
         Ok(Self::new(result))
     }
@@ -349,7 +439,7 @@ impl BinaryValue {
             ));
         }
 
-        let result: Vec<u8> = self.0.iter().zip(&other.0).map(|(a, b)| a | b).collect();
+        let result: Vec<u8> = self.0.iter().zip(other.0.iter()).map(|(a, b)| a | b).collect();
 
         Ok(Self::new(result))
     }
@@ -362,7 +452,7 @@ impl BinaryValue {
             ));
         }
 
-        let result: Vec<u8> = self.0.iter().zip(&other.0).map(|(a, b)| a ^ b).collect();
+        let result: Vec<u8> = self.0.iter().zip(other.0.iter()).map(|(a, b)| a ^ b).collect();
 
         Ok(Self::new(result))
     }
@@ -472,20 +562,20 @@ impl BinaryValue {
     /// Reverses the byte order
     #[must_use]
     pub fn reverse(&self) -> Self {
-        let mut result = self.0.clone();
+        let mut result = self.0.to_vec();
         result.reverse();
         Self::new(result)
     }
 
     /// Reverses the byte order in place
     pub fn reverse_mut(&mut self) {
-        self.0.reverse();
+        self.bytes_mut().reverse();
     }
 
     /// Removes duplicate consecutive bytes
     #[must_use]
     pub fn dedup(&self) -> Self {
-        let mut result = self.0.clone();
+        let mut result = self.0.to_vec();
         result.dedup();
         Self::new(result)
     }
@@ -493,7 +583,7 @@ impl BinaryValue {
     /// Concatenates with another BinaryValue
     #[must_use]
     pub fn concat(&self, other: &Self) -> Self {
-        let mut result = self.0.clone();
+        let mut result = self.0.to_vec();
         result.extend_from_slice(&other.0);
         Self::new(result)
     }
@@ -522,12 +612,24 @@ impl BinaryValue {
     #[must_use]
     pub fn hash_simple(&self) -> u64 {
         let mut hash = 0u64;
-        for &byte in &self.0 {
+        for &byte in self.0.iter() {
             hash = hash.wrapping_mul(31).wrapping_add(byte as u64);
         }
         hash
     }
 
+    /// Computes a digest of the binary data with the given algorithm
+    ///
+    /// For data that does not fit in memory, feed chunks to a
+    /// [`BinaryHasher`] instead.
+    #[cfg(feature = "hash")]
+    #[must_use]
+    pub fn digest(&self, algorithm: HashAlgorithm) -> Vec<u8> {
+        let mut hasher = BinaryHasher::new(algorithm);
+        hasher.update(&self.0);
+        hasher.finalize()
+    }
+
     /// Returns statistics about byte distribution
     #[must_use]
     pub fn byte_statistics(&self) -> ByteStatistics {
@@ -539,7 +641,7 @@ impl BinaryValue {
         let mut min = u8::MAX;
         let mut max = u8::MIN;
 
-        for &byte in &self.0 {
+        for &byte in self.0.iter() {
             counts[byte as usize] += 1;
             min = min.min(byte);
             max = max.max(byte);
@@ -573,7 +675,7 @@ impl BinaryValue {
         }
 
         let mut counts = [0usize; 256];
-        for &byte in &self.0 {
+        for &byte in self.0.iter() {
             counts[byte as usize] += 1;
         }
 
@@ -655,6 +757,73 @@ pub struct ByteStatistics {
     pub most_common_count: usize,
 }
 
+/// Digest algorithms supported by [`BinaryHasher`]
+#[cfg(feature = "hash")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HashAlgorithm {
+    /// CRC-32 (IEEE), 4-byte big-endian digest
+    Crc32,
+    /// SHA-256, 32-byte digest
+    Sha256,
+}
+
+/// Incremental hasher for binary data fed in chunks
+///
+/// Produces the same digest as [`BinaryValue::digest`] over the concatenated
+/// chunks without buffering them.
+#[cfg(feature = "hash")]
+#[derive(Debug, Clone)]
+pub struct BinaryHasher {
+    state: HasherState,
+}
+
+#[cfg(feature = "hash")]
+#[derive(Debug, Clone)]
+enum HasherState {
+    Crc32(crc32fast::Hasher),
+    Sha256(sha2::Sha256),
+}
+
+#[cfg(feature = "hash")]
+impl BinaryHasher {
+    /// Creates a hasher for the given algorithm
+    #[must_use]
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        let state = match algorithm {
+            HashAlgorithm::Crc32 => HasherState::Crc32(crc32fast::Hasher::new()),
+            HashAlgorithm::Sha256 => HasherState::Sha256(sha2::Sha256::new()),
+        };
+        Self { state }
+    }
+
+    /// Returns the algorithm this hasher uses
+    #[must_use]
+    pub fn algorithm(&self) -> HashAlgorithm {
+        match self.state {
+            HasherState::Crc32(_) => HashAlgorithm::Crc32,
+            HasherState::Sha256(_) => HashAlgorithm::Sha256,
+        }
+    }
+
+    /// Feeds a chunk of data into the hasher
+    pub fn update(&mut self, chunk: &[u8]) {
+        match &mut self.state {
+            HasherState::Crc32(crc) => crc.update(chunk),
+            HasherState::Sha256(sha) => sha.update(chunk),
+        }
+    }
+
+    /// Consumes the hasher and returns the digest
+    #[must_use]
+    pub fn finalize(self) -> Vec<u8> {
+        match self.state {
+            HasherState::Crc32(crc) => crc.finalize().to_be_bytes().to_vec(),
+            HasherState::Sha256(sha) => sha.finalize().to_vec(),
+        }
+    }
+}
+
 impl Default for BinaryValue {
     #[inline]
     fn default() -> Self {
@@ -664,6 +833,19 @@ impl Default for BinaryValue {
 
 // === Trait Implementations ===
 
+impl PartialEq for BinaryValue {
+    // Compare contents, like the other shared containers
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl Hash for BinaryValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 impl fmt::Display for BinaryValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.to_base64())
@@ -697,7 +879,7 @@ impl Deref for BinaryValue {
 impl DerefMut for BinaryValue {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        self.bytes_mut()
     }
 }
 
@@ -706,35 +888,35 @@ impl DerefMut for BinaryValue {
 impl From<Vec<u8>> for BinaryValue {
     #[inline]
     fn from(data: Vec<u8>) -> Self {
-        Self(data)
+        Self::new(data)
     }
 }
 
 impl From<&[u8]> for BinaryValue {
     #[inline]
     fn from(data: &[u8]) -> Self {
-        Self(data.to_vec())
+        Self::new(data.to_vec())
     }
 }
 
 impl From<&str> for BinaryValue {
     #[inline]
     fn from(data: &str) -> Self {
-        Self(data.as_bytes().to_vec())
+        Self::new(data.as_bytes().to_vec())
     }
 }
 
 impl From<String> for BinaryValue {
     #[inline]
     fn from(data: String) -> Self {
-        Self(data.into_bytes())
+        Self::new(data.into_bytes())
     }
 }
 
 impl From<BinaryValue> for Vec<u8> {
     #[inline]
     fn from(value: BinaryValue) -> Vec<u8> {
-        value.0
+        value.into_bytes()
     }
 }
 
@@ -742,13 +924,13 @@ impl From<BinaryValue> for Vec<u8> {
 
 impl FromIterator<u8> for BinaryValue {
     fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        Self::new(iter.into_iter().collect())
     }
 }
 
 impl Extend<u8> for BinaryValue {
     fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
-        self.0.extend(iter);
+        self.bytes_mut().extend(iter);
     }
 }
 
@@ -757,7 +939,7 @@ impl IntoIterator for BinaryValue {
     type IntoIter = std::vec::IntoIter<u8>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.into_bytes().into_iter()
     }
 }
 
@@ -823,6 +1005,17 @@ mod tests {
         assert_eq!(filled.as_bytes(), &[42, 42, 42, 42]);
     }
 
+    #[test]
+    fn test_clone_shares_storage_until_mutated() {
+        let original = BinaryValue::zeros(1 << 16);
+        let mut copy = original.clone();
+        assert_eq!(original.as_bytes().as_ptr(), copy.as_bytes().as_ptr());
+
+        copy.push(1);
+        assert_ne!(original.as_bytes().as_ptr(), copy.as_bytes().as_ptr());
+        assert_eq!(original.len(), 1 << 16);
+    }
+
     #[test]
     fn test_encoding_decoding() {
         let data = b"hello world";
@@ -845,6 +1038,51 @@ mod tests {
         assert_eq!(utf8, "hello world");
     }
 
+    #[test]
+    fn test_malformed_input_is_an_error() {
+        // A multi-byte character used to split a byte pair off its char boundary
+        for input in ["aé1", "éé", "0€", "+f", "0xff", "f", "zz"] {
+            assert!(BinaryValue::from_hex(input).is_err(), "{input:?}");
+        }
+        let error = BinaryValue::from_hex("aé1").unwrap_err();
+        assert!(error.to_string().contains("Invalid hex character: 'é'"));
+        let separated = BinaryValue::from_hex("de:ad-be ef").unwrap();
+        assert_eq!(separated.as_bytes(), [0xde, 0xad, 0xbe, 0xef]);
+
+        // Truncated and otherwise corrupt base64
+        for input in ["aGVsbG8gd29ybGQ", "aGVsbG8gd29ybG", "a", "aGVs=bG8", "aGVsbG8é", "===="] {
+            assert!(BinaryValue::from_base64(input).is_err(), "{input:?}");
+            assert!(BinaryValue::from_base64_url(input).is_err(), "{input:?}");
+        }
+        for input in ["é", "aé1", "+f", "aGVsbG8gd29ybGQ", "\u{0}"] {
+            assert!(input.parse::<BinaryValue>().is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_text_decoding() {
+        // "Hé" in UTF-16LE with a byte order mark
+        let utf16_le = BinaryValue::new(vec![0xFF, 0xFE, b'H', 0x00, 0xE9, 0x00]);
+        assert_eq!(utf16_le.to_utf16_string(true).unwrap(), "Hé");
+        assert_eq!(utf16_le.to_utf16_string(false).unwrap(), "Hé");
+        assert_eq!(utf16_le.decode_text().unwrap(), "Hé");
+
+        let utf16_be = BinaryValue::new(vec![0x00, b'H', 0x00, 0xE9]);
+        assert_eq!(utf16_be.to_utf16_string(false).unwrap(), "Hé");
+
+        let odd = BinaryValue::new(vec![0xFF, 0xFE, b'H', 0x00, 0xE9]);
+        assert!(odd.to_utf16_string(true).is_err());
+        assert!(odd.decode_text().is_err());
+
+        let latin1 = BinaryValue::new(vec![b'c', b'a', b'f', 0xE9, b' ', 0xA9, 0xFF]);
+        assert_eq!(latin1.to_latin1_string(), "café ©ÿ");
+        assert!(latin1.to_utf8().is_err());
+
+        let utf8_bom = BinaryValue::new("\u{FEFF}ok".as_bytes().to_vec());
+        assert_eq!(utf8_bom.decode_text().unwrap(), "ok");
+        assert_eq!(BinaryValue::new(b"plain".to_vec()).decode_text().unwrap(), "plain");
+    }
+
     #[test]
     fn test_bitwise_operations() {
         let a = BinaryValue::from(vec![0b11110000, 0b10101010]);
@@ -936,6 +1174,54 @@ mod tests {
         assert_eq!(data.checksum_xor(), 4); // 1^2^3^4
     }
 
+    #[cfg(feature = "hash")]
+    #[test]
+    fn test_digests() {
+        let data = BinaryValue::from("abc");
+        assert_eq!(data.digest(HashAlgorithm::Crc32), vec![0x35, 0x24, 0x41, 0xC2]);
+        assert_eq!(
+            BinaryValue::new(data.digest(HashAlgorithm::Sha256)).to_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            BinaryValue::empty().digest(HashAlgorithm::Sha256),
+            BinaryValue::from_hex(
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+            )
+            .unwrap()
+            .into_bytes()
+        );
+
+        // Standard check values
+        assert_eq!(
+            BinaryValue::from("123456789").digest(HashAlgorithm::Crc32),
+            0xCBF4_3926u32.to_be_bytes()
+        );
+        assert_eq!(
+            BinaryValue::from("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+                .digest(HashAlgorithm::Sha256),
+            BinaryValue::from_hex(
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+            )
+            .unwrap()
+            .into_bytes()
+        );
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn test_streaming_hasher() {
+        let data: BinaryValue = (0..1000u32).map(|i| (i % 251) as u8).collect();
+
+        for algorithm in [HashAlgorithm::Crc32, HashAlgorithm::Sha256] {
+            let mut hasher = BinaryHasher::new(algorithm);
+            for chunk in data.chunks(37).unwrap() {
+                hasher.update(&chunk);
+            }
+            assert_eq!(hasher.finalize(), data.digest(algorithm));
+        }
+    }
+
     #[cfg(feature = "json")]
     #[test]
     fn test_json_conversion() {
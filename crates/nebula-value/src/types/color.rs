@@ -17,6 +17,190 @@ pub struct ColorValue {
     pub a: u8,
 }
 
+/// Type of dichromatic color vision deficiency to simulate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColorBlindness {
+    /// Missing long-wavelength (red) cones
+    Protanopia,
+    /// Missing medium-wavelength (green) cones
+    Deuteranopia,
+    /// Missing short-wavelength (blue) cones
+    Tritanopia,
+}
+
+/// Linear RGB to LMS cone response (Viénot, Brettel & Mollon, 1999)
+const RGB_TO_LMS: [[f32; 3]; 3] =
+    [[17.8824, 43.5161, 4.11935], [3.45565, 27.1554, 3.86714], [0.0299566, 0.184309, 1.46709]];
+
+/// Inverse of [`RGB_TO_LMS`]
+const LMS_TO_RGB: [[f32; 3]; 3] = [
+    [0.080_944_45, -0.130_504_41, 0.116_721_07],
+    [-0.010_248_534, 0.054_019_33, -0.113_614_71],
+    [-0.000_365_296_94, -0.004_121_615, 0.693_511_4],
+];
+
+/// CIE76 color difference above which two colors count as distinguishable
+///
+/// A difference of about 2.3 is a just noticeable difference; this leaves
+/// headroom so that colors are told apart at a glance, not side by side.
+const DISTINGUISHABLE_DELTA_E: f32 = 10.0;
+
+/// CSS Color Module Level 4 named colors, sorted by name
+///
+/// Aliases (`aqua`/`cyan`, `fuchsia`/`magenta`, `gray`/`grey`, ...) share an
+/// RGB value; reverse lookups return the alphabetically first one.
+const NAMED_COLORS: &[(&str, u8, u8, u8)] = &[
+    ("aliceblue", 240, 248, 255),
+    ("antiquewhite", 250, 235, 215),
+    ("aqua", 0, 255, 255),
+    ("aquamarine", 127, 255, 212),
+    ("azure", 240, 255, 255),
+    ("beige", 245, 245, 220),
+    ("bisque", 255, 228, 196),
+    ("black", 0, 0, 0),
+    ("blanchedalmond", 255, 235, 205),
+    ("blue", 0, 0, 255),
+    ("blueviolet", 138, 43, 226),
+    ("brown", 165, 42, 42),
+    ("burlywood", 222, 184, 135),
+    ("cadetblue", 95, 158, 160),
+    ("chartreuse", 127, 255, 0),
+    ("chocolate", 210, 105, 30),
+    ("coral", 255, 127, 80),
+    ("cornflowerblue", 100, 149, 237),
+    ("cornsilk", 255, 248, 220),
+    ("crimson", 220, 20, 60),
+    ("cyan", 0, 255, 255),
+    ("darkblue", 0, 0, 139),
+    ("darkcyan", 0, 139, 139),
+    ("darkgoldenrod", 184, 134, 11),
+    ("darkgray", 169, 169, 169),
+    ("darkgreen", 0, 100, 0),
+    ("darkgrey", 169, 169, 169),
+    ("darkkhaki", 189, 183, 107),
+    ("darkmagenta", 139, 0, 139),
+    ("darkolivegreen", 85, 107, 47),
+    ("darkorange", 255, 140, 0),
+    ("darkorchid", 153, 50, 204),
+    ("darkred", 139, 0, 0),
+    ("darksalmon", 233, 150, 122),
+    ("darkseagreen", 143, 188, 143),
+    ("darkslateblue", 72, 61, 139),
+    ("darkslategray", 47, 79, 79),
+    ("darkslategrey", 47, 79, 79),
+    ("darkturquoise", 0, 206, 209),
+    ("darkviolet", 148, 0, 211),
+    ("deeppink", 255, 20, 147),
+    ("deepskyblue", 0, 191, 255),
+    ("dimgray", 105, 105, 105),
+    ("dimgrey", 105, 105, 105),
+    ("dodgerblue", 30, 144, 255),
+    ("firebrick", 178, 34, 34),
+    ("floralwhite", 255, 250, 240),
+    ("forestgreen", 34, 139, 34),
+    ("fuchsia", 255, 0, 255),
+    ("gainsboro", 220, 220, 220),
+    ("ghostwhite", 248, 248, 255),
+    ("gold", 255, 215, 0),
+    ("goldenrod", 218, 165, 32),
+    ("gray", 128, 128, 128),
+    ("green", 0, 128, 0),
+    ("greenyellow", 173, 255, 47),
+    ("grey", 128, 128, 128),
+    ("honeydew", 240, 255, 240),
+    ("hotpink", 255, 105, 180),
+    ("indianred", 205, 92, 92),
+    ("indigo", 75, 0, 130),
+    ("ivory", 255, 255, 240),
+    ("khaki", 240, 230, 140),
+    ("lavender", 230, 230, 250),
+    ("lavenderblush", 255, 240, 245),
+    ("lawngreen", 124, 252, 0),
+    ("lemonchiffon", 255, 250, 205),
+    ("lightblue", 173, 216, 230),
+    ("lightcoral", 240, 128, 128),
+    ("lightcyan", 224, 255, 255),
+    ("lightgoldenrodyellow", 250, 250, 210),
+    ("lightgray", 211, 211, 211),
+    ("lightgreen", 144, 238, 144),
+    ("lightgrey", 211, 211, 211),
+    ("lightpink", 255, 182, 193),
+    ("lightsalmon", 255, 160, 122),
+    ("lightseagreen", 32, 178, 170),
+    ("lightskyblue", 135, 206, 250),
+    ("lightslategray", 119, 136, 153),
+    ("lightslategrey", 119, 136, 153),
+    ("lightsteelblue", 176, 196, 222),
+    ("lightyellow", 255, 255, 224),
+    ("lime", 0, 255, 0),
+    ("limegreen", 50, 205, 50),
+    ("linen", 250, 240, 230),
+    ("magenta", 255, 0, 255),
+    ("maroon", 128, 0, 0),
+    ("mediumaquamarine", 102, 205, 170),
+    ("mediumblue", 0, 0, 205),
+    ("mediumorchid", 186, 85, 211),
+    ("mediumpurple", 147, 112, 219),
+    ("mediumseagreen", 60, 179, 113),
+    ("mediumslateblue", 123, 104, 238),
+    ("mediumspringgreen", 0, 250, 154),
+    ("mediumturquoise", 72, 209, 204),
+    ("mediumvioletred", 199, 21, 133),
+    ("midnightblue", 25, 25, 112),
+    ("mintcream", 245, 255, 250),
+    ("mistyrose", 255, 228, 225),
+    ("moccasin", 255, 228, 181),
+    ("navajowhite", 255, 222, 173),
+    ("navy", 0, 0, 128),
+    ("oldlace", 253, 245, 230),
+    ("olive", 128, 128, 0),
+    ("olivedrab", 107, 142, 35),
+    ("orange", 255, 165, 0),
+    ("orangered", 255, 69, 0),
+    ("orchid", 218, 112, 214),
+    ("palegoldenrod", 238, 232, 170),
+    ("palegreen", 152, 251, 152),
+    ("paleturquoise", 175, 238, 238),
+    ("palevioletred", 219, 112, 147),
+    ("papayawhip", 255, 239, 213),
+    ("peachpuff", 255, 218, 185),
+    ("peru", 205, 133, 63),
+    ("pink", 255, 192, 203),
+    ("plum", 221, 160, 221),
+    ("powderblue", 176, 224, 230),
+    ("purple", 128, 0, 128),
+    ("rebeccapurple", 102, 51, 153),
+    ("red", 255, 0, 0),
+    ("rosybrown", 188, 143, 143),
+    ("royalblue", 65, 105, 225),
+    ("saddlebrown", 139, 69, 19),
+    ("salmon", 250, 128, 114),
+    ("sandybrown", 244, 164, 96),
+    ("seagreen", 46, 139, 87),
+    ("seashell", 255, 245, 238),
+    ("sienna", 160, 82, 45),
+    ("silver", 192, 192, 192),
+    ("skyblue", 135, 206, 235),
+    ("slateblue", 106, 90, 205),
+    ("slategray", 112, 128, 144),
+    ("slategrey", 112, 128, 144),
+    ("snow", 255, 250, 250),
+    ("springgreen", 0, 255, 127),
+    ("steelblue", 70, 130, 180),
+    ("tan", 210, 180, 140),
+    ("teal", 0, 128, 128),
+    ("thistle", 216, 191, 216),
+    ("tomato", 255, 99, 71),
+    ("turquoise", 64, 224, 208),
+    ("violet", 238, 130, 238),
+    ("wheat", 245, 222, 179),
+    ("white", 255, 255, 255),
+    ("whitesmoke", 245, 245, 245),
+    ("yellow", 255, 255, 0),
+    ("yellowgreen", 154, 205, 50),
+];
+
 // Custom serialization implementation
 #[cfg(feature = "serde")]
 impl Serialize for ColorValue {
@@ -128,6 +312,12 @@ impl ColorValue {
         // Remove # prefix if present
         let hex = hex.strip_prefix('#').unwrap_or(hex);
 
+        // Only ASCII digits make the byte slicing below safe; this also keeps
+        // `from_str_radix` from accepting a sign
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ValueError::custom("Invalid hex color format"));
+        }
+
         match hex.len() {
             3 => {
                 // #RGB -> #RRGGBB
@@ -199,35 +389,61 @@ impl ColorValue {
     // === Named Colors ===
 
     /// Get color from standard color name
+    ///
+    /// Accepts the CSS4 named colors and `transparent`, case-insensitively.
     #[must_use]
     pub fn from_name(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "transparent" => Some(Self::rgba(0, 0, 0, 0)),
-            "black" => Some(Self::rgb(0, 0, 0)),
-            "white" => Some(Self::rgb(255, 255, 255)),
-            "red" => Some(Self::rgb(255, 0, 0)),
-            "green" => Some(Self::rgb(0, 128, 0)),
-            "blue" => Some(Self::rgb(0, 0, 255)),
-            "yellow" => Some(Self::rgb(255, 255, 0)),
-            "cyan" => Some(Self::rgb(0, 255, 255)),
-            "magenta" => Some(Self::rgb(255, 0, 255)),
-            "orange" => Some(Self::rgb(255, 165, 0)),
-            "purple" => Some(Self::rgb(128, 0, 128)),
-            "pink" => Some(Self::rgb(255, 192, 203)),
-            "brown" => Some(Self::rgb(165, 42, 42)),
-            "gray" | "grey" => Some(Self::rgb(128, 128, 128)),
-            "lightgray" | "lightgrey" => Some(Self::rgb(211, 211, 211)),
-            "darkgray" | "darkgrey" => Some(Self::rgb(169, 169, 169)),
-            "lime" => Some(Self::rgb(0, 255, 0)),
-            "navy" => Some(Self::rgb(0, 0, 128)),
-            "maroon" => Some(Self::rgb(128, 0, 0)),
-            "olive" => Some(Self::rgb(128, 128, 0)),
-            "silver" => Some(Self::rgb(192, 192, 192)),
-            "teal" => Some(Self::rgb(0, 128, 128)),
-            "aqua" => Some(Self::rgb(0, 255, 255)),
-            "fuchsia" => Some(Self::rgb(255, 0, 255)),
-            _ => None,
+        let name = name.to_lowercase();
+        if name == "transparent" {
+            return Some(Self::transparent());
         }
+
+        NAMED_COLORS
+            .binary_search_by(|(candidate, ..)| (*candidate).cmp(name.as_str()))
+            .ok()
+            .map(|index| {
+                let (_, r, g, b) = NAMED_COLORS[index];
+                Self::rgb(r, g, b)
+            })
+    }
+
+    /// Get the CSS name of this exact color, if it has one
+    ///
+    /// Fully transparent colors are named `transparent`; otherwise the color
+    /// must be opaque and match a named color exactly.
+    #[must_use]
+    pub fn exact_name(&self) -> Option<&'static str> {
+        if *self == Self::transparent() {
+            return Some("transparent");
+        }
+        if self.a != 255 {
+            return None;
+        }
+
+        NAMED_COLORS
+            .iter()
+            .find(|&&(_, r, g, b)| (r, g, b) == (self.r, self.g, self.b))
+            .map(|&(name, ..)| name)
+    }
+
+    /// Get the CSS named color closest to this one
+    ///
+    /// Distance is Euclidean in RGB space and alpha is ignored, except that
+    /// fully transparent black is named `transparent`.
+    #[must_use]
+    pub fn nearest_name(&self) -> &'static str {
+        if *self == Self::transparent() {
+            return "transparent";
+        }
+
+        let distance = |&&(_, r, g, b): &&(&str, u8, u8, u8)| {
+            let dr = i32::from(self.r) - i32::from(r);
+            let dg = i32::from(self.g) - i32::from(g);
+            let db = i32::from(self.b) - i32::from(b);
+            dr * dr + dg * dg + db * db
+        };
+
+        NAMED_COLORS.iter().min_by_key(distance).map_or("black", |&(name, ..)| name)
     }
 
     // === Common Color Constants ===
@@ -392,6 +608,46 @@ impl ColorValue {
         }
     }
 
+    /// Format as a CSS `rgb(r, g, b)` function, ignoring alpha
+    #[must_use]
+    pub fn to_css_rgb(&self) -> String {
+        format!("rgb({}, {}, {})", self.r, self.g, self.b)
+    }
+
+    /// Format as a CSS `rgba(r, g, b, a)` function
+    #[must_use]
+    pub fn to_css_rgba(&self) -> String {
+        format!("rgba({}, {}, {}, {})", self.r, self.g, self.b, self.css_alpha())
+    }
+
+    /// Format as a CSS `hsl(h, s%, l%)` function, ignoring alpha
+    ///
+    /// Components are rounded to whole numbers.
+    #[must_use]
+    pub fn to_css_hsl(&self) -> String {
+        let (h, s, l) = self.css_hsl_components();
+        format!("hsl({h}, {s}%, {l}%)")
+    }
+
+    /// Format as a CSS `hsla(h, s%, l%, a)` function
+    #[must_use]
+    pub fn to_css_hsla(&self) -> String {
+        let (h, s, l) = self.css_hsl_components();
+        format!("hsla({h}, {s}%, {l}%, {})", self.css_alpha())
+    }
+
+    /// HSL components rounded for CSS output, with the hue kept below 360
+    fn css_hsl_components(&self) -> (u16, u8, u8) {
+        let (h, s, l) = self.to_hsl();
+        ((h.round() as u16) % 360, s.round() as u8, l.round() as u8)
+    }
+
+    /// Alpha (0-1) with at most 3 decimals and no trailing zeros
+    fn css_alpha(&self) -> String {
+        let alpha = format!("{:.3}", self.a as f32 / 255.0);
+        alpha.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+
     // === Color Manipulation ===
 
     /// Create a new color with modified alpha
@@ -447,6 +703,34 @@ impl ColorValue {
         Self::rgba(gray, gray, gray, self.a)
     }
 
+    /// Simulate how the color appears to a dichromat
+    ///
+    /// The color is linearized, projected in LMS cone space onto the plane
+    /// a dichromat can perceive, and converted back to sRGB (Viénot, Brettel
+    /// & Mollon, 1999). Each plane passes through white and a primary the
+    /// dichromat still sees correctly: blue for protanopia and deuteranopia,
+    /// red for tritanopia. Alpha is kept unchanged.
+    #[must_use]
+    pub fn simulate_color_blindness(&self, kind: ColorBlindness) -> Self {
+        let (r, g, b) = self.to_rgb_floats();
+        let linear = [Self::srgb_to_linear(r), Self::srgb_to_linear(g), Self::srgb_to_linear(b)];
+        let [l, m, s] = Self::mat3_mul(&RGB_TO_LMS, linear);
+
+        let lms = match kind {
+            ColorBlindness::Protanopia => [2.02344 * m - 2.52581 * s, m, s],
+            ColorBlindness::Deuteranopia => [l, 0.494207 * l + 1.24827 * s, s],
+            ColorBlindness::Tritanopia => [l, m, -0.012245 * l + 0.0720345 * m],
+        };
+
+        let [r, g, b] = Self::mat3_mul(&LMS_TO_RGB, lms);
+        let (r, g, b) = Self::rgb_floats_to_u8(
+            Self::linear_to_srgb(r.clamp(0.0, 1.0)),
+            Self::linear_to_srgb(g.clamp(0.0, 1.0)),
+            Self::linear_to_srgb(b.clamp(0.0, 1.0)),
+        );
+        Self::rgba(r, g, b, self.a)
+    }
+
     /// Invert the color (keeping alpha)
     #[must_use]
     pub const fn invert(&self) -> Self {
@@ -454,6 +738,9 @@ impl ColorValue {
     }
 
     /// Mix with another color
+    ///
+    /// This is a plain per-channel interpolation, alpha included; use
+    /// [`ColorValue::over`] to layer a translucent color on another.
     #[must_use]
     pub fn mix(&self, other: &Self, ratio: f32) -> Self {
         let ratio = ratio.clamp(0.0, 1.0);
@@ -467,6 +754,33 @@ impl ColorValue {
         )
     }
 
+    /// Composite this color over `background` (Porter-Duff source-over)
+    ///
+    /// Channels are premultiplied by alpha before blending and divided back
+    /// out afterwards. A fully transparent result is returned as
+    /// [`ColorValue::transparent`].
+    #[must_use]
+    pub fn over(&self, background: &Self) -> Self {
+        let src_a = self.a as f32 / 255.0;
+        let dst_a = background.a as f32 / 255.0;
+        let out_a = src_a + dst_a * (1.0 - src_a);
+        if out_a == 0.0 {
+            return Self::transparent();
+        }
+
+        let blend = |src: u8, dst: u8| {
+            let premultiplied = src as f32 * src_a + dst as f32 * dst_a * (1.0 - src_a);
+            (premultiplied / out_a).round().clamp(0.0, 255.0) as u8
+        };
+
+        Self::rgba(
+            blend(self.r, background.r),
+            blend(self.g, background.g),
+            blend(self.b, background.b),
+            (out_a * 255.0).round() as u8,
+        )
+    }
+
     // === Color Harmony ===
 
     /// Get the complementary color (opposite on color wheel)
@@ -515,6 +829,28 @@ impl ColorValue {
         (lighter + 0.05) / (darker + 0.05)
     }
 
+    /// Perceptual distance to another color (CIE76 ΔE in CIELAB, D65)
+    ///
+    /// Alpha is ignored.
+    #[must_use]
+    pub fn delta_e(&self, other: &Self) -> f32 {
+        let (l1, a1, b1) = self.to_lab();
+        let (l2, a2, b2) = other.to_lab();
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// Check whether the two colors remain distinguishable to a dichromat
+    ///
+    /// Both colors are passed through
+    /// [`ColorValue::simulate_color_blindness`] and compared with
+    /// [`ColorValue::delta_e`] against a threshold of 10.
+    #[must_use]
+    pub fn is_distinguishable_from(&self, other: &Self, kind: ColorBlindness) -> bool {
+        let a = self.simulate_color_blindness(kind);
+        let b = other.simulate_color_blindness(kind);
+        a.delta_e(&b) >= DISTINGUISHABLE_DELTA_E
+    }
+
     /// Check if color is web-safe (216 web-safe colors)
     #[must_use]
     pub fn is_web_safe(&self) -> bool {
@@ -603,6 +939,41 @@ impl ColorValue {
         (h, s * 100.0, v * 100.0)
     }
 
+    /// Decode an sRGB channel (0.0-1.0) to linear light
+    fn srgb_to_linear(c: f32) -> f32 {
+        if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    }
+
+    /// Encode a linear-light channel (0.0-1.0) as sRGB
+    fn linear_to_srgb(c: f32) -> f32 {
+        if c <= 0.003_130_8 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 }
+    }
+
+    fn mat3_mul(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+        [
+            m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+            m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+            m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+        ]
+    }
+
+    /// Convert to CIELAB (L: 0-100) under the D65 white point
+    fn to_lab(self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_rgb_floats();
+        let (r, g, b) = (Self::srgb_to_linear(r), Self::srgb_to_linear(g), Self::srgb_to_linear(b));
+
+        let x = (0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b) / 0.950_47;
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        let z = (0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b) / 1.088_83;
+
+        let f = |t: f32| {
+            if t > 216.0 / 24389.0 { t.cbrt() } else { (24389.0 / 27.0 * t + 16.0) / 116.0 }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+
+        (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+
     /// Calculate a hue component (shared between HSL and HSV conversion)
     fn calculate_hue(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
         if max == r {
@@ -752,11 +1123,39 @@ mod tests {
         assert_eq!(color.to_hex_lowercase(), "#ff8040");
     }
 
+    #[test]
+    fn test_malformed_hex_is_an_error() {
+        // Multi-byte characters used to be sliced off their char boundary
+        let inputs = ["#éa", "#aé", "#ééé", "#€", "#ff€00", "#🎨🎨", "#+f+f+f", "#-1", "#", ""];
+        for input in inputs {
+            assert!(ColorValue::from_hex(input).is_err(), "{input:?}");
+            assert!(ColorValue::parse(input).is_err(), "{input:?}");
+        }
+        assert!(ColorValue::parse("ré").is_err());
+        assert!("#ggg".parse::<ColorValue>().is_err());
+    }
+
     #[test]
     fn test_named_colors() {
         assert_eq!(ColorValue::from_name("red"), Some(ColorValue::red()));
         assert_eq!(ColorValue::from_name("transparent"), Some(ColorValue::transparent()));
         assert_eq!(ColorValue::from_name("nonexistent"), None);
+        assert_eq!(ColorValue::from_name("RebeccaPurple"), Some(ColorValue::rgb(102, 51, 153)));
+    }
+
+    #[test]
+    fn test_color_names() {
+        let red = ColorValue::from_hex("#FF0000").unwrap();
+        assert_eq!(red.exact_name(), Some("red"));
+        assert_eq!(red.nearest_name(), "red");
+
+        let near_red = ColorValue::from_hex("#FE0101").unwrap();
+        assert_eq!(near_red.exact_name(), None);
+        assert_eq!(near_red.nearest_name(), "red");
+
+        assert_eq!(ColorValue::rgb(0, 255, 255).exact_name(), Some("aqua"));
+        assert_eq!(ColorValue::rgba(255, 0, 0, 128).exact_name(), None);
+        assert_eq!(ColorValue::transparent().nearest_name(), "transparent");
     }
 
     #[test]
@@ -777,6 +1176,23 @@ mod tests {
         assert_eq!(inverted, ColorValue::white());
     }
 
+    #[test]
+    fn test_over() {
+        let translucent_red = ColorValue::rgba(255, 0, 0, 128);
+        assert_eq!(translucent_red.over(&ColorValue::white()), ColorValue::rgb(255, 127, 127));
+
+        let background = ColorValue::rgba(10, 20, 30, 200);
+        assert_eq!(ColorValue::rgba(255, 255, 255, 0).over(&background), background);
+
+        // Both translucent: alpha accumulates, color weights by coverage
+        let layered = ColorValue::rgba(0, 0, 255, 128).over(&ColorValue::rgba(255, 0, 0, 128));
+        assert_eq!(layered.a, 192);
+        assert!(layered.b > layered.r);
+
+        let empty = ColorValue::transparent();
+        assert_eq!(empty.over(&empty), empty);
+    }
+
     #[test]
     fn test_color_harmony() {
         let red = ColorValue::red();
@@ -817,4 +1233,49 @@ mod tests {
         let opaque_color = ColorValue::rgb(255, 128, 64);
         assert_eq!(format!("{:#}", opaque_color), "rgb(255, 128, 64)");
     }
+
+    #[test]
+    fn test_css_formatting() {
+        let color = ColorValue::rgba(64, 191, 64, 128);
+        assert_eq!(color.to_css_rgb(), "rgb(64, 191, 64)");
+        assert_eq!(color.to_css_rgba(), "rgba(64, 191, 64, 0.502)");
+        assert_eq!(color.to_css_hsl(), "hsl(120, 50%, 50%)");
+        assert_eq!(color.to_css_hsla(), "hsla(120, 50%, 50%, 0.502)");
+
+        let opaque = ColorValue::rgb(255, 0, 0);
+        assert_eq!(opaque.to_css_rgba(), "rgba(255, 0, 0, 1)");
+        assert_eq!(opaque.to_css_hsla(), "hsla(0, 100%, 50%, 1)");
+        assert_eq!(ColorValue::rgba(0, 0, 0, 51).to_css_rgba(), "rgba(0, 0, 0, 0.2)");
+        assert_eq!(ColorValue::transparent().to_css_hsla(), "hsla(0, 0%, 0%, 0)");
+    }
+
+    #[test]
+    fn test_color_blindness() {
+        // Neutral colors lie on every projection plane
+        for kind in
+            [ColorBlindness::Protanopia, ColorBlindness::Deuteranopia, ColorBlindness::Tritanopia]
+        {
+            for gray in [ColorValue::white(), ColorValue::black(), ColorValue::rgb(128, 128, 128)] {
+                assert_eq!(gray.simulate_color_blindness(kind), gray);
+            }
+        }
+
+        // Red and green collapse to yellows for red-green deficiencies
+        let red = ColorValue::rgb(255, 0, 0);
+        assert_eq!(red.simulate_color_blindness(ColorBlindness::Deuteranopia).to_hex(), "#939300");
+        assert_eq!(red.simulate_color_blindness(ColorBlindness::Tritanopia), red);
+        let faded = ColorValue::rgba(255, 0, 0, 128);
+        assert_eq!(faded.simulate_color_blindness(ColorBlindness::Protanopia).a, 128);
+
+        let tab_red = ColorValue::rgb(214, 39, 40);
+        let tab_green = ColorValue::rgb(44, 160, 44);
+        assert!(tab_red.delta_e(&tab_green) > 100.0);
+        assert!(!tab_red.is_distinguishable_from(&tab_green, ColorBlindness::Deuteranopia));
+        assert!(tab_red.is_distinguishable_from(&tab_green, ColorBlindness::Protanopia));
+        assert!(tab_red.is_distinguishable_from(&tab_green, ColorBlindness::Tritanopia));
+
+        let blue = ColorValue::rgb(0, 0, 255);
+        assert!(blue.is_distinguishable_from(&red, ColorBlindness::Deuteranopia));
+        assert!(tab_red.delta_e(&tab_red).abs() < f32::EPSILON);
+    }
 }
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use chrono::{DateTime, Datelike, Duration as ChronoDuration, NaiveDate, NaiveTime, Timelike, Utc};
+use chrono::{
+    DateTime, Datelike, Duration as ChronoDuration, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Timelike, Utc, Weekday,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +23,127 @@ pub enum DateTimeValue {
     Time(NaiveTime),
 }
 
+/// Formats tried by [`DateTimeValue::parse_smart`] after RFC 3339 and
+/// RFC 2822, most specific first
+///
+/// Slash dates are read month first (`MM/DD/YYYY`); dotted dates day first.
+const COMMON_DATETIME_FORMATS: [&str; 15] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f %z",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%m/%d/%Y %H:%M:%S",
+    "%m/%d/%Y",
+    "%d.%m.%Y",
+    "%B %d, %Y",
+    "%b %d, %Y",
+    "%d %B %Y",
+    "%d %b %Y",
+    "%H:%M:%S%.f",
+    "%H:%M",
+];
+
+/// Calendar unit used to truncate or round a [`DateTimeValue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TimeUnit {
+    /// Whole seconds, dropping fractions
+    Second,
+    /// Start of the minute
+    Minute,
+    /// Start of the hour
+    Hour,
+    /// Midnight
+    Day,
+    /// Midnight on the first of the month
+    Month,
+    /// Midnight on January 1st
+    Year,
+}
+
+/// All calendar and clock components of a [`DateTimeValue`], as returned
+/// by [`DateTimeValue::components`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DateTimeComponents {
+    /// Calendar year
+    pub year: i32,
+    /// Month (1-12)
+    pub month: u32,
+    /// Day of month (1-31)
+    pub day: u32,
+    /// Hour (0-23)
+    pub hour: u32,
+    /// Minute (0-59)
+    pub minute: u32,
+    /// Second (0-59)
+    pub second: u32,
+    /// Day of the week
+    pub weekday: Weekday,
+    /// Day of year (1-366)
+    pub day_of_year: u32,
+    /// ISO 8601 week-numbering year, which differs from `year` around
+    /// January 1st
+    pub iso_year: i32,
+    /// ISO 8601 week (1-53)
+    pub iso_week: u32,
+}
+
+impl TimeUnit {
+    /// Zeroes every component finer than this unit
+    fn truncate(self, dt: NaiveDateTime) -> NaiveDateTime {
+        let date = dt.date();
+        let time = dt.time();
+        let (date, time) = match self {
+            Self::Second => (date, time.with_nanosecond(0).unwrap_or(time)),
+            Self::Minute => {
+                (date, NaiveTime::from_hms_opt(time.hour(), time.minute(), 0).unwrap_or(time))
+            },
+            Self::Hour => (date, NaiveTime::from_hms_opt(time.hour(), 0, 0).unwrap_or(time)),
+            Self::Day => (date, NaiveTime::MIN),
+            Self::Month => (date.with_day(1).unwrap_or(date), NaiveTime::MIN),
+            Self::Year => (date.with_ordinal(1).unwrap_or(date), NaiveTime::MIN),
+        };
+        date.and_time(time)
+    }
+
+    /// Nominal length of the unit in seconds, with months and years
+    /// approximated as 30 and 365 days
+    pub(crate) const fn nominal_seconds(self) -> f64 {
+        match self {
+            Self::Second => 1.0,
+            Self::Minute => 60.0,
+            Self::Hour => 3_600.0,
+            Self::Day => 86_400.0,
+            Self::Month => 30.0 * 86_400.0,
+            Self::Year => 365.0 * 86_400.0,
+        }
+    }
+
+    /// Adds one unit to a boundary produced by [`TimeUnit::truncate`]
+    fn next_boundary(self, boundary: NaiveDateTime) -> Option<NaiveDateTime> {
+        match self {
+            Self::Second => boundary.checked_add_signed(ChronoDuration::seconds(1)),
+            Self::Minute => boundary.checked_add_signed(ChronoDuration::minutes(1)),
+            Self::Hour => boundary.checked_add_signed(ChronoDuration::hours(1)),
+            Self::Day => boundary.checked_add_signed(ChronoDuration::days(1)),
+            Self::Month => boundary.checked_add_months(Months::new(1)),
+            Self::Year => boundary.checked_add_months(Months::new(12)),
+        }
+    }
+
+    /// Rounds to the nearest boundary, halfway points rounding up
+    fn round(self, dt: NaiveDateTime) -> NaiveDateTime {
+        let floor = self.truncate(dt);
+        match self.next_boundary(floor) {
+            Some(ceil) if dt - floor >= ceil - dt => ceil,
+            _ => floor,
+        }
+    }
+}
+
 impl DateTimeValue {
     // === Construction ===
 
@@ -151,6 +275,78 @@ impl DateTimeValue {
         )))
     }
 
+    /// Parses `input` with each strftime format in turn, returning the first
+    /// success
+    ///
+    /// Formats with a time zone (`%z`) produce UTC datetimes; formats with
+    /// only date or only time fields produce [`DateTimeValue::Date`] or
+    /// [`DateTimeValue::Time`]. The error lists every format tried.
+    pub fn parse_any(input: &str, formats: &[&str]) -> ValueResult<Self> {
+        formats.iter().find_map(|format| Self::parse_format(input, format)).ok_or_else(|| {
+            ValueError::custom(format!(
+                "Unable to parse datetime from '{input}'; tried formats: {}",
+                formats.join(", ")
+            ))
+        })
+    }
+
+    /// Parses a date, time or datetime in any common format
+    ///
+    /// Tries RFC 3339 (ISO 8601), RFC 2822, a built-in list of common
+    /// formats such as `YYYY-MM-DD` and `MM/DD/YYYY`, and finally reads a
+    /// bare integer as a Unix timestamp, in seconds, milliseconds,
+    /// microseconds or nanoseconds depending on its magnitude.
+    pub fn parse_smart(input: &str) -> ValueResult<Self> {
+        let input = input.trim();
+        if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+            return Ok(Self::DateTime(dt.with_timezone(&Utc)));
+        }
+        if let Ok(dt) = DateTime::parse_from_rfc2822(input) {
+            return Ok(Self::DateTime(dt.with_timezone(&Utc)));
+        }
+        if let Some(value) =
+            COMMON_DATETIME_FORMATS.iter().find_map(|format| Self::parse_format(input, format))
+        {
+            return Ok(value);
+        }
+        if let Some(value) = input.parse::<i64>().ok().and_then(Self::from_unix_guess) {
+            return Ok(value);
+        }
+
+        Err(ValueError::custom(format!(
+            "Unable to parse datetime from '{input}'; tried RFC 3339, RFC 2822, {} and Unix \
+             timestamps",
+            COMMON_DATETIME_FORMATS.join(", ")
+        )))
+    }
+
+    /// Parses with a single strftime format, picking the variant from the
+    /// fields the format provides
+    fn parse_format(input: &str, format: &str) -> Option<Self> {
+        if let Ok(dt) = DateTime::parse_from_str(input, format) {
+            return Some(Self::DateTime(dt.with_timezone(&Utc)));
+        }
+        if let Ok(dt) = NaiveDateTime::parse_from_str(input, format) {
+            return Some(Self::DateTime(dt.and_utc()));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(input, format) {
+            return Some(Self::Date(date));
+        }
+        NaiveTime::parse_from_str(input, format).ok().map(Self::Time)
+    }
+
+    /// Reads a Unix timestamp, guessing the unit from its magnitude: up to
+    /// 11 digits are seconds, 14 milliseconds, 17 microseconds, otherwise
+    /// nanoseconds
+    fn from_unix_guess(timestamp: i64) -> Option<Self> {
+        match timestamp.unsigned_abs() {
+            0..100_000_000_000 => Self::from_timestamp(timestamp),
+            100_000_000_000..100_000_000_000_000 => Self::from_timestamp_millis(timestamp),
+            100_000_000_000_000..100_000_000_000_000_000 => Self::from_timestamp_micros(timestamp),
+            _ => Some(Self::from_timestamp_nanos(timestamp)),
+        }
+    }
+
     // === Type Checks ===
 
     /// Returns true if this is a datetime value
@@ -256,6 +452,60 @@ impl DateTimeValue {
         }
     }
 
+    /// Gets the day of the week (if available)
+    #[must_use]
+    pub fn weekday(&self) -> Option<Weekday> {
+        self.date_component().map(|date| date.weekday())
+    }
+
+    /// Gets the day of the year (1-366) (if available)
+    #[must_use]
+    pub fn day_of_year(&self) -> Option<u32> {
+        self.date_component().map(|date| date.ordinal())
+    }
+
+    /// Gets the ISO 8601 week-numbering year and week (1-53) (if available)
+    ///
+    /// The year can differ from [`year`](Self::year): 2024-12-30 is in week 1
+    /// of 2025.
+    #[must_use]
+    pub fn iso_week(&self) -> Option<(i32, u32)> {
+        self.date_component().map(|date| (date.iso_week().year(), date.iso_week().week()))
+    }
+
+    /// Gets all components in UTC (if the value has a date)
+    ///
+    /// Date-only values report midnight; time-only values return `None`.
+    #[must_use]
+    pub fn components(&self) -> Option<DateTimeComponents> {
+        self.components_in(&Utc)
+    }
+
+    /// Gets all components as wall-clock time in the given time zone
+    ///
+    /// Date-only values carry no zone and ignore `tz`.
+    #[must_use]
+    pub fn components_in<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTimeComponents> {
+        let local = match self {
+            Self::DateTime(dt) => dt.with_timezone(tz).naive_local(),
+            Self::Date(date) => date.and_time(NaiveTime::MIN),
+            Self::Time(_) => return None,
+        };
+        let iso_week = local.iso_week();
+        Some(DateTimeComponents {
+            year: local.year(),
+            month: local.month(),
+            day: local.day(),
+            hour: local.hour(),
+            minute: local.minute(),
+            second: local.second(),
+            weekday: local.weekday(),
+            day_of_year: local.ordinal(),
+            iso_year: iso_week.year(),
+            iso_week: iso_week.week(),
+        })
+    }
+
     // === Timestamp Methods ===
 
     /// Returns the timestamp in seconds (datetime only)
@@ -375,6 +625,54 @@ impl DateTimeValue {
         }
     }
 
+    /// Format relative to another moment (e.g., "3 minutes ago", "in 2 days")
+    ///
+    /// The difference is collapsed to the largest whole unit. Gaps under a
+    /// minute in either direction are rendered as "just now". Months and
+    /// years are approximated as 30 and 365 days respectively.
+    #[must_use]
+    pub fn relative_to(&self, now: &Self) -> String {
+        let anchor = self.date_component().or_else(|| now.date_component()).unwrap_or_default();
+        let (Some(this), Some(now)) =
+            (self.to_datetime(Some(anchor)), now.to_datetime(Some(anchor)))
+        else {
+            return self.format_compact();
+        };
+
+        let seconds = (this - now).num_seconds();
+        let abs = seconds.unsigned_abs();
+
+        const MINUTE: u64 = 60;
+        const HOUR: u64 = 60 * MINUTE;
+        const DAY: u64 = 24 * HOUR;
+        const WEEK: u64 = 7 * DAY;
+        const MONTH: u64 = 30 * DAY;
+        const YEAR: u64 = 365 * DAY;
+
+        let (count, unit) = match abs {
+            0..MINUTE => return "just now".to_string(),
+            MINUTE..HOUR => (abs / MINUTE, "minute"),
+            HOUR..DAY => (abs / HOUR, "hour"),
+            DAY..WEEK => (abs / DAY, "day"),
+            WEEK..MONTH => (abs / WEEK, "week"),
+            MONTH..YEAR => (abs / MONTH, "month"),
+            _ => (abs / YEAR, "year"),
+        };
+        let plural = if count == 1 { "" } else { "s" };
+
+        if seconds < 0 {
+            format!("{count} {unit}{plural} ago")
+        } else {
+            format!("in {count} {unit}{plural}")
+        }
+    }
+
+    /// Format relative to the current moment
+    #[must_use]
+    pub fn relative_to_now(&self) -> String {
+        self.relative_to(&Self::now())
+    }
+
     // === Date/Time Arithmetic ===
 
     /// Add duration to datetime (only works for datetime values)
@@ -407,6 +705,135 @@ impl DateTimeValue {
         }
     }
 
+    // === Truncation and Rounding ===
+
+    /// Truncates to the start of `unit` in UTC, zeroing finer components
+    ///
+    /// Dates are unaffected by units finer than a day; times are reset to
+    /// midnight by `Day` and coarser units.
+    #[must_use]
+    pub fn truncate_to(&self, unit: TimeUnit) -> Self {
+        // UTC has no gaps, so mapping back from local time cannot fail
+        self.truncate_to_in(unit, &Utc).unwrap_or(*self)
+    }
+
+    /// Rounds to the nearest `unit` boundary in UTC; halfway rounds up
+    ///
+    /// Time-only values wrap around midnight.
+    #[must_use]
+    pub fn round_to(&self, unit: TimeUnit) -> Self {
+        self.round_to_in(unit, &Utc).unwrap_or(*self)
+    }
+
+    /// Truncates to the start of `unit` in the given time zone
+    ///
+    /// Truncating to `Day` in `-05:00` yields local midnight (05:00 UTC)
+    /// rather than UTC midnight. Fails if the resulting local time does not
+    /// exist in `tz`, e.g. a midnight skipped by a DST change. Date- and
+    /// time-only values carry no zone and ignore `tz`.
+    pub fn truncate_to_in<Tz: TimeZone>(&self, unit: TimeUnit, tz: &Tz) -> ValueResult<Self> {
+        self.adjust_local(tz, |dt| unit.truncate(dt))
+    }
+
+    /// Rounds to the nearest `unit` boundary in the given time zone
+    ///
+    /// See [`DateTimeValue::truncate_to_in`] for how the zone is applied.
+    pub fn round_to_in<Tz: TimeZone>(&self, unit: TimeUnit, tz: &Tz) -> ValueResult<Self> {
+        self.adjust_local(tz, |dt| unit.round(dt))
+    }
+
+    /// Applies `adjust` to the value as wall-clock time in `tz`
+    fn adjust_local<Tz: TimeZone>(
+        &self,
+        tz: &Tz,
+        adjust: impl Fn(NaiveDateTime) -> NaiveDateTime,
+    ) -> ValueResult<Self> {
+        match self {
+            Self::DateTime(dt) => {
+                let local = adjust(dt.with_timezone(tz).naive_local());
+                tz.from_local_datetime(&local)
+                    .earliest()
+                    .map(|dt| Self::DateTime(dt.with_timezone(&Utc)))
+                    .ok_or_else(|| ValueError::custom(format!("Local time {local} does not exist")))
+            },
+            Self::Date(date) => Ok(Self::Date(adjust(date.and_time(NaiveTime::MIN)).date())),
+            Self::Time(time) => Ok(Self::Time(adjust(NaiveDate::MIN.and_time(*time)).time())),
+        }
+    }
+
+    // === Business Day Arithmetic ===
+
+    /// Returns true if the date falls on a Saturday or Sunday
+    ///
+    /// Time-only values have no date and are never considered weekend.
+    #[must_use]
+    pub fn is_weekend(&self) -> bool {
+        self.date_component()
+            .is_some_and(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+    }
+
+    /// Returns true if the date falls on a weekday (Monday through Friday)
+    #[must_use]
+    pub fn is_business_day(&self) -> bool {
+        self.date_component().is_some() && !self.is_weekend()
+    }
+
+    /// Returns the next business day strictly after this value
+    ///
+    /// The time component of datetime values is preserved.
+    #[must_use]
+    pub fn next_business_day(&self) -> Self {
+        self.add_business_days(1)
+    }
+
+    /// Advances by `n` business days, skipping Saturdays and Sundays
+    ///
+    /// Negative `n` moves backward. The time component of datetime values is
+    /// preserved; time-only values are returned unchanged.
+    #[must_use]
+    pub fn add_business_days(&self, n: i64) -> Self {
+        self.add_business_days_with_holidays(n, &[])
+    }
+
+    /// Advances by `n` business days, skipping weekends and the given holidays
+    ///
+    /// Holidays are matched by their date component only, so both date and
+    /// datetime values may be supplied.
+    #[must_use]
+    pub fn add_business_days_with_holidays(&self, n: i64, holidays: &[Self]) -> Self {
+        let Some(mut date) = self.date_component() else {
+            return *self;
+        };
+
+        let holidays: Vec<NaiveDate> = holidays.iter().filter_map(Self::date_component).collect();
+        let is_business_day = |d: NaiveDate| {
+            !matches!(d.weekday(), Weekday::Sat | Weekday::Sun) && !holidays.contains(&d)
+        };
+
+        let mut remaining = n.unsigned_abs();
+        while remaining > 0 {
+            let step = if n > 0 { date.succ_opt() } else { date.pred_opt() };
+            let Some(next) = step else {
+                break;
+            };
+            date = next;
+            if is_business_day(date) {
+                remaining -= 1;
+            }
+        }
+
+        self.with_date(date)
+    }
+
+    /// Replaces the date component, keeping the time of datetime values
+    fn with_date(&self, date: NaiveDate) -> Self {
+        match self {
+            Self::DateTime(dt) => Self::DateTime(date.and_time(dt.time()).and_utc()),
+            Self::Date(_) => Self::Date(date),
+            Self::Time(time) => Self::Time(*time),
+        }
+    }
+
     // === Validation Methods ===
 
     /// Check if the datetime is in the past
@@ -587,6 +1014,42 @@ mod tests {
         assert!(time.is_time());
     }
 
+    #[test]
+    fn test_parse_any() {
+        let formats = ["%Y-%m-%d", "%d/%m/%Y", "%Y-%m-%d %H:%M"];
+        let date = DateTimeValue::parse_any("15/01/2024", &formats).unwrap();
+        assert_eq!(date, DateTimeValue::date(2024, 1, 15).unwrap());
+
+        let dt = DateTimeValue::parse_any("2024-01-15 14:30", &formats).unwrap();
+        assert!(dt.is_datetime());
+        assert_eq!(dt.minute(), Some(30));
+
+        let error = DateTimeValue::parse_any("next tuesday", &formats).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("next tuesday"));
+        assert!(formats.iter().all(|format| message.contains(format)));
+    }
+
+    #[test]
+    fn test_parse_smart() {
+        let expected = DateTimeValue::from_timestamp(1_705_329_045).unwrap();
+        assert_eq!(DateTimeValue::parse_smart("2024-01-15T14:30:45Z").unwrap(), expected);
+        let rfc2822 = DateTimeValue::parse_smart("Mon, 15 Jan 2024 14:30:45 +0000").unwrap();
+        assert_eq!(rfc2822, expected);
+        assert_eq!(DateTimeValue::parse_smart("2024-01-15 14:30:45").unwrap(), expected);
+        assert_eq!(DateTimeValue::parse_smart("1705329045").unwrap(), expected);
+        assert_eq!(DateTimeValue::parse_smart("1705329045000").unwrap(), expected);
+
+        let date = DateTimeValue::date(2024, 1, 15).unwrap();
+        assert_eq!(DateTimeValue::parse_smart("2024-01-15").unwrap(), date);
+        assert_eq!(DateTimeValue::parse_smart("01/15/2024").unwrap(), date);
+        assert_eq!(DateTimeValue::parse_smart("January 15, 2024").unwrap(), date);
+        assert!(DateTimeValue::parse_smart("14:30").unwrap().is_time());
+
+        let error = DateTimeValue::parse_smart("soon").unwrap_err().to_string();
+        assert!(error.contains("RFC 2822") && error.contains("%m/%d/%Y"));
+    }
+
     #[test]
     fn test_formatting() {
         let dt = DateTimeValue::from_timestamp(1705329045).unwrap(); // 2024-01-15 14:30:45 UTC
@@ -595,6 +1058,25 @@ mod tests {
         assert!(dt.format_human().contains("January"));
     }
 
+    #[test]
+    fn test_relative_formatting() {
+        let now = DateTimeValue::from_timestamp(1705329045).unwrap();
+
+        let past = DateTimeValue::from_timestamp(1705329045 - 90).unwrap();
+        assert_eq!(past.relative_to(&now), "1 minute ago");
+
+        let future = DateTimeValue::from_timestamp(1705329045 + 3 * 3600).unwrap();
+        assert_eq!(future.relative_to(&now), "in 3 hours");
+
+        let recent = DateTimeValue::from_timestamp(1705329045 - 59).unwrap();
+        assert_eq!(recent.relative_to(&now), "just now");
+        let minute = DateTimeValue::from_timestamp(1705329045 - 60).unwrap();
+        assert_eq!(minute.relative_to(&now), "1 minute ago");
+
+        let earlier = DateTimeValue::date(2024, 1, 1).unwrap();
+        assert_eq!(earlier.relative_to(&now), "2 weeks ago");
+    }
+
     #[test]
     fn test_arithmetic() {
         let dt = DateTimeValue::from_timestamp(1705329045).unwrap();
@@ -604,6 +1086,107 @@ mod tests {
         assert!(later.timestamp().unwrap() > dt.timestamp().unwrap());
     }
 
+    #[test]
+    fn test_components() {
+        // 2024-12-31T23:30:00Z is 2025-01-01 08:30 in Tokyo
+        let value = DateTimeValue::from_timestamp(1_735_687_800).unwrap();
+        let tokyo = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+
+        let local = value.components_in(&tokyo).unwrap();
+        assert_eq!((local.year, local.month, local.day), (2025, 1, 1));
+        assert_eq!((local.hour, local.minute, local.second), (8, 30, 0));
+        assert_eq!(local.weekday, Weekday::Wed);
+        assert_eq!(local.weekday.to_string(), "Wed");
+        assert_eq!(local.day_of_year, 1);
+        assert_eq!((local.iso_year, local.iso_week), (2025, 1));
+
+        let utc = value.components().unwrap();
+        assert_eq!((utc.year, utc.month, utc.day, utc.hour), (2024, 12, 31, 23));
+        assert_eq!(utc.day_of_year, 366);
+        // Tuesday 2024-12-31 already belongs to ISO week 1 of 2025
+        assert_eq!((utc.iso_year, utc.iso_week), (2025, 1));
+        assert_eq!(value.weekday(), Some(Weekday::Tue));
+        assert_eq!(value.day_of_year(), Some(366));
+        assert_eq!(value.iso_week(), Some((2025, 1)));
+
+        let date = DateTimeValue::date(2021, 1, 3).unwrap();
+        assert_eq!(date.iso_week(), Some((2020, 53)));
+        assert_eq!(date.components_in(&tokyo).unwrap().hour, 0);
+        assert!(DateTimeValue::time(12, 0, 0).unwrap().components().is_none());
+    }
+
+    #[test]
+    fn test_truncate_to() {
+        let dt = DateTimeValue::from_iso8601("2024-03-10T19:30:45.250Z").unwrap();
+        let new_york = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+
+        // 14:30 local; start of day is local midnight, 05:00 UTC
+        let local_day = dt.truncate_to_in(TimeUnit::Day, &new_york).unwrap();
+        assert_eq!(local_day, DateTimeValue::from_iso8601("2024-03-10T05:00:00Z").unwrap());
+        assert_eq!(
+            dt.truncate_to(TimeUnit::Day),
+            DateTimeValue::from_iso8601("2024-03-10T00:00:00Z").unwrap()
+        );
+        assert_eq!(
+            dt.truncate_to(TimeUnit::Minute),
+            DateTimeValue::from_iso8601("2024-03-10T19:30:00Z").unwrap()
+        );
+        assert_eq!(
+            dt.truncate_to(TimeUnit::Year),
+            DateTimeValue::from_iso8601("2024-01-01T00:00:00Z").unwrap()
+        );
+
+        let date = DateTimeValue::date(2024, 3, 10).unwrap();
+        assert_eq!(date.truncate_to(TimeUnit::Hour), date);
+        assert_eq!(date.truncate_to(TimeUnit::Month), DateTimeValue::date(2024, 3, 1).unwrap());
+    }
+
+    #[test]
+    fn test_round_to() {
+        let down = DateTimeValue::from_iso8601("2024-03-10T14:29:59Z").unwrap();
+        let up = DateTimeValue::from_iso8601("2024-03-10T14:30:00Z").unwrap();
+        assert_eq!(
+            down.round_to(TimeUnit::Hour),
+            DateTimeValue::from_iso8601("2024-03-10T14:00:00Z").unwrap()
+        );
+        assert_eq!(
+            up.round_to(TimeUnit::Hour),
+            DateTimeValue::from_iso8601("2024-03-10T15:00:00Z").unwrap()
+        );
+
+        let date = DateTimeValue::date(2024, 1, 20).unwrap();
+        assert_eq!(date.round_to(TimeUnit::Month), DateTimeValue::date(2024, 2, 1).unwrap());
+
+        let late = DateTimeValue::time(23, 59, 40).unwrap();
+        assert_eq!(late.round_to(TimeUnit::Minute), DateTimeValue::time(0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_business_days() {
+        // 2024-01-12 is a Friday
+        let friday = DateTimeValue::date(2024, 1, 12).unwrap();
+        assert!(friday.is_business_day());
+        assert!(!friday.is_weekend());
+        assert!(DateTimeValue::date(2024, 1, 13).unwrap().is_weekend());
+
+        assert_eq!(friday.next_business_day(), DateTimeValue::date(2024, 1, 15).unwrap());
+        assert_eq!(friday.add_business_days(3), DateTimeValue::date(2024, 1, 17).unwrap());
+        assert_eq!(DateTimeValue::date(2024, 1, 17).unwrap().add_business_days(-3), friday);
+
+        // Monday the 15th is a holiday, so three business days lands on Thursday
+        let holidays = [DateTimeValue::date(2024, 1, 15).unwrap()];
+        assert_eq!(
+            friday.add_business_days_with_holidays(3, &holidays),
+            DateTimeValue::date(2024, 1, 18).unwrap()
+        );
+
+        // Time component is preserved for full datetimes
+        let dt = DateTimeValue::from_timestamp(1705069800).unwrap(); // Fri 2024-01-12 14:30 UTC
+        let moved = dt.add_business_days(1);
+        assert_eq!(moved.day(), Some(15));
+        assert_eq!(moved.hour(), Some(14));
+    }
+
     #[test]
     fn test_validation() {
         let past = DateTimeValue::from_timestamp(946684800).unwrap(); // Year 2000
@@ -1,5 +1,5 @@
 use std::collections::HashSet;
-use std::fmt::Display;
+use std::fmt::{Display, Write};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -16,6 +16,68 @@ pub struct ExpressionValue {
     template: String,
 }
 
+/// Data sources an expression reads from, found by parsing it without
+/// evaluation
+///
+/// Mirrors the dependency sets nebula-template collects for templates.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpressionSources {
+    /// Input data paths used (`$input.path`)
+    pub input_paths: HashSet<String>,
+    /// Node IDs referenced (`$node('id')`)
+    pub node_ids: HashSet<String>,
+    /// Trigger data paths used (`$trigger.json.path`)
+    pub trigger_paths: HashSet<String>,
+    /// Environment variables used (`$env.NAME`)
+    pub env_vars: HashSet<String>,
+    /// Whether system data is used
+    pub uses_system: bool,
+    /// Whether execution data is used
+    pub uses_execution: bool,
+    /// Whether workflow data is used
+    pub uses_workflow: bool,
+}
+
+/// Data source of a `$...` reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ReferenceSource {
+    Input,
+    Node(String),
+    Trigger,
+    Environment,
+    System,
+    Execution,
+    Workflow,
+}
+
+/// A `$source.path` reference found in an expression segment
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Reference {
+    source: ReferenceSource,
+    /// Normalized path, e.g. `items[0].name`; `json.` is stripped from node
+    /// and trigger paths
+    path: String,
+}
+
+impl Display for Reference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            ReferenceSource::Input => f.write_str("$input")?,
+            ReferenceSource::Node(id) => write!(f, "$node('{id}')")?,
+            ReferenceSource::Trigger => f.write_str("$trigger")?,
+            ReferenceSource::Environment => f.write_str("$env")?,
+            ReferenceSource::System => f.write_str("$system")?,
+            ReferenceSource::Execution => f.write_str("$execution")?,
+            ReferenceSource::Workflow => f.write_str("$workflow")?,
+        }
+        if self.path.is_empty() || self.path.starts_with('[') {
+            f.write_str(&self.path)
+        } else {
+            write!(f, ".{}", self.path)
+        }
+    }
+}
+
 impl ExpressionValue {
     // === Construction ===
 
@@ -225,6 +287,50 @@ impl ExpressionValue {
         self.extract_node_references().contains(&node_id.to_string())
     }
 
+    /// Lists the data references in the expression, in order of first
+    /// appearance and without duplicates
+    ///
+    /// The expression is parsed, not evaluated, so no context is needed.
+    /// Example: `"{{ $input.a + $node('x').json.b }}"` -> `["$input.a", "$node('x').b"]`
+    #[must_use]
+    pub fn variables(&self) -> Vec<String> {
+        let mut variables = Vec::new();
+        for reference in self.references() {
+            let variable = reference.to_string();
+            if !variables.contains(&variable) {
+                variables.push(variable);
+            }
+        }
+        variables
+    }
+
+    /// Collects the data sources the expression reads from, without
+    /// evaluating it
+    #[must_use]
+    pub fn referenced_sources(&self) -> ExpressionSources {
+        let mut sources = ExpressionSources::default();
+        for Reference { source, path } in self.references() {
+            match source {
+                ReferenceSource::Input => {
+                    sources.input_paths.insert(path);
+                },
+                ReferenceSource::Node(id) => {
+                    sources.node_ids.insert(id);
+                },
+                ReferenceSource::Trigger => {
+                    sources.trigger_paths.insert(path);
+                },
+                ReferenceSource::Environment => {
+                    sources.env_vars.insert(path);
+                },
+                ReferenceSource::System => sources.uses_system = true,
+                ReferenceSource::Execution => sources.uses_execution = true,
+                ReferenceSource::Workflow => sources.uses_workflow = true,
+            }
+        }
+        sources
+    }
+
     // === Expression Parsing Helpers ===
 
     fn parse_node_reference(&self, expr: &str) -> Option<String> {
@@ -257,6 +363,106 @@ impl ExpressionValue {
         None
     }
 
+    /// Parses every `$source.path` reference out of the expression segments,
+    /// skipping string literals and unknown `$` names such as `$json`
+    fn references(&self) -> Vec<Reference> {
+        let mut references = Vec::new();
+        for expr in self.extract_expressions() {
+            let mut chars = expr.chars().peekable();
+            while let Some(ch) = chars.next() {
+                match ch {
+                    '\'' | '"' => Self::skip_string(&mut chars, ch),
+                    '$' => references.extend(Self::parse_reference(&mut chars)),
+                    _ => {},
+                }
+            }
+        }
+        references
+    }
+
+    /// Parses one reference after its `$`, consuming the source and path
+    fn parse_reference(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Option<Reference> {
+        let name = Self::take_identifier(chars);
+        let source = match name.as_str() {
+            "input" => ReferenceSource::Input,
+            "node" => {
+                Self::skip_whitespace(chars);
+                chars.next_if_eq(&'(')?;
+                Self::skip_whitespace(chars);
+                let quote = chars.next_if(|c| *c == '\'' || *c == '"')?;
+                let id: String = chars.by_ref().take_while(|c| *c != quote).collect();
+                Self::skip_whitespace(chars);
+                chars.next_if_eq(&')')?;
+                ReferenceSource::Node(id)
+            },
+            "trigger" => ReferenceSource::Trigger,
+            "env" => ReferenceSource::Environment,
+            "system" => ReferenceSource::System,
+            "execution" => ReferenceSource::Execution,
+            "workflow" => ReferenceSource::Workflow,
+            _ => return None,
+        };
+
+        let mut path = String::new();
+        loop {
+            // `?.` continues the path; `??` or `?` starts an operator
+            let optional = chars.next_if_eq(&'?').is_some();
+            if optional && chars.next_if_eq(&'.').is_none() {
+                break;
+            }
+            if optional || chars.next_if_eq(&'.').is_some() {
+                let segment = Self::take_identifier(chars);
+                if segment.is_empty() {
+                    break;
+                }
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(&segment);
+            } else if chars.next_if_eq(&'[').is_some() {
+                let index = Self::take_identifier(chars);
+                if chars.next_if_eq(&']').is_none() {
+                    break;
+                }
+                let _ = write!(path, "[{index}]");
+            } else {
+                break;
+            }
+        }
+
+        if matches!(source, ReferenceSource::Node(_) | ReferenceSource::Trigger) {
+            if path == "json" {
+                path.clear();
+            } else if let Some(rest) = path.strip_prefix("json.") {
+                path = rest.to_string();
+            }
+        }
+
+        Some(Reference { source, path })
+    }
+
+    fn take_identifier(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+        let mut identifier = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+            identifier.push(c);
+        }
+        identifier
+    }
+
+    fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn skip_string(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, quote: char) {
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                chars.next();
+            } else if c == quote {
+                break;
+            }
+        }
+    }
+
     fn validate_expression_syntax(&self, expr: &str) -> ValueResult<()> {
         let trimmed = expr.trim();
 
@@ -345,3 +551,40 @@ impl Display for ExpressionValue {
         write!(f, "{}", self.template)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variables_and_referenced_sources() {
+        let expr = ExpressionValue::new("{{ $input.a + $node('x').b }}");
+        assert_eq!(expr.variables(), vec!["$input.a", "$node('x').b"]);
+
+        let sources = expr.referenced_sources();
+        assert!(sources.input_paths.contains("a"));
+        assert!(sources.node_ids.contains("x"));
+        assert!(sources.env_vars.is_empty() && !sources.uses_system);
+
+        let expr = ExpressionValue::new(
+            "{{ $node(\"user\").json.items[0].name }} {{ $env.API_KEY ?? '$input.skipped' }} \
+             {{ $trigger.json.id }} {{ $execution.id }} {{ $json.parse($input?.raw) }}",
+        );
+        assert_eq!(
+            expr.variables(),
+            vec![
+                "$node('user').items[0].name",
+                "$env.API_KEY",
+                "$trigger.id",
+                "$execution.id",
+                "$input.raw",
+            ]
+        );
+        let sources = expr.referenced_sources();
+        assert_eq!(sources.input_paths, HashSet::from(["raw".to_string()]));
+        assert_eq!(sources.trigger_paths, HashSet::from(["id".to_string()]));
+        assert!(sources.env_vars.contains("API_KEY") && sources.uses_execution);
+
+        assert!(ExpressionValue::new("plain text").variables().is_empty());
+    }
+}
@@ -14,18 +14,22 @@ pub mod object;
 pub mod regex;
 pub mod string;
 
-pub use array::ArrayValue;
+pub use array::{ArrayValue, JoinKind};
 pub use binary::BinaryValue;
+#[cfg(feature = "hash")]
+pub use binary::{BinaryHasher, HashAlgorithm};
 pub use boolean::BooleanValue;
-pub use color::ColorValue;
+pub use chrono::Weekday;
+pub use color::{ColorBlindness, ColorValue};
 pub use cron::CronValue;
-pub use datetime::DateTimeValue;
+pub use datetime::{DateTimeComponents, DateTimeValue, TimeUnit};
 pub use duration::DurationValue;
-pub use expression::ExpressionValue;
+pub use expression::{ExpressionSources, ExpressionValue};
 pub use file::FileValue;
 pub use mode::ModeValue;
-pub use number::NumberValue;
-pub use object::ObjectValue;
+pub use number::{FloatFormat, Locale, NumberValue};
+pub use object::{Entry, FlattenOptions, ObjectValue};
 pub use regex::RegexValue;
-pub use string::StringValue;
-
+#[cfg(feature = "intern")]
+pub use string::StringInterner;
+pub use string::{LengthUnit, NormalizationForm, StringValue};
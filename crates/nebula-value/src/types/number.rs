@@ -7,7 +7,7 @@ use std::str::FromStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{ValueError, ValueResult};
+use crate::{DurationValue, TimeUnit, ValueError, ValueResult};
 
 /// Number value type supporting both integers and floating-point numbers
 #[derive(Debug, Clone, Copy)]
@@ -20,6 +20,73 @@ pub enum NumberValue {
     Float(f64),
 }
 
+/// SI byte units, each 1000 times the previous
+const DECIMAL_BYTE_UNITS: [&str; 7] = ["B", "KB", "MB", "GB", "TB", "PB", "EB"];
+
+/// IEC byte units, each 1024 times the previous
+const BINARY_BYTE_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Roman numeral symbols, including subtractive pairs, from largest to smallest
+const ROMAN_NUMERALS: [(i64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Formatting policy for [`NumberValue::format_float`]
+///
+/// The default policy matches `Display`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FloatFormat {
+    /// Number of digits after the decimal point
+    pub decimals: Option<usize>,
+    /// Number of significant digits; ignored when `decimals` is set
+    pub significant_digits: Option<usize>,
+    /// Switch to scientific notation when `|value| >= 10^n`, or when
+    /// `|value| < 10^-n` for non-zero values
+    pub scientific_threshold: Option<u32>,
+    /// Strip trailing zeros (and a dangling decimal point) from the fraction
+    pub trim_trailing_zeros: bool,
+}
+
+/// Decimal and grouping separator presets for
+/// [`NumberValue::parse_with_locale`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// `1,234.56` (US, UK and most English-speaking regions)
+    #[default]
+    Us,
+    /// `1.234,56` (Germany, Italy, Spain and much of continental Europe)
+    Eu,
+    /// `1 234,56` (France, Scandinavia); any whitespace groups digits
+    Fr,
+    /// `1'234.56` (Switzerland)
+    Ch,
+}
+
+impl Locale {
+    /// Returns the `(decimal, thousands)` separators of this locale
+    #[must_use]
+    pub const fn separators(self) -> (char, char) {
+        match self {
+            Self::Us => ('.', ','),
+            Self::Eu => (',', '.'),
+            Self::Fr => (',', ' '),
+            Self::Ch => ('.', '\''),
+        }
+    }
+}
+
 impl NumberValue {
     // === Constants ===
 
@@ -366,6 +433,62 @@ impl NumberValue {
         }
     }
 
+    // === Bitwise Operations ===
+
+    /// Returns the integer value, erroring for floats
+    fn require_integer(&self, operation: &str) -> ValueResult<i64> {
+        match self {
+            Self::Integer(i) => Ok(*i),
+            Self::Float(_) => Err(ValueError::unsupported_operation(operation, "float")),
+        }
+    }
+
+    /// Bitwise AND of two integers
+    pub fn bit_and(&self, other: &Self) -> ValueResult<Self> {
+        Ok(Self::Integer(self.require_integer("bit_and")? & other.require_integer("bit_and")?))
+    }
+
+    /// Bitwise OR of two integers
+    pub fn bit_or(&self, other: &Self) -> ValueResult<Self> {
+        Ok(Self::Integer(self.require_integer("bit_or")? | other.require_integer("bit_or")?))
+    }
+
+    /// Bitwise XOR of two integers
+    pub fn bit_xor(&self, other: &Self) -> ValueResult<Self> {
+        Ok(Self::Integer(self.require_integer("bit_xor")? ^ other.require_integer("bit_xor")?))
+    }
+
+    /// Bitwise NOT of an integer
+    pub fn bit_not(&self) -> ValueResult<Self> {
+        Ok(Self::Integer(!self.require_integer("bit_not")?))
+    }
+
+    /// Shifts an integer left by `bits`, which must be below 64
+    pub fn shift_left(&self, bits: u32) -> ValueResult<Self> {
+        self.require_integer("shift_left")?
+            .checked_shl(bits)
+            .map(Self::Integer)
+            .ok_or_else(|| ValueError::custom(format!("Shift amount {bits} exceeds 63 bits")))
+    }
+
+    /// Arithmetic (sign-preserving) right shift by `bits`, which must be below 64
+    pub fn shift_right(&self, bits: u32) -> ValueResult<Self> {
+        self.require_integer("shift_right")?
+            .checked_shr(bits)
+            .map(Self::Integer)
+            .ok_or_else(|| ValueError::custom(format!("Shift amount {bits} exceeds 63 bits")))
+    }
+
+    /// Number of set bits in the integer's two's complement representation
+    pub fn count_ones(&self) -> ValueResult<u32> {
+        Ok(self.require_integer("count_ones")?.count_ones())
+    }
+
+    /// Number of unset bits in the integer's two's complement representation
+    pub fn count_zeros(&self) -> ValueResult<u32> {
+        Ok(self.require_integer("count_zeros")?.count_zeros())
+    }
+
     // === Mathematical Functions ===
 
     /// Absolute value
@@ -623,18 +746,124 @@ impl NumberValue {
         if self.as_f64() >= other.as_f64() { *self } else { *other }
     }
 
+    /// Compares two numbers under a total order
+    ///
+    /// Integers compare exactly with each other and with floats, so large
+    /// integers that round to the same `f64` still order correctly. Floats
+    /// follow [`f64::total_cmp`]: `-0.0` sorts before `0.0` and NaN sorts
+    /// after every other number (or before, if its sign bit is set).
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        match (*self, *other) {
+            (Self::Integer(a), Self::Integer(b)) => a.cmp(&b),
+            (Self::Float(a), Self::Float(b)) => a.total_cmp(&b),
+            (Self::Integer(a), Self::Float(b)) => cmp_int_float(a, b),
+            (Self::Float(a), Self::Integer(b)) => cmp_int_float(b, a).reverse(),
+        }
+    }
+
+    /// Compares two numbers exactly, or `None` if either is NaN
+    ///
+    /// Unlike [`total_cmp`](Self::total_cmp), `-0.0` equals `0.0`.
+    pub(crate) fn exact_cmp(&self, other: &Self) -> Option<Ordering> {
+        // Adding `0.0` turns `-0.0` into `0.0`
+        match (*self, *other) {
+            (Self::Integer(a), Self::Integer(b)) => Some(a.cmp(&b)),
+            (Self::Float(a), Self::Float(b)) => a.partial_cmp(&b),
+            (Self::Integer(a), Self::Float(b)) => (!b.is_nan()).then(|| cmp_int_float(a, b + 0.0)),
+            (Self::Float(a), Self::Integer(b)) => {
+                (!a.is_nan()).then(|| cmp_int_float(b, a + 0.0).reverse())
+            },
+        }
+    }
+
+    /// Checks whether two numbers differ by at most `epsilon`
+    ///
+    /// Equal numbers always compare equal, so integers compare exactly with
+    /// an `epsilon` of zero. NaN is never approximately equal to anything.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self == other || (self.as_f64() - other.as_f64()).abs() <= epsilon
+    }
+
     /// Clamps the number to a range
+    ///
+    /// Integers compare exactly, so bounds beyond 2^53 still hold. NaN is
+    /// returned unchanged.
     #[must_use]
     pub fn clamp(&self, min: &Self, max: &Self) -> Self {
-        if self.as_f64() < min.as_f64() {
-            *min
-        } else if self.as_f64() > max.as_f64() {
-            *max
+        self.clamp_checked(min, max).0
+    }
+
+    /// Clamps the number to a range, also reporting whether it was out of range
+    ///
+    /// Returns the clamped value and `true` if clamping changed it.
+    #[must_use]
+    pub fn clamp_checked(&self, min: &Self, max: &Self) -> (Self, bool) {
+        if self.exact_cmp(min) == Some(Ordering::Less) {
+            (*min, true)
+        } else if self.exact_cmp(max) == Some(Ordering::Greater) {
+            (*max, true)
         } else {
-            *self
+            (*self, false)
         }
     }
 
+    /// Returns the number unchanged if it lies within the range, otherwise an error
+    pub fn clamp_or_err(&self, min: &Self, max: &Self) -> ValueResult<Self> {
+        match self.clamp_checked(min, max) {
+            (value, false) => Ok(value),
+            (_, true) => Err(ValueError::number_out_of_range(
+                self.to_string(),
+                min.to_string(),
+                max.to_string(),
+            )),
+        }
+    }
+
+    // === Interpolation ===
+
+    /// Linearly interpolates from `self` to `to`
+    ///
+    /// `t` is clamped to `0.0..=1.0`, so the result stays between the two
+    /// endpoints; see [`lerp_unclamped`](Self::lerp_unclamped) to extrapolate.
+    /// Always returns a float.
+    #[must_use]
+    pub fn lerp(&self, to: &Self, t: f64) -> Self {
+        self.lerp_unclamped(to, t.clamp(0.0, 1.0))
+    }
+
+    /// Linearly interpolates from `self` to `to` without clamping `t`
+    ///
+    /// `t` outside `0.0..=1.0` extrapolates beyond the endpoints.
+    #[must_use]
+    pub fn lerp_unclamped(&self, to: &Self, t: f64) -> Self {
+        let from = self.as_f64();
+        Self::Float(from + (to.as_f64() - from) * t)
+    }
+
+    /// Remaps the number from the range `in_min..in_max` to `out_min..out_max`
+    ///
+    /// Values outside the input range are extrapolated, not clamped; either
+    /// range may be reversed. Fails if the input range has zero width.
+    pub fn map_range(
+        &self,
+        in_min: &Self,
+        in_max: &Self,
+        out_min: &Self,
+        out_max: &Self,
+    ) -> ValueResult<Self> {
+        let width = in_max.as_f64() - in_min.as_f64();
+        if width == 0.0 {
+            return Err(ValueError::custom(format!(
+                "Input range {in_min}..{in_max} has zero width"
+            )));
+        }
+
+        let t = (self.as_f64() - in_min.as_f64()) / width;
+        Ok(out_min.lerp_unclamped(out_max, t))
+    }
+
     // === Range Validation ===
 
     /// Validates that the number is within a range
@@ -693,6 +922,247 @@ impl NumberValue {
         }
     }
 
+    // === Byte Sizes ===
+
+    /// Formats a byte count for display, e.g. `1.5 KiB` or `1.5 MB`
+    ///
+    /// `binary` selects 1024-based IEC units (KiB, MiB, ...) instead of
+    /// 1000-based SI units (KB, MB, ...). The largest unit that keeps the
+    /// value at least 1 is used, with one decimal place; plain bytes are shown
+    /// without decimals.
+    #[must_use]
+    pub fn to_byte_size(&self, binary: bool) -> String {
+        let (base, units) =
+            if binary { (1024.0, &BINARY_BYTE_UNITS) } else { (1000.0, &DECIMAL_BYTE_UNITS) };
+
+        let bytes = self.as_f64();
+        let mut magnitude = bytes.abs();
+        let mut unit = 0;
+        while magnitude >= base && unit < units.len() - 1 {
+            magnitude /= base;
+            unit += 1;
+        }
+        // Rounding can carry into the next unit (e.g. 1023.96 KiB)
+        let rounded = if unit == 0 { magnitude.round() } else { (magnitude * 10.0).round() / 10.0 };
+        if rounded >= base && unit < units.len() - 1 {
+            magnitude /= base;
+            unit += 1;
+        }
+
+        let sign = if bytes < 0.0 { "-" } else { "" };
+        if unit == 0 {
+            format!("{sign}{magnitude:.0} {}", units[0])
+        } else {
+            format!("{sign}{magnitude:.1} {}", units[unit])
+        }
+    }
+
+    /// Parses a byte size such as `1.5MB`, `2 GiB` or `512`
+    ///
+    /// Units are case-insensitive; SI units (KB, MB, ...) are 1000-based and
+    /// IEC units (KiB, MiB, ...) are 1024-based. A bare number or a `B`
+    /// suffix means bytes. Whole results are returned as integers.
+    pub fn from_byte_size(s: &str) -> ValueResult<Self> {
+        let trimmed = s.trim();
+        let split = trimmed.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(trimmed.len());
+        let (number, unit) = trimmed.split_at(split);
+
+        let invalid = || ValueError::invalid_format("byte size", s);
+        let number: f64 = number.trim().parse().map_err(|_| invalid())?;
+        let unit = unit.to_ascii_lowercase();
+
+        let multiplier = if unit.is_empty() || unit == "b" {
+            1.0
+        } else if let Some(power) =
+            DECIMAL_BYTE_UNITS.iter().position(|u| u.eq_ignore_ascii_case(&unit))
+        {
+            1000_f64.powi(power as i32)
+        } else if let Some(power) =
+            BINARY_BYTE_UNITS.iter().position(|u| u.eq_ignore_ascii_case(&unit))
+        {
+            1024_f64.powi(power as i32)
+        } else {
+            return Err(invalid());
+        };
+
+        let bytes = number * multiplier;
+        if !bytes.is_finite() {
+            return Err(invalid());
+        }
+        if bytes.fract() == 0.0 && bytes.abs() < i64::MAX as f64 {
+            Ok(Self::Integer(bytes as i64))
+        } else {
+            Ok(Self::Float(bytes))
+        }
+    }
+
+    // === Rates ===
+
+    /// Treats this number as a count and returns it per second of `over`,
+    /// e.g. events per second
+    ///
+    /// Errors if the duration is zero.
+    pub fn rate(&self, over: &DurationValue) -> ValueResult<NumberValue> {
+        self.per_unit(over, TimeUnit::Second)
+    }
+
+    /// Like [`rate`](Self::rate), but per `unit` instead of per second
+    ///
+    /// Months and years are approximated as 30 and 365 days.
+    pub fn per_unit(&self, over: &DurationValue, unit: TimeUnit) -> ValueResult<NumberValue> {
+        let seconds = over.as_secs_f64();
+        if seconds == 0.0 {
+            return Err(ValueError::custom("Cannot compute a rate over a zero duration"));
+        }
+        Ok(Self::Float(self.as_f64() / seconds * unit.nominal_seconds()))
+    }
+
+    // === Localized Parsing ===
+
+    /// Parses a number written with the given decimal and grouping
+    /// separators, e.g. `1.234,56` with `','` and `'.'`
+    ///
+    /// Grouping separators are removed and the decimal separator becomes
+    /// `.` before parsing as with [`FromStr`]; input without either separator
+    /// parses as plain. A whitespace grouping separator matches any
+    /// whitespace, such as the no-break spaces used in French formatting.
+    pub fn parse_localized(s: &str, decimal_sep: char, thousands_sep: char) -> ValueResult<Self> {
+        let invalid = || ValueError::invalid_format("localized number", s);
+        if decimal_sep == thousands_sep {
+            return Err(invalid());
+        }
+
+        let normalized: String = s
+            .trim()
+            .chars()
+            .filter(|&c| {
+                c != thousands_sep && !(thousands_sep.is_whitespace() && c.is_whitespace())
+            })
+            .map(|c| if c == decimal_sep { '.' } else { c })
+            .collect();
+        normalized.parse().map_err(|_| invalid())
+    }
+
+    /// Parses a number using a [`Locale`] preset's separators
+    pub fn parse_with_locale(s: &str, locale: Locale) -> ValueResult<Self> {
+        let (decimal_sep, thousands_sep) = locale.separators();
+        Self::parse_localized(s, decimal_sep, thousands_sep)
+    }
+
+    // === Formatting ===
+
+    /// Formats the number according to the given [`FloatFormat`] policy
+    ///
+    /// Integers are formatted through their `f64` value, so `42` with two
+    /// fixed decimals becomes `42.00`, but without decimals, significant
+    /// digits or a scientific threshold they keep every digit, as in
+    /// `Display`. Non-finite values are written as `NaN`, `inf` and `-inf`
+    /// regardless of the policy.
+    #[must_use]
+    pub fn format_float(&self, opts: FloatFormat) -> String {
+        let value = self.as_f64();
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let scientific = opts.scientific_threshold.is_some_and(|n| {
+            let bound = 10_f64.powi(n as i32);
+            value.abs() >= bound || (value != 0.0 && value.abs() < bound.recip())
+        });
+
+        let formatted = match (scientific, opts.decimals, opts.significant_digits) {
+            (true, Some(decimals), _) => format!("{value:.decimals$e}"),
+            (true, None, Some(digits)) => {
+                let precision = digits.saturating_sub(1);
+                format!("{value:.precision$e}")
+            },
+            (true, None, None) => format!("{value:e}"),
+            (false, Some(decimals), _) => format!("{value:.decimals$}"),
+            (false, None, Some(digits)) => {
+                // Round in scientific form first so carries (9.99 -> 10) update the exponent
+                let precision = digits.saturating_sub(1);
+                let rounded = format!("{value:.precision$e}");
+                let exponent: i32 =
+                    rounded.rsplit('e').next().and_then(|e| e.parse().ok()).unwrap_or(0);
+                let decimals = (digits as i32 - 1 - exponent).max(0) as usize;
+                let rounded: f64 = rounded.parse().unwrap_or(value);
+                format!("{rounded:.decimals$}")
+            },
+            (false, None, None) => self.to_string(),
+        };
+
+        if opts.trim_trailing_zeros { trim_fraction_zeros(&formatted) } else { formatted }
+    }
+
+    /// Formats an integer in the given base (2 to 36), using lowercase digits
+    ///
+    /// Negative values get a leading `-`. Floats are rejected.
+    pub fn to_radix(&self, radix: u32) -> ValueResult<String> {
+        if !(2..=36).contains(&radix) {
+            return Err(ValueError::number_out_of_range(radix.to_string(), "2", "36"));
+        }
+        let value = self.require_integer("to_radix")?;
+
+        let mut magnitude = value.unsigned_abs();
+        let mut digits = Vec::new();
+        loop {
+            let digit = (magnitude % u64::from(radix)) as u32;
+            digits.push(char::from_digit(digit, radix).unwrap_or('0'));
+            magnitude /= u64::from(radix);
+            if magnitude == 0 {
+                break;
+            }
+        }
+        if value < 0 {
+            digits.push('-');
+        }
+        Ok(digits.iter().rev().collect())
+    }
+
+    /// Formats an integer from 1 to 3999 as an uppercase Roman numeral
+    pub fn to_roman(&self) -> ValueResult<String> {
+        let mut value = self.require_integer("to_roman")?;
+        if !(1..=3999).contains(&value) {
+            return Err(ValueError::number_out_of_range(value.to_string(), "1", "3999"));
+        }
+
+        let mut roman = String::new();
+        for (amount, symbol) in ROMAN_NUMERALS {
+            while value >= amount {
+                roman.push_str(symbol);
+                value -= amount;
+            }
+        }
+        Ok(roman)
+    }
+
+    /// Parses a Roman numeral (case-insensitive) into an integer
+    ///
+    /// Only canonical numerals are accepted, so `IIII` or `IC` are errors.
+    pub fn from_roman(s: &str) -> ValueResult<Self> {
+        let numeral = s.trim().to_ascii_uppercase();
+        let invalid = || ValueError::invalid_format("roman numeral", s);
+
+        let mut rest = numeral.as_str();
+        let mut value = 0;
+        for (amount, symbol) in ROMAN_NUMERALS {
+            while let Some(tail) = rest.strip_prefix(symbol) {
+                value += amount;
+                rest = tail;
+            }
+        }
+        if !rest.is_empty() || value == 0 {
+            return Err(invalid());
+        }
+
+        // The greedy parse accepts non-canonical forms like `IIII`; reject them
+        let number = Self::Integer(value);
+        match number.to_roman() {
+            Ok(canonical) if canonical == numeral => Ok(number),
+            _ => Err(invalid()),
+        }
+    }
+
     // === Collection Operations ===
 
     /// Sum of a slice of numbers
@@ -737,6 +1207,19 @@ impl NumberValue {
     }
 }
 
+/// Strips trailing zeros from the fraction of a fixed or scientific number
+fn trim_fraction_zeros(formatted: &str) -> String {
+    let (mantissa, exponent) = match formatted.find('e') {
+        Some(i) => formatted.split_at(i),
+        None => (formatted, ""),
+    };
+    if !mantissa.contains('.') {
+        return formatted.to_string();
+    }
+    let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+    format!("{mantissa}{exponent}")
+}
+
 // === Default Implementation ===
 
 impl Default for NumberValue {
@@ -748,6 +1231,9 @@ impl Default for NumberValue {
 
 // === Trait Implementations ===
 
+/// Integers are written as is; integral floats drop their fraction (`2.0` is
+/// written as `2`) and other floats use the shortest round-trip form. Use
+/// [`NumberValue::format_float`] for other policies.
 impl fmt::Display for NumberValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -794,6 +1280,17 @@ impl FromStr for NumberValue {
     }
 }
 
+/// Exact comparison of an integer with a float, consistent with
+/// [`f64::total_cmp`] for the float's sign of zero and NaN
+fn cmp_int_float(int: i64, float: f64) -> Ordering {
+    match (int as f64).total_cmp(&float) {
+        // `int as f64` may have rounded; the float is then integral and within
+        // ±2^63, so i128 compares both exactly
+        Ordering::Equal => i128::from(int).cmp(&(float as i128)),
+        ordering => ordering,
+    }
+}
+
 // === Equality and Ordering ===
 
 impl PartialEq for NumberValue {
@@ -1211,6 +1708,79 @@ mod tests {
         assert_eq!(a.add(&c), NumberValue::Float(12.5));
     }
 
+    #[test]
+    fn test_format_float() {
+        let fixed = FloatFormat { decimals: Some(2), ..FloatFormat::default() };
+        assert_eq!(NumberValue::new_float(1.23456).format_float(fixed), "1.23");
+        assert_eq!(NumberValue::new_int(42).format_float(fixed), "42.00");
+        let trimmed = FloatFormat { trim_trailing_zeros: true, ..fixed };
+        assert_eq!(NumberValue::new_float(2.5).format_float(trimmed), "2.5");
+        assert_eq!(NumberValue::new_int(42).format_float(trimmed), "42");
+
+        let significant = FloatFormat { significant_digits: Some(3), ..FloatFormat::default() };
+        assert_eq!(NumberValue::new_float(123.456).format_float(significant), "123");
+        assert_eq!(NumberValue::new_float(0.0012345).format_float(significant), "0.00123");
+        assert_eq!(NumberValue::new_float(9.999).format_float(significant), "10.0");
+        assert_eq!(NumberValue::new_int(98_765).format_float(significant), "98800");
+
+        let scientific = FloatFormat {
+            significant_digits: Some(3),
+            scientific_threshold: Some(6),
+            ..FloatFormat::default()
+        };
+        assert_eq!(NumberValue::new_float(6.02214e23).format_float(scientific), "6.02e23");
+        assert_eq!(NumberValue::new_float(1.5e-9).format_float(scientific), "1.50e-9");
+        assert_eq!(NumberValue::new_float(1234.5).format_float(scientific), "1230");
+        assert_eq!(NumberValue::new_float(f64::INFINITY).format_float(scientific), "inf");
+
+        assert_eq!(NumberValue::new_float(2.0).format_float(FloatFormat::default()), "2");
+        assert_eq!(
+            NumberValue::new_int(9_007_199_254_740_993).format_float(FloatFormat::default()),
+            "9007199254740993"
+        );
+    }
+
+    #[test]
+    fn test_byte_size() {
+        assert_eq!(NumberValue::new_int(1536).to_byte_size(true), "1.5 KiB");
+        assert_eq!(NumberValue::new_int(1_500_000).to_byte_size(false), "1.5 MB");
+        assert_eq!(NumberValue::new_int(512).to_byte_size(true), "512 B");
+        assert_eq!(NumberValue::new_float(1023.7).to_byte_size(true), "1.0 KiB");
+        assert_eq!(NumberValue::new_int(1_048_575).to_byte_size(true), "1.0 MiB");
+        assert_eq!(NumberValue::new_int(-2048).to_byte_size(true), "-2.0 KiB");
+
+        assert_eq!(NumberValue::from_byte_size("2GiB").unwrap(), NumberValue::Integer(2 << 30));
+        assert_eq!(NumberValue::from_byte_size("1.5MB").unwrap(), NumberValue::Integer(1_500_000));
+        assert_eq!(NumberValue::from_byte_size(" 2 kib ").unwrap(), NumberValue::Integer(2048));
+        assert_eq!(NumberValue::from_byte_size("100").unwrap(), NumberValue::Integer(100));
+        assert!(NumberValue::from_byte_size("2 GX").is_err());
+        assert!(NumberValue::from_byte_size("MB").is_err());
+    }
+
+    #[test]
+    fn test_bitwise() {
+        let a = NumberValue::new_int(0b1100);
+        let b = NumberValue::new_int(0b1010);
+
+        assert_eq!(a.bit_and(&b).unwrap(), NumberValue::Integer(0b1000));
+        assert_eq!(a.bit_or(&b).unwrap(), NumberValue::Integer(0b1110));
+        assert_eq!(a.bit_xor(&b).unwrap(), NumberValue::Integer(0b0110));
+        assert_eq!(NumberValue::ZERO.bit_not().unwrap(), NumberValue::Integer(-1));
+
+        assert_eq!(a.shift_left(2).unwrap(), NumberValue::Integer(0b110000));
+        assert_eq!(a.shift_right(2).unwrap(), NumberValue::Integer(0b11));
+        assert_eq!(NumberValue::new_int(-8).shift_right(1).unwrap(), NumberValue::Integer(-4));
+        assert!(a.shift_left(64).is_err());
+        assert!(a.shift_right(64).is_err());
+
+        assert_eq!(a.count_ones().unwrap(), 2);
+        assert_eq!(a.count_zeros().unwrap(), 62);
+
+        let float = NumberValue::new_float(12.0);
+        assert!(matches!(a.bit_and(&float), Err(ValueError::UnsupportedOperation { .. })));
+        assert!(NumberValue::new_float(f64::NAN).count_ones().is_err());
+    }
+
     #[test]
     fn test_operators() {
         let a = NumberValue::new_int(10);
@@ -1312,6 +1882,80 @@ mod tests {
         assert!(negative.validate_range(Some(0.0), Some(10.0)).is_err());
     }
 
+    #[test]
+    fn test_total_cmp() {
+        let int = NumberValue::Integer;
+        let float = NumberValue::Float;
+        assert_eq!(int(2).total_cmp(&float(2.0)), Ordering::Equal);
+        assert_eq!(int(2).total_cmp(&float(2.5)), Ordering::Less);
+        assert_eq!(float(f64::NAN).total_cmp(&int(i64::MAX)), Ordering::Greater);
+        assert_eq!(float(-0.0).total_cmp(&int(0)), Ordering::Less);
+        // 2^53 + 1 is not representable as f64 but still compares exactly
+        assert_eq!(
+            int((1 << 53) + 1).total_cmp(&float(9_007_199_254_740_992.0)),
+            Ordering::Greater
+        );
+        assert_eq!(int(i64::MAX).total_cmp(&float(9_223_372_036_854_775_808.0)), Ordering::Less);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let sum = NumberValue::from(0.1) + NumberValue::from(0.2);
+        assert!(sum.approx_eq(&NumberValue::from(0.3), 1e-9));
+        assert!(!sum.approx_eq(&NumberValue::from(0.3), 0.0));
+        assert!(NumberValue::from(2).approx_eq(&NumberValue::from(2.0), 0.0));
+        assert!(!NumberValue::from(f64::NAN).approx_eq(&NumberValue::from(f64::NAN), 1.0));
+    }
+
+    #[test]
+    fn test_clamp_variants() {
+        let min = NumberValue::new_int(0);
+        let max = NumberValue::new_int(10);
+
+        let in_range = NumberValue::new_int(5);
+        assert_eq!(in_range.clamp_checked(&min, &max), (in_range, false));
+        assert_eq!(in_range.clamp_or_err(&min, &max).unwrap(), in_range);
+
+        let below = NumberValue::new_int(-3);
+        assert_eq!(below.clamp_checked(&min, &max), (min, true));
+        assert!(matches!(below.clamp_or_err(&min, &max), Err(ValueError::NumberOutOfRange { .. })));
+
+        let above = NumberValue::new_float(10.5);
+        assert_eq!(above.clamp_checked(&min, &max), (max, true));
+        assert!(matches!(above.clamp_or_err(&min, &max), Err(ValueError::NumberOutOfRange { .. })));
+
+        // Integers past 2^53 round to the same float but still compare exactly
+        let big = NumberValue::new_int(1 << 53);
+        let past = NumberValue::new_int((1 << 53) + 1);
+        assert_eq!(past.clamp_checked(&min, &big), (big, true));
+        let float_big = NumberValue::new_float(9007199254740992.0);
+        assert_eq!(NumberValue::clamp(&past, &min, &float_big), float_big);
+
+        assert!(!NumberValue::new_float(-0.0).clamp_checked(&min, &max).1);
+        assert!(NumberValue::clamp(&NumberValue::new_float(f64::NAN), &min, &max).is_nan());
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let from = NumberValue::new_int(10);
+        let to = NumberValue::new_int(20);
+        assert_eq!(from.lerp(&to, 0.5), NumberValue::new_float(15.0));
+        assert_eq!(from.lerp(&to, 1.5), NumberValue::new_float(20.0));
+        assert_eq!(from.lerp_unclamped(&to, 1.5), NumberValue::new_float(25.0));
+
+        let zero = NumberValue::ZERO;
+        let ten = NumberValue::new_int(10);
+        let hundred = NumberValue::new_int(100);
+        let mapped = NumberValue::new_int(5).map_range(&zero, &ten, &zero, &hundred).unwrap();
+        assert_eq!(mapped, NumberValue::new_float(50.0));
+
+        // Reversed output range
+        let mapped = NumberValue::new_int(2).map_range(&zero, &ten, &hundred, &zero).unwrap();
+        assert_eq!(mapped, NumberValue::new_float(80.0));
+
+        assert!(NumberValue::new_int(5).map_range(&ten, &ten, &zero, &hundred).is_err());
+    }
+
     #[test]
     fn test_from_methods() {
         assert_eq!(NumberValue::from_i8(42i8), NumberValue::Integer(42));
@@ -1325,4 +1969,69 @@ mod tests {
         assert_eq!(NumberValue::from_int(42i32), NumberValue::Integer(42));
         assert_eq!(NumberValue::from_int(123u16), NumberValue::Integer(123));
     }
+
+    #[test]
+    fn test_rate() {
+        let minute = DurationValue::from_secs_f64(60.0).unwrap();
+        let events = NumberValue::from(120);
+
+        assert_eq!(events.rate(&minute).unwrap(), NumberValue::from(2.0));
+        assert_eq!(events.per_unit(&minute, TimeUnit::Minute).unwrap(), NumberValue::from(120.0));
+        assert_eq!(events.per_unit(&minute, TimeUnit::Hour).unwrap(), NumberValue::from(7_200.0));
+
+        let zero = DurationValue::from_secs_f64(0.0).unwrap();
+        let error = events.rate(&zero).unwrap_err();
+        assert!(error.to_string().contains("zero duration"));
+    }
+
+    #[test]
+    fn test_parse_localized() {
+        let expected = NumberValue::new_float(1234.56);
+        assert_eq!(NumberValue::parse_localized("1.234,56", ',', '.').unwrap(), expected);
+        assert_eq!(NumberValue::parse_localized("1,234.56", '.', ',').unwrap(), expected);
+        assert_eq!(NumberValue::parse_with_locale("1.234,56", Locale::Eu).unwrap(), expected);
+        assert_eq!(NumberValue::parse_with_locale("1,234.56", Locale::Us).unwrap(), expected);
+        let french = NumberValue::parse_with_locale("1\u{202F}234,56", Locale::Fr);
+        assert_eq!(french.unwrap(), expected);
+        assert_eq!(NumberValue::parse_with_locale("1'234.56", Locale::Ch).unwrap(), expected);
+
+        assert_eq!(
+            NumberValue::parse_with_locale("-1.000.000", Locale::Eu).unwrap(),
+            NumberValue::Integer(-1_000_000)
+        );
+        let plain = NumberValue::parse_with_locale("42", Locale::Eu);
+        assert_eq!(plain.unwrap(), NumberValue::Integer(42));
+
+        assert!(NumberValue::parse_with_locale("1,2,3", Locale::Eu).is_err());
+        assert!(NumberValue::parse_with_locale("", Locale::Us).is_err());
+        assert!(NumberValue::parse_with_locale("abc", Locale::Us).is_err());
+        assert!(NumberValue::parse_localized("1.5", '.', '.').is_err());
+    }
+
+    #[test]
+    fn test_radix_and_roman() {
+        let n = NumberValue::new_int(255);
+        assert_eq!(n.to_radix(16).unwrap(), "ff");
+        assert_eq!(n.to_radix(2).unwrap(), "11111111");
+        assert_eq!(NumberValue::new_int(-10).to_radix(2).unwrap(), "-1010");
+        assert_eq!(NumberValue::ZERO.to_radix(36).unwrap(), "0");
+        assert!(matches!(n.to_radix(1), Err(ValueError::NumberOutOfRange { .. })));
+        assert!(n.to_radix(37).is_err());
+        assert!(NumberValue::new_float(1.5).to_radix(10).is_err());
+
+        assert_eq!(NumberValue::new_int(2024).to_roman().unwrap(), "MMXXIV");
+        assert_eq!(NumberValue::new_int(3999).to_roman().unwrap(), "MMMCMXCIX");
+        assert!(NumberValue::new_int(0).to_roman().is_err());
+        assert!(NumberValue::new_int(4000).to_roman().is_err());
+
+        for i in 1..=3999 {
+            let roman = NumberValue::new_int(i).to_roman().unwrap();
+            assert_eq!(NumberValue::from_roman(&roman).unwrap(), NumberValue::Integer(i));
+        }
+        assert_eq!(NumberValue::from_roman("xiv").unwrap(), NumberValue::Integer(14));
+        assert!(NumberValue::from_roman("IIII").is_err());
+        assert!(NumberValue::from_roman("IC").is_err());
+        assert!(NumberValue::from_roman("").is_err());
+        assert!(NumberValue::from_roman("ABC").is_err());
+    }
 }
@@ -7,25 +7,51 @@ use indexmap::IndexMap;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{Value, ValueError, ValueResult};
+use crate::{ArrayValue, Value, ValueError, ValueResult};
 #[cfg(feature = "collections")]
 type InternalMap<K, V> = IndexMap<K, V>;
 
-#[cfg(not(feature = "collections"))]
 use std::collections::HashMap;
 use std::hash::Hash;
+use std::sync::Arc;
 
 #[cfg(not(feature = "collections"))]
 type InternalMap<K, V> = HashMap<K, V>;
 
+#[cfg(feature = "collections")]
+type InternalEntry<'a> = indexmap::map::Entry<'a, String, Value>;
+
+#[cfg(not(feature = "collections"))]
+type InternalEntry<'a> = std::collections::hash_map::Entry<'a, String, Value>;
+
+/// Key format for [`ObjectValue::flatten_with`] and
+/// [`ObjectValue::unflatten_with`]
+///
+/// The default matches [`ObjectValue::flatten`]: `.`-joined keys with arrays
+/// kept intact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlattenOptions {
+    /// String placed between path segments
+    pub separator: String,
+    /// Flatten arrays into index segments (`items.0.name`); when unflattening,
+    /// numeric segments rebuild arrays
+    pub index_arrays: bool,
+}
+
+impl Default for FlattenOptions {
+    fn default() -> Self {
+        Self { separator: ".".to_string(), index_arrays: false }
+    }
+}
+
 /// Object value type for key-value collections with ordered keys
 ///
 /// Uses IndexMap when `collections` feature is enabled for ordered keys,
 /// falls back to HashMap when disabled.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
-pub struct ObjectValue(InternalMap<String, Value>);
+pub struct ObjectValue(Arc<InternalMap<String, Value>>);
 
 impl ObjectValue {
     // === Constructors ===
@@ -34,14 +60,27 @@ impl ObjectValue {
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self(InternalMap::new())
+        Self::from_map(InternalMap::new())
     }
 
     /// Creates an object from the internal map type
     #[inline]
     #[must_use]
     pub fn from_map(map: InternalMap<String, Value>) -> Self {
-        Self(map)
+        Self(Arc::new(map))
+    }
+
+    /// Mutable access to the entries, copying them first if shared
+    #[inline]
+    fn map_mut(&mut self) -> &mut InternalMap<String, Value> {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Moves the values into `out` unless a clone shares them
+    pub(crate) fn take_unique_into(&mut self, out: &mut Vec<Value>) {
+        if let Some(map) = Arc::get_mut(&mut self.0) {
+            out.extend(std::mem::take(map).into_values());
+        }
     }
 
     /// Creates an object with specified capacity
@@ -50,11 +89,11 @@ impl ObjectValue {
     pub fn with_capacity(capacity: usize) -> Self {
         #[cfg(feature = "collections")]
         {
-            Self(IndexMap::with_capacity(capacity))
+            Self::from_map(IndexMap::with_capacity(capacity))
         }
         #[cfg(not(feature = "collections"))]
         {
-            Self(HashMap::with_capacity(capacity))
+            Self::from_map(HashMap::with_capacity(capacity))
         }
     }
 
@@ -67,7 +106,7 @@ impl ObjectValue {
         V: Into<Value>,
     {
         let map = pairs.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
-        Self(map)
+        Self::from_map(map)
     }
 
     /// Creates an object from alternating keys and values
@@ -92,7 +131,7 @@ impl ObjectValue {
             map.insert(key, value);
         }
 
-        Ok(Self(map))
+        Ok(Self::from_map(map))
     }
 
     // === Basic Operations ===
@@ -128,19 +167,19 @@ impl ObjectValue {
     /// Reserves capacity for at least additional more elements
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
-        self.0.reserve(additional);
+        self.map_mut().reserve(additional);
     }
 
     /// Shrinks the capacity as much as possible
     #[inline]
     pub fn shrink_to_fit(&mut self) {
-        self.0.shrink_to_fit();
+        self.map_mut().shrink_to_fit();
     }
 
     /// Removes all key-value pairs
     #[inline]
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.map_mut().clear();
     }
 
     // === Key-Value Access ===
@@ -156,7 +195,7 @@ impl ObjectValue {
     #[inline]
     #[must_use]
     pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
-        self.0.get_mut(key)
+        self.map_mut().get_mut(key)
     }
 
     /// Gets a value by key with error handling
@@ -184,7 +223,7 @@ impl ObjectValue {
     #[inline]
     #[must_use]
     pub fn get_index_mut(&mut self, index: usize) -> Option<(&String, &mut Value)> {
-        self.0.get_index_mut(index)
+        self.map_mut().get_index_mut(index)
     }
 
     /// Checks if a key exists
@@ -194,10 +233,38 @@ impl ObjectValue {
         self.0.contains_key(key)
     }
 
+    /// Gets a value by key, ignoring ASCII case (`content-type` matches
+    /// `Content-Type`)
+    ///
+    /// An exact match wins; otherwise the first case-insensitive match in
+    /// iteration order is returned. This is a linear scan when there is no
+    /// exact match.
+    #[must_use]
+    pub fn get_ignore_case(&self, key: &str) -> Option<&Value> {
+        self.get(key).or_else(|| {
+            self.0.iter().find(|(k, _)| k.eq_ignore_ascii_case(key)).map(|(_, v)| v)
+        })
+    }
+
+    /// Checks if a key exists, ignoring ASCII case
+    #[must_use]
+    pub fn contains_key_ignore_case(&self, key: &str) -> bool {
+        self.get_ignore_case(key).is_some()
+    }
+
+    /// Gets every entry whose key matches ignoring ASCII case, in iteration
+    /// order
+    ///
+    /// Useful for spotting keys that only differ by case.
+    #[must_use]
+    pub fn get_ci_all(&self, key: &str) -> Vec<(&String, &Value)> {
+        self.0.iter().filter(|(k, _)| k.eq_ignore_ascii_case(key)).collect()
+    }
+
     /// Inserts a key-value pair, returning the previous value if the key
     /// existed
     pub fn insert(&mut self, key: String, value: Value) -> Option<Value> {
-        self.0.insert(key, value)
+        self.map_mut().insert(key, value)
     }
 
     /// Inserts a key-value pair if the key doesn't exist
@@ -211,15 +278,20 @@ impl ObjectValue {
         }
     }
 
+    /// Gets the entry for a key for in-place get-or-insert manipulation
+    pub fn entry(&mut self, key: String) -> Entry<'_> {
+        Entry(self.map_mut().entry(key))
+    }
+
     /// Removes a key-value pair, returning the value if the key existed
     pub fn remove(&mut self, key: &str) -> Option<Value> {
         #[cfg(feature = "collections")]
         {
-            self.0.shift_remove(key)
+            self.map_mut().shift_remove(key)
         }
         #[cfg(not(feature = "collections"))]
         {
-            self.0.remove(key)
+            self.map_mut().remove(key)
         }
     }
 
@@ -228,16 +300,17 @@ impl ObjectValue {
     #[cfg(feature = "collections")]
     #[must_use]
     pub fn remove_index(&mut self, index: usize) -> Option<(String, Value)> {
-        self.0.shift_remove_index(index)
+        self.map_mut().shift_remove_index(index)
     }
 
     // === Deep/Nested Access ===
 
-    /// Gets a cloned value using a path (e.g., "user.profile.name")
+    /// Gets a value using a path (e.g., "user.profile.name"); numeric
+    /// segments index into arrays
     #[must_use]
-    pub fn get_nested_cloned(&self, path: &str) -> Option<Value> {
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current: &Value = &Value::Object(self.clone());
+    pub fn get_nested(&self, path: &str) -> Option<&Value> {
+        let mut parts = path.split('.');
+        let mut current = self.get(parts.next()?)?;
 
         for part in parts {
             match current {
@@ -255,7 +328,31 @@ impl ObjectValue {
             }
         }
 
-        Some(current.clone())
+        Some(current)
+    }
+
+    /// Gets a cloned value using a path (e.g., "user.profile.name")
+    #[must_use]
+    pub fn get_nested_cloned(&self, path: &str) -> Option<Value> {
+        self.get_nested(path).cloned()
+    }
+
+    /// Gets the value at a path converted to `T`
+    ///
+    /// Errors if the path is missing or the value cannot be converted.
+    pub fn get_path<T>(&self, path: &str) -> ValueResult<T>
+    where T: for<'a> TryFrom<&'a Value, Error = ValueError> {
+        let value = self.get_nested(path).ok_or_else(|| ValueError::key_not_found(path))?;
+        T::try_from(value)
+    }
+
+    /// Gets the value at a path converted to `T`, or `default` if the path
+    /// is missing or holds a value of another type, e.g.
+    /// `config.get_path_or("db.port", 5432i64)`
+    #[must_use]
+    pub fn get_path_or<T>(&self, path: &str, default: T) -> T
+    where T: for<'a> TryFrom<&'a Value, Error = ValueError> {
+        self.get_path(path).unwrap_or(default)
     }
 
     /// Sets a value using a path, creating intermediate objects as needed
@@ -342,7 +439,7 @@ impl ObjectValue {
     /// Returns a mutable iterator over values
     #[inline]
     pub fn values_mut(&mut self) -> impl Iterator<Item = &mut Value> {
-        self.0.values_mut()
+        self.map_mut().values_mut()
     }
 
     /// Returns an iterator over key-value pairs
@@ -354,7 +451,7 @@ impl ObjectValue {
     /// Returns a mutable iterator over key-value pairs
     #[inline]
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut Value)> {
-        self.0.iter_mut()
+        self.map_mut().iter_mut()
     }
 
     /// Collects all keys into a vector
@@ -369,12 +466,39 @@ impl ObjectValue {
         self.values().cloned().collect()
     }
 
+    /// Sorts keys lexicographically in place
+    ///
+    /// Without the `collections` feature the backing map is unordered, so
+    /// this is a no-op.
+    pub fn sort_keys(&mut self) {
+        self.sort_keys_by(|a, b| a.cmp(b));
+    }
+
+    /// Sorts keys in place using a custom comparator
+    ///
+    /// Without the `collections` feature the backing map is unordered, so
+    /// this is a no-op.
+    #[cfg_attr(not(feature = "collections"), allow(unused_mut, unused_variables))]
+    pub fn sort_keys_by<F>(&mut self, mut compare: F)
+    where F: FnMut(&str, &str) -> std::cmp::Ordering {
+        #[cfg(feature = "collections")]
+        self.map_mut().sort_by(|k1, _, k2, _| compare(k1, k2));
+    }
+
+    /// Returns a copy with keys sorted lexicographically
+    #[must_use]
+    pub fn sorted_keys(&self) -> Self {
+        let mut sorted = self.clone();
+        sorted.sort_keys();
+        sorted
+    }
+
     // === Transformation Operations ===
 
     /// Retains only the key-value pairs that satisfy the predicate
     pub fn retain<F>(&mut self, mut f: F)
     where F: FnMut(&String, &mut Value) -> bool {
-        self.0.retain(|k, v| f(k, v));
+        self.map_mut().retain(|k, v| f(k, v));
     }
 
     /// Filters the object by keys, returning a new object
@@ -387,7 +511,7 @@ impl ObjectValue {
             .filter(|(k, _)| predicate(k))
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
-        Self(filtered)
+        Self::from_map(filtered)
     }
 
     /// Filters the object by values, returning a new object
@@ -400,7 +524,7 @@ impl ObjectValue {
             .filter(|(_, v)| predicate(v))
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
-        Self(filtered)
+        Self::from_map(filtered)
     }
 
     /// Maps values to new values, keeping the same keys
@@ -408,11 +532,11 @@ impl ObjectValue {
     where F: FnMut(&Value) -> ValueResult<Value> {
         let mut result = InternalMap::new();
 
-        for (k, v) in &self.0 {
+        for (k, v) in self.0.iter() {
             result.insert(k.clone(), f(v)?);
         }
 
-        Ok(Self(result))
+        Ok(Self::from_map(result))
     }
 
     /// Maps keys to new keys, keeping the same values
@@ -420,12 +544,64 @@ impl ObjectValue {
     where F: FnMut(&String) -> ValueResult<String> {
         let mut result = InternalMap::new();
 
-        for (k, v) in &self.0 {
+        for (k, v) in self.0.iter() {
             let new_key = f(k)?;
             result.insert(new_key, v.clone());
         }
 
-        Ok(Self(result))
+        Ok(Self::from_map(result))
+    }
+
+    /// Renames a key in place, keeping its position when keys are ordered
+    ///
+    /// Fails if `from` is absent or `to` already exists.
+    pub fn rename_key(&mut self, from: &str, to: &str) -> ValueResult<()> {
+        if !self.contains_key(from) {
+            return Err(ValueError::key_not_found(from));
+        }
+        if from == to {
+            return Ok(());
+        }
+        if self.contains_key(to) {
+            return Err(ValueError::custom(format!("Key '{}' already exists", to)));
+        }
+
+        #[cfg(feature = "collections")]
+        {
+            if let Some((index, _, value)) = self.map_mut().shift_remove_full(from) {
+                self.map_mut().shift_insert(index, to.to_string(), value);
+            }
+        }
+        #[cfg(not(feature = "collections"))]
+        {
+            if let Some(value) = self.map_mut().remove(from) {
+                self.map_mut().insert(to.to_string(), value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Renames several keys at once, keeping their positions
+    ///
+    /// Renames are applied simultaneously, so keys can be swapped. Fails
+    /// without modifying the object if a source key is absent or two keys
+    /// would end up with the same name.
+    pub fn rename_keys(&mut self, mapping: &HashMap<String, String>) -> ValueResult<()> {
+        if let Some(missing) = mapping.keys().find(|key| !self.contains_key(key)) {
+            return Err(ValueError::key_not_found(missing.as_str()));
+        }
+
+        let mut result = InternalMap::with_capacity(self.0.len());
+        for (key, value) in self.0.iter() {
+            let new_key = mapping.get(key).unwrap_or(key);
+            if result.contains_key(new_key) {
+                return Err(ValueError::custom(format!("Key '{}' already exists", new_key)));
+            }
+            result.insert(new_key.clone(), value.clone());
+        }
+
+        self.0 = Arc::new(result);
+        Ok(())
     }
 
     /// Transforms both keys and values
@@ -433,26 +609,26 @@ impl ObjectValue {
     where F: FnMut(&String, &Value) -> ValueResult<(String, Value)> {
         let mut result = InternalMap::new();
 
-        for (k, v) in &self.0 {
+        for (k, v) in self.0.iter() {
             let (new_key, new_value) = f(k, v)?;
             result.insert(new_key, new_value);
         }
 
-        Ok(Self(result))
+        Ok(Self::from_map(result))
     }
 
     // === Merging Operations ===
 
     /// Merges another object into this one (shallow merge)
     pub fn merge(&mut self, other: &Self) {
-        for (k, v) in &other.0 {
-            self.0.insert(k.clone(), v.clone());
+        for (k, v) in other.0.iter() {
+            self.map_mut().insert(k.clone(), v.clone());
         }
     }
 
     /// Deep merges another object into this one
     pub fn deep_merge(&mut self, other: &Self) -> ValueResult<()> {
-        for (k, v) in &other.0 {
+        for (k, v) in other.0.iter() {
             match (self.get_mut(k), v) {
                 (Some(Value::Object(existing)), Value::Object(incoming)) => {
                     existing.deep_merge(incoming)?;
@@ -517,6 +693,26 @@ impl ObjectValue {
         self.0.iter().all(|(k, v)| predicate(k, v))
     }
 
+    /// Checks whether every key in `subset` exists here with a matching value
+    ///
+    /// Nested objects match recursively, so they only need to contain the
+    /// subset's keys. Arrays must have the same length and match
+    /// element-wise under the same rules; other values must be equal.
+    #[must_use]
+    pub fn contains_subset(&self, subset: &ObjectValue) -> bool {
+        subset
+            .0
+            .iter()
+            .all(|(key, expected)| self.0.get(key).is_some_and(|v| value_contains(v, expected)))
+    }
+
+    /// Checks whether this object is contained in `other`; see
+    /// [`contains_subset`](Self::contains_subset)
+    #[must_use]
+    pub fn is_subset_of(&self, other: &ObjectValue) -> bool {
+        other.contains_subset(self)
+    }
+
     // === Utility Operations ===
 
     /// Flattens nested objects using dot notation
@@ -524,12 +720,12 @@ impl ObjectValue {
     pub fn flatten(&self) -> Self {
         let mut result = InternalMap::new();
         self.flatten_recursive("", &mut result);
-        Self(result)
+        Self::from_map(result)
     }
 
     /// Helper function for recursive flattening
     fn flatten_recursive(&self, prefix: &str, result: &mut InternalMap<String, Value>) {
-        for (k, v) in &self.0 {
+        for (k, v) in self.0.iter() {
             let key = if prefix.is_empty() { k.clone() } else { format!("{prefix}.{k}") };
 
             match v {
@@ -543,11 +739,71 @@ impl ObjectValue {
         }
     }
 
+    /// Flattens nested objects, and optionally arrays, into single-level keys
+    ///
+    /// Empty objects and arrays are kept as values so that
+    /// [`unflatten_with`](Self::unflatten_with) restores them.
+    #[must_use]
+    pub fn flatten_with(&self, opts: &FlattenOptions) -> Self {
+        let mut result = InternalMap::new();
+        for (k, v) in self.0.iter() {
+            flatten_value(k.clone(), v, opts, &mut result);
+        }
+        Self::from_map(result)
+    }
+
+    /// Lazily yields every leaf with its `.`-joined path, depth first
+    ///
+    /// Yields the same entries as [`flatten_with`](Self::flatten_with) with
+    /// default options, borrowing values instead of building a map: non-empty
+    /// objects are descended into, while arrays and empty objects are leaves.
+    pub fn deep_iter(&self) -> impl Iterator<Item = (String, &Value)> {
+        DeepIter::new(self, ".", false)
+    }
+
+    /// Lazily yields every leaf with its path, formatted as by
+    /// [`flatten_with`](Self::flatten_with) with the same options
+    pub fn deep_iter_with<'a>(
+        &'a self,
+        opts: &'a FlattenOptions,
+    ) -> impl Iterator<Item = (String, &'a Value)> {
+        DeepIter::new(self, &opts.separator, opts.index_arrays)
+    }
+
+    /// Paths of the leaves yielded by [`deep_iter`](Self::deep_iter)
+    pub fn deep_keys(&self) -> impl Iterator<Item = String> + '_ {
+        self.deep_iter().map(|(path, _)| path)
+    }
+
+    /// Leaf values yielded by [`deep_iter`](Self::deep_iter)
+    pub fn deep_values(&self) -> impl Iterator<Item = &Value> {
+        self.deep_iter().map(|(_, value)| value)
+    }
+
+    /// Rebuilds nested values from keys produced by
+    /// [`flatten_with`](Self::flatten_with) with the same options
+    ///
+    /// With `index_arrays`, numeric segments create arrays and any gaps are
+    /// filled with nulls. Fails if two keys conflict, e.g. `a` and `a.b`.
+    pub fn unflatten_with(&self, opts: &FlattenOptions) -> ValueResult<Self> {
+        let mut root = Value::Object(Self::new());
+        let index_limit = opts.index_arrays.then_some(self.len());
+        for (k, v) in self.0.iter() {
+            let segments: Vec<&str> = k.split(opts.separator.as_str()).collect();
+            unflatten_into(&mut root, &segments, v.clone(), index_limit)
+                .map_err(|err| err.into_value_error(k))?;
+        }
+        match root {
+            Value::Object(obj) => Ok(obj),
+            _ => unreachable!("root is always an object"),
+        }
+    }
+
     /// Reverses the flattening operation (limited support)
     pub fn unflatten(&self) -> ValueResult<Self> {
         let mut result = Self::new();
 
-        for (k, v) in &self.0 {
+        for (k, v) in self.0.iter() {
             result.set_nested(k, v.clone())?;
         }
 
@@ -558,7 +814,7 @@ impl ObjectValue {
     pub fn invert(&self) -> ValueResult<Self> {
         let mut result = InternalMap::new();
 
-        for (k, v) in &self.0 {
+        for (k, v) in self.0.iter() {
             match v.as_string() {
                 Some(string_val) => {
                     result.insert(string_val.to_string(), Value::string(k.as_str()));
@@ -571,7 +827,7 @@ impl ObjectValue {
             }
         }
 
-        Ok(Self(result))
+        Ok(Self::from_map(result))
     }
 
     /// Creates a copy with only the specified keys
@@ -585,7 +841,7 @@ impl ObjectValue {
             }
         }
 
-        Self(result)
+        Self::from_map(result)
     }
 
     /// Creates a copy without the specified keys
@@ -600,7 +856,24 @@ impl ObjectValue {
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
 
-        Self(filtered)
+        Self::from_map(filtered)
+    }
+
+    /// Splits the object into the entries that satisfy the predicate and
+    /// those that don't
+    ///
+    /// Like [`pick`](Self::pick) and [`omit`](Self::omit) combined, but driven
+    /// by a predicate instead of fixed key lists. Each half keeps the
+    /// original insertion order when the `collections` feature is enabled.
+    #[must_use]
+    pub fn partition_keys<F>(&self, mut predicate: F) -> (Self, Self)
+    where F: FnMut(&str, &Value) -> bool {
+        let (matching, rest): (InternalMap<_, _>, InternalMap<_, _>) = self
+            .0
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .partition(|(k, v)| predicate(k, v));
+        (Self::from_map(matching), Self::from_map(rest))
     }
 
     /// Gets the size in terms of number of nested values
@@ -625,8 +898,8 @@ impl ObjectValue {
 
     /// Converts to the internal map
     #[must_use]
-    pub fn into_inner_map(self) -> InternalMap<String, Value> {
-        self.0
+    pub fn into_inner_map(mut self) -> InternalMap<String, Value> {
+        Arc::unwrap_or_clone(std::mem::take(&mut self.0))
     }
 
     /// Gets a reference to the internal map
@@ -640,7 +913,7 @@ impl ObjectValue {
     #[inline]
     #[must_use]
     pub fn as_inner_map_mut(&mut self) -> &mut InternalMap<String, Value> {
-        &mut self.0
+        self.map_mut()
     }
 }
 
@@ -653,6 +926,205 @@ impl Default for ObjectValue {
     }
 }
 
+// === Helper Functions ===
+
+/// Adds `value` under `key`, descending into non-empty objects (and arrays
+/// when enabled)
+fn flatten_value(
+    key: String,
+    value: &Value,
+    opts: &FlattenOptions,
+    result: &mut InternalMap<String, Value>,
+) {
+    match value {
+        Value::Object(obj) if !obj.is_empty() => {
+            for (k, v) in obj.0.iter() {
+                flatten_value(format!("{key}{}{k}", opts.separator), v, opts, result);
+            }
+        },
+        Value::Array(arr) if opts.index_arrays && !arr.is_empty() => {
+            for (i, v) in arr.iter().enumerate() {
+                flatten_value(format!("{key}{}{i}", opts.separator), v, opts, result);
+            }
+        },
+        _ => {
+            result.insert(key, value.clone());
+        },
+    }
+}
+
+/// Depth-first iterator behind [`ObjectValue::deep_iter`]
+///
+/// Each frame keeps the path of the container it walks; the root object has
+/// none, so its keys are yielded unprefixed.
+struct DeepIter<'a> {
+    stack: Vec<DeepFrame<'a>>,
+    separator: &'a str,
+    index_arrays: bool,
+}
+
+enum DeepFrame<'a> {
+    Object(Option<String>, <&'a InternalMap<String, Value> as IntoIterator>::IntoIter),
+    Array(String, std::iter::Enumerate<std::slice::Iter<'a, Value>>),
+}
+
+impl<'a> DeepIter<'a> {
+    fn new(root: &'a ObjectValue, separator: &'a str, index_arrays: bool) -> Self {
+        Self { stack: vec![DeepFrame::Object(None, root.0.iter())], separator, index_arrays }
+    }
+}
+
+impl<'a> Iterator for DeepIter<'a> {
+    type Item = (String, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next = match self.stack.last_mut()? {
+                DeepFrame::Object(prefix, iter) => iter.next().map(|(k, v)| match prefix {
+                    Some(prefix) => (format!("{prefix}{}{k}", self.separator), v),
+                    None => (k.clone(), v),
+                }),
+                DeepFrame::Array(prefix, iter) => {
+                    iter.next().map(|(i, v)| (format!("{prefix}{}{i}", self.separator), v))
+                },
+            };
+            let Some((path, value)) = next else {
+                self.stack.pop();
+                continue;
+            };
+
+            match value {
+                Value::Object(obj) if !obj.is_empty() => {
+                    self.stack.push(DeepFrame::Object(Some(path), obj.0.iter()));
+                },
+                Value::Array(arr) if self.index_arrays && !arr.is_empty() => {
+                    self.stack.push(DeepFrame::Array(path, arr.iter().enumerate()));
+                },
+                _ => return Some((path, value)),
+            }
+        }
+    }
+}
+
+/// Why [`unflatten_into`] could not store a value
+enum UnflattenError {
+    /// A segment ran into a value of the wrong shape or a leaf is already set
+    Conflict,
+    /// An array index was not below the limit
+    IndexTooLarge(usize),
+}
+
+impl UnflattenError {
+    fn into_value_error(self, key: &str) -> ValueError {
+        match self {
+            Self::Conflict => ValueError::custom(format!("Conflicting flattened key '{key}'")),
+            Self::IndexTooLarge(index) => ValueError::custom(format!(
+                "Array index {index} in flattened key '{key}' exceeds the number of keys"
+            )),
+        }
+    }
+}
+
+/// Stores `value` at `segments` below `target`, creating containers as needed
+///
+/// Numeric segments index arrays when `index_limit` is set. Every array
+/// element of a flattened object has a key of its own, so indices must be
+/// below the number of keys; the limit keeps a key like `a.4000000000` from
+/// allocating a huge array.
+fn unflatten_into(
+    target: &mut Value,
+    segments: &[&str],
+    value: Value,
+    index_limit: Option<usize>,
+) -> Result<(), UnflattenError> {
+    let Some((segment, rest)) = segments.split_first() else {
+        if !target.is_null() {
+            return Err(UnflattenError::Conflict);
+        }
+        *target = value;
+        return Ok(());
+    };
+
+    let index = index_limit.and_then(|_| segment.parse::<usize>().ok());
+    if target.is_null() {
+        *target = match index {
+            Some(_) => Value::Array(ArrayValue::empty()),
+            None => Value::Object(ObjectValue::new()),
+        };
+    }
+
+    let child = match (target, index) {
+        (Value::Array(arr), Some(index)) => {
+            if index_limit.is_some_and(|limit| index >= limit) {
+                return Err(UnflattenError::IndexTooLarge(index));
+            }
+            if arr.len() <= index {
+                arr.resize(index + 1, Value::Null);
+            }
+            &mut arr[index]
+        },
+        (Value::Object(obj), None) => {
+            obj.map_mut().entry((*segment).to_string()).or_insert(Value::Null)
+        },
+        _ => return Err(UnflattenError::Conflict),
+    };
+    unflatten_into(child, rest, value, index_limit)
+}
+
+/// Whether `actual` structurally contains `expected`, as for
+/// [`ObjectValue::contains_subset`]
+fn value_contains(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => actual.contains_subset(expected),
+        (Value::Array(actual), Value::Array(expected)) => {
+            actual.len() == expected.len()
+                && actual.iter().zip(expected.iter()).all(|(a, e)| value_contains(a, e))
+        },
+        _ => actual == expected,
+    }
+}
+
+// === Entry API ===
+
+/// A view into a single key of an [`ObjectValue`], which may be vacant or
+/// occupied
+///
+/// Obtained from [`ObjectValue::entry`]; mirrors `std`'s map entry API.
+pub struct Entry<'a>(InternalEntry<'a>);
+
+impl<'a> Entry<'a> {
+    /// Returns the entry's key
+    #[must_use]
+    pub fn key(&self) -> &str {
+        self.0.key()
+    }
+
+    /// Inserts `default` if the key is vacant, returning the value
+    pub fn or_insert(self, default: Value) -> &'a mut Value {
+        self.0.or_insert(default)
+    }
+
+    /// Inserts the result of `default` if the key is vacant, returning the
+    /// value
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut Value
+    where F: FnOnce() -> Value {
+        self.0.or_insert_with(default)
+    }
+
+    /// Modifies the value in place if the key is occupied
+    #[must_use]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where F: FnOnce(&mut Value) {
+        Self(self.0.and_modify(f))
+    }
+}
+
+impl fmt::Debug for Entry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Entry").field(&self.key()).finish()
+    }
+}
+
 // === Trait Implementations ===
 
 impl fmt::Display for ObjectValue {
@@ -661,9 +1133,29 @@ impl fmt::Display for ObjectValue {
     }
 }
 
+impl Drop for ObjectValue {
+    fn drop(&mut self) {
+        // Only nested containers can make the default drop recurse
+        let nested = |map: &&mut InternalMap<String, Value>| {
+            map.values().any(|value| value.is_array() || value.is_object())
+        };
+        if let Some(map) = Arc::get_mut(&mut self.0).filter(nested) {
+            crate::value::drop_nested(std::mem::take(map).into_values().collect());
+        }
+    }
+}
+
+impl PartialEq for ObjectValue {
+    // Compare contents: `Arc`'s own `PartialEq` treats shared storage as equal
+    // without looking, which would make a NaN value equal to itself
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
 impl Hash for ObjectValue {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        for (k, v) in &self.0 {
+        for (k, v) in self.0.iter() {
             k.hash(state);
             v.hash(state);
         }
@@ -682,7 +1174,7 @@ impl Deref for ObjectValue {
 impl DerefMut for ObjectValue {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        self.map_mut()
     }
 }
 
@@ -696,7 +1188,7 @@ impl Index<&str> for ObjectValue {
 
 impl IndexMut<&str> for ObjectValue {
     fn index_mut(&mut self, key: &str) -> &mut Self::Output {
-        self.0.get_mut(key).expect("Key not found")
+        self.map_mut().get_mut(key).expect("Key not found")
     }
 }
 
@@ -706,7 +1198,7 @@ impl IndexMut<&str> for ObjectValue {
 impl From<IndexMap<String, Value>> for ObjectValue {
     #[inline]
     fn from(map: IndexMap<String, Value>) -> Self {
-        Self(map)
+        Self::from_map(map)
     }
 }
 
@@ -714,7 +1206,7 @@ impl From<IndexMap<String, Value>> for ObjectValue {
 impl From<ObjectValue> for IndexMap<String, Value> {
     #[inline]
     fn from(obj: ObjectValue) -> Self {
-        obj.0
+        obj.into_inner_map()
     }
 }
 
@@ -722,18 +1214,18 @@ impl From<std::collections::HashMap<String, Value>> for ObjectValue {
     fn from(map: std::collections::HashMap<String, Value>) -> Self {
         #[cfg(feature = "collections")]
         {
-            Self(map.into_iter().collect())
+            Self::from_map(map.into_iter().collect())
         }
         #[cfg(not(feature = "collections"))]
         {
-            Self(map)
+            Self::from_map(map)
         }
     }
 }
 
 impl From<ObjectValue> for std::collections::HashMap<String, Value> {
     fn from(obj: ObjectValue) -> Self {
-        obj.0.into_iter().collect()
+        obj.into_inner_map().into_iter().collect()
     }
 }
 
@@ -741,25 +1233,25 @@ impl From<ObjectValue> for std::collections::HashMap<String, Value> {
 
 impl FromIterator<(String, Value)> for ObjectValue {
     fn from_iter<T: IntoIterator<Item = (String, Value)>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        Self::from_map(iter.into_iter().collect())
     }
 }
 
 impl<'a> FromIterator<(&'a str, Value)> for ObjectValue {
     fn from_iter<T: IntoIterator<Item = (&'a str, Value)>>(iter: T) -> Self {
-        Self(iter.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+        Self::from_map(iter.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
     }
 }
 
 impl Extend<(String, Value)> for ObjectValue {
     fn extend<T: IntoIterator<Item = (String, Value)>>(&mut self, iter: T) {
-        self.0.extend(iter);
+        self.map_mut().extend(iter);
     }
 }
 
 impl<'a> Extend<(&'a str, Value)> for ObjectValue {
     fn extend<T: IntoIterator<Item = (&'a str, Value)>>(&mut self, iter: T) {
-        self.0.extend(iter.into_iter().map(|(k, v)| (k.to_string(), v)));
+        self.map_mut().extend(iter.into_iter().map(|(k, v)| (k.to_string(), v)));
     }
 }
 
@@ -768,7 +1260,7 @@ impl IntoIterator for ObjectValue {
     type IntoIter = <InternalMap<String, Value> as IntoIterator>::IntoIter;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.into_inner_map().into_iter()
     }
 }
 
@@ -786,7 +1278,7 @@ impl<'a> IntoIterator for &'a mut ObjectValue {
     type IntoIter = <&'a mut InternalMap<String, Value> as IntoIterator>::IntoIter;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter_mut()
+        self.map_mut().iter_mut()
     }
 }
 
@@ -795,7 +1287,7 @@ impl<'a> IntoIterator for &'a mut ObjectValue {
 #[cfg(feature = "json")]
 impl From<ObjectValue> for serde_json::Value {
     fn from(obj: ObjectValue) -> Self {
-        let map = obj.0.into_iter().map(|(k, v)| (k, v.into())).collect();
+        let map = obj.into_inner_map().into_iter().map(|(k, v)| (k, v.into())).collect();
         serde_json::Value::Object(map)
     }
 }
@@ -809,7 +1301,7 @@ impl TryFrom<serde_json::Value> for ObjectValue {
             serde_json::Value::Object(map) => {
                 let converted: Result<InternalMap<String, Value>, ValueError> =
                     map.into_iter().map(|(k, v)| Ok((k, v.try_into()?))).collect();
-                Ok(Self(converted?))
+                Ok(Self::from_map(converted?))
             },
             other => Err(ValueError::custom(format!("Cannot convert {:?} to ObjectValue", other))),
         }
@@ -832,6 +1324,32 @@ mod tests {
         assert_eq!(obj.get("name"), Some(&Value::string("John")));
     }
 
+    #[test]
+    fn test_clone_shares_storage_until_mutated() {
+        let mut original: ObjectValue =
+            (0..10_000).map(|i| (format!("key{i}"), Value::number(i))).collect();
+        let mut copy = original.clone();
+        assert!(Arc::ptr_eq(&original.0, &copy.0));
+
+        // Reads through either handle don't copy
+        assert_eq!(copy.get("key42"), Some(&Value::number(42)));
+        assert!(Arc::ptr_eq(&original.0, &copy.0));
+
+        copy.insert("extra".to_string(), Value::boolean(true));
+        assert!(!Arc::ptr_eq(&original.0, &copy.0));
+        assert_eq!(original.len(), 10_000);
+        assert_eq!(copy.len(), 10_001);
+
+        // Shared storage still compares value by value
+        let nan = ObjectValue::from_pairs([("x", Value::number(f64::NAN))]);
+        assert_ne!(nan, nan.clone());
+
+        // A handle that is no longer shared mutates in place
+        let storage = Arc::as_ptr(&original.0);
+        original.remove("key0");
+        assert_eq!(Arc::as_ptr(&original.0), storage);
+    }
+
     #[test]
     fn test_basic_operations() {
         let mut obj = ObjectValue::new();
@@ -851,6 +1369,45 @@ mod tests {
         assert!(!obj.contains_key("key1"));
     }
 
+    #[test]
+    fn test_get_ignore_case() {
+        let mut headers = ObjectValue::new();
+        headers.insert("Content-Type".to_string(), Value::string("text/html"));
+        headers.insert("X-Request-Id".to_string(), Value::string("abc"));
+
+        assert_eq!(headers.get_ignore_case("content-type"), Some(&Value::string("text/html")));
+        assert!(headers.contains_key_ignore_case("x-request-id"));
+        assert_eq!(headers.get_ignore_case("accept"), None);
+        assert!(!headers.contains_key_ignore_case("accept"));
+
+        headers.insert("content-type".to_string(), Value::string("application/json"));
+        assert_eq!(
+            headers.get_ignore_case("content-type"),
+            Some(&Value::string("application/json"))
+        );
+        assert_eq!(headers.get_ci_all("CONTENT-TYPE").len(), 2);
+    }
+
+    #[test]
+    fn test_get_path() {
+        let mut db = ObjectValue::new();
+        db.insert("port".to_string(), Value::number(6432));
+        db.insert("host".to_string(), Value::number(1));
+        let mut config = ObjectValue::new();
+        config.insert("db".to_string(), Value::object(db));
+
+        assert_eq!(config.get_path::<i64>("db.port"), Ok(6432));
+        assert_eq!(config.get_path_or("db.port", 5432i64), 6432);
+        assert_eq!(config.get_path_or("db.timeout", 30i64), 30);
+        assert_eq!(config.get_path_or("db.host", "localhost".to_string()), "localhost");
+
+        assert!(matches!(
+            config.get_path::<i64>("db.timeout"),
+            Err(ValueError::KeyNotFound { .. })
+        ));
+        assert!(config.get_path::<bool>("db.port").is_err());
+    }
+
     #[test]
     fn test_nested_access() {
         let mut obj = ObjectValue::new();
@@ -931,6 +1488,78 @@ mod tests {
         assert!(!flattened.is_empty());
     }
 
+    #[test]
+    fn test_flatten_with() -> ValueResult<()> {
+        let mut obj = ObjectValue::new();
+        obj.set_nested("user.name", Value::string("John"))?;
+        obj.set_nested("user.tags", Value::array(ArrayValue::new(vec![
+            Value::string("admin"),
+            Value::object(ObjectValue::from_pairs([("level", Value::number(2))])),
+        ])))?;
+        obj.insert("meta".to_string(), Value::object(ObjectValue::new()));
+
+        let slash = FlattenOptions { separator: "/".to_string(), ..Default::default() };
+        let flat = obj.flatten_with(&slash);
+        assert_eq!(flat.len(), 3);
+        assert_eq!(flat.get("user/name"), Some(&Value::string("John")));
+        assert!(flat.get("user/tags").is_some_and(Value::is_array));
+        assert_eq!(flat.get("meta"), Some(&Value::object(ObjectValue::new())));
+        assert_eq!(flat.unflatten_with(&slash)?, obj);
+
+        let indexed = FlattenOptions { index_arrays: true, ..Default::default() };
+        let flat = obj.flatten_with(&indexed);
+        assert_eq!(flat.len(), 4);
+        assert_eq!(flat.get("user.tags.0"), Some(&Value::string("admin")));
+        assert_eq!(flat.get("user.tags.1.level"), Some(&Value::number(2)));
+        assert_eq!(flat.unflatten_with(&indexed)?, obj);
+
+        let conflicting =
+            ObjectValue::from_pairs([("a", Value::number(1)), ("a.b", Value::number(2))]);
+        assert!(conflicting.unflatten_with(&FlattenOptions::default()).is_err());
+
+        // Indices beyond the number of keys are rejected instead of allocated
+        for key in ["a.18446744073709551615", "a.4000000000", "a.1"] {
+            let huge = ObjectValue::from_pairs([(key, Value::number(1))]);
+            let err = huge.unflatten_with(&indexed).unwrap_err();
+            assert!(err.to_string().contains("exceeds the number of keys"));
+        }
+        let sparse = ObjectValue::from_pairs([("a.1", Value::number(1)), ("b", Value::number(2))]);
+        let nested = sparse.unflatten_with(&indexed)?;
+        assert_eq!(nested.get_nested("a.1"), Some(&Value::number(1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deep_iter() {
+        let mut obj = ObjectValue::new();
+        obj.set_nested("user.name", Value::string("John")).unwrap();
+        obj.set_nested("user.age", Value::number(30)).unwrap();
+        obj.set_nested("user.tags", Value::array(ArrayValue::new(vec![Value::string("admin")])))
+            .unwrap();
+        obj.insert("active".to_string(), Value::boolean(true));
+
+        let mut leaves: Vec<_> = obj.deep_iter().collect();
+        leaves.sort_by(|a, b| a.0.cmp(&b.0));
+        let paths: Vec<_> = leaves.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(paths, ["active", "user.age", "user.name", "user.tags"]);
+        assert!(leaves.iter().all(|(_, value)| !value.is_object()));
+        assert_eq!(leaves[2].1, &Value::string("John"));
+        assert_eq!(obj.deep_values().count(), 4);
+
+        let indexed = FlattenOptions { index_arrays: true, ..Default::default() };
+        let mut paths: Vec<_> = obj.deep_iter_with(&indexed).map(|(path, _)| path).collect();
+        paths.sort();
+        assert_eq!(paths, ["active", "user.age", "user.name", "user.tags.0"]);
+
+        let mut keys: Vec<_> = obj.deep_keys().collect();
+        let mut flat_keys: Vec<_> = obj.flatten_with(&FlattenOptions::default()).key_names();
+        keys.sort();
+        flat_keys.sort();
+        assert_eq!(keys, flat_keys);
+        assert_eq!(ObjectValue::new().deep_iter().count(), 0);
+    }
+
     #[test]
     fn test_pick_omit() {
         let obj = ObjectValue::from_pairs([
@@ -954,6 +1583,71 @@ mod tests {
         assert!(!omitted.contains_key("b"));
     }
 
+    #[test]
+    fn test_partition_keys() {
+        let obj = ObjectValue::from_pairs([
+            ("config.host", Value::string("localhost")),
+            ("trace_id", Value::string("abc")),
+            ("config.port", Value::number(8080)),
+            ("user", Value::string("ann")),
+        ]);
+
+        let (config, extra) = obj.partition_keys(|key, _| key.starts_with("config."));
+        assert_eq!(config.len(), 2);
+        assert!(config.contains_key("config.host") && config.contains_key("config.port"));
+        assert_eq!(extra.len(), 2);
+        assert!(extra.contains_key("trace_id") && extra.contains_key("user"));
+        assert_eq!(config.merged(&extra), obj);
+
+        #[cfg(feature = "collections")]
+        {
+            assert_eq!(config.keys().collect::<Vec<_>>(), ["config.host", "config.port"]);
+            assert_eq!(extra.keys().collect::<Vec<_>>(), ["trace_id", "user"]);
+        }
+
+        let (numbers, _) = obj.partition_keys(|_, value| value.is_number());
+        assert_eq!(numbers.keys().collect::<Vec<_>>(), ["config.port"]);
+    }
+
+    #[test]
+    fn test_contains_subset() {
+        let user = ObjectValue::from_pairs([
+            ("name", Value::string("Ann")),
+            ("age", Value::number(30)),
+            (
+                "address",
+                Value::Object(ObjectValue::from_pairs([
+                    ("city", Value::string("Oslo")),
+                    ("zip", Value::string("0150")),
+                ])),
+            ),
+            ("tags", Value::array(vec![Value::string("a"), Value::string("b")])),
+        ]);
+
+        assert!(user.contains_subset(&user));
+        assert!(user.contains_subset(&ObjectValue::new()));
+        let subset = ObjectValue::from_pairs([("name", Value::string("Ann"))]);
+        assert!(user.contains_subset(&subset));
+        assert!(subset.is_subset_of(&user));
+        assert!(!user.is_subset_of(&subset));
+
+        let missing = ObjectValue::from_pairs([("email", Value::string("a@b.c"))]);
+        assert!(!user.contains_subset(&missing));
+
+        let nested = ObjectValue::from_pairs([(
+            "address",
+            Value::Object(ObjectValue::from_pairs([("city", Value::string("Oslo"))])),
+        )]);
+        assert!(user.contains_subset(&nested));
+
+        let differing = ObjectValue::from_pairs([("age", Value::number(31))]);
+        assert!(!user.contains_subset(&differing));
+
+        let partial_array =
+            ObjectValue::from_pairs([("tags", Value::array(vec![Value::string("a")]))]);
+        assert!(!user.contains_subset(&partial_array));
+    }
+
     #[cfg(feature = "json")]
     #[test]
     fn test_json_conversion() {
@@ -971,6 +1665,84 @@ mod tests {
         assert_eq!(back, obj);
     }
 
+    #[test]
+    fn test_entry() {
+        let mut obj = ObjectValue::new();
+
+        *obj.entry("count".to_string()).or_insert(Value::number(1)) = Value::number(2);
+        assert_eq!(obj.get("count"), Some(&Value::number(2)));
+
+        obj.entry("count".to_string())
+            .and_modify(|v| *v = Value::number(10))
+            .or_insert_with(|| panic!("key is occupied"));
+        assert_eq!(obj.get("count"), Some(&Value::number(10)));
+
+        let mut counts = ObjectValue::new();
+        for word in "the cat saw the other cat the end".split(' ') {
+            counts
+                .entry(word.to_string())
+                .and_modify(|n| *n = Value::number(n.as_number().unwrap().as_i64().unwrap() + 1))
+                .or_insert(Value::number(1));
+        }
+        assert_eq!(counts.get("the"), Some(&Value::number(3)));
+        assert_eq!(counts.get("cat"), Some(&Value::number(2)));
+        assert_eq!(counts.get("end"), Some(&Value::number(1)));
+        assert_eq!(counts.len(), 5);
+    }
+
+    #[test]
+    fn test_rename_key_errors() {
+        let mut obj = ObjectValue::from_pairs([("a", Value::number(1)), ("b", Value::number(2))]);
+
+        let missing = obj.rename_key("missing", "c");
+        assert!(matches!(missing, Err(ValueError::KeyNotFound { .. })));
+
+        assert!(obj.rename_key("a", "b").is_err());
+        assert_eq!(obj.get("a"), Some(&Value::number(1)));
+        assert_eq!(obj.get("b"), Some(&Value::number(2)));
+    }
+
+    #[cfg(feature = "collections")]
+    #[test]
+    fn test_rename_key_preserves_order() {
+        let mut obj = ObjectValue::from_pairs([
+            ("id", Value::number(1)),
+            ("full_name", Value::string("Ada")),
+            ("email", Value::string("ada@example.com")),
+        ]);
+
+        obj.rename_key("full_name", "name").unwrap();
+        assert_eq!(obj.key_names(), vec!["id", "name", "email"]);
+        assert_eq!(obj.get("name"), Some(&Value::string("Ada")));
+
+        let mapping = HashMap::from([
+            ("id".to_string(), "email".to_string()),
+            ("email".to_string(), "id".to_string()),
+        ]);
+        obj.rename_keys(&mapping).unwrap();
+        assert_eq!(obj.key_names(), vec!["email", "name", "id"]);
+        assert_eq!(obj.get("email"), Some(&Value::number(1)));
+    }
+
+    #[cfg(feature = "collections")]
+    #[test]
+    fn test_sort_keys() {
+        let mut obj = ObjectValue::from_pairs([
+            ("charlie", Value::number(3)),
+            ("alpha", Value::number(1)),
+            ("bo", Value::number(2)),
+        ]);
+
+        assert_eq!(obj.sorted_keys().key_names(), vec!["alpha", "bo", "charlie"]);
+        assert_eq!(obj.key_names(), vec!["charlie", "alpha", "bo"]);
+
+        obj.sort_keys();
+        assert_eq!(obj.key_names(), vec!["alpha", "bo", "charlie"]);
+
+        obj.sort_keys_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        assert_eq!(obj.key_names(), vec!["bo", "alpha", "charlie"]);
+    }
+
     #[cfg(feature = "collections")]
     #[test]
     fn test_indexed_access() {
@@ -2,7 +2,7 @@ use std::fmt;
 use std::hash::Hash;
 use std::str::FromStr;
 
-use regex::{Captures, Regex};
+use regex::{Captures, Regex, RegexSet};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -197,6 +197,9 @@ impl RegexValue {
     }
 
     /// Finds the first match in the text
+    ///
+    /// `start` and `end` are byte offsets into `text` and always fall on
+    /// UTF-8 character boundaries, so `&text[m.start..m.end]` is valid.
     #[must_use]
     pub fn find(&self, text: &str) -> Option<RegexMatch> {
         self.compiled.find(text).map(|m| RegexMatch {
@@ -206,7 +209,10 @@ impl RegexValue {
         })
     }
 
-    /// Finds all matches in the text
+    /// Finds all non-overlapping matches in the text, in order
+    ///
+    /// Offsets are byte offsets, as for [`RegexValue::find`]. Returns an empty
+    /// vector when nothing matches.
     #[must_use]
     pub fn find_all(&self, text: &str) -> Vec<RegexMatch> {
         self.compiled
@@ -215,6 +221,23 @@ impl RegexValue {
             .collect()
     }
 
+    /// Counts the non-overlapping matches in the text
+    #[must_use]
+    pub fn match_count(&self, text: &str) -> usize {
+        self.compiled.find_iter(text).count()
+    }
+
+    /// Tests whether any of several patterns matches the text
+    ///
+    /// The patterns are compiled together into a single [`RegexSet`], so the
+    /// text is scanned once and matching stops as soon as any pattern matches.
+    /// Errors if any pattern is invalid.
+    pub fn match_any(patterns: &[&str], text: &str) -> ValueResult<bool> {
+        let set = RegexSet::new(patterns)
+            .map_err(|e| ValueError::custom(format!("Invalid regex set {patterns:?}: {e}")))?;
+        Ok(set.is_match(text))
+    }
+
     /// Captures the first match with groups
     #[must_use]
     pub fn captures(&self, text: &str) -> Option<RegexCaptures> {
@@ -367,6 +390,8 @@ impl RegexValue {
 // === Helper Structures ===
 
 /// Represents a regex match with position information
+///
+/// `start` and `end` are byte offsets on UTF-8 character boundaries.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RegexMatch {
@@ -524,6 +549,44 @@ mod tests {
         assert_eq!(matches[1].text, "456");
     }
 
+    #[test]
+    fn test_match_count_and_any() {
+        let regex = RegexValue::new("ab").unwrap();
+        assert_eq!(regex.match_count("ab ab xab"), 3);
+        assert_eq!(regex.match_count("nothing"), 0);
+        // Matches don't overlap
+        assert_eq!(RegexValue::new("aa").unwrap().match_count("aaaaa"), 2);
+
+        assert!(RegexValue::match_any(&[r"spam", r"\d{3}"], "spam and eggs").unwrap());
+        assert!(RegexValue::match_any(&[r"spam", r"\d{3}"], "call 555").unwrap());
+        assert!(!RegexValue::match_any(&[r"spam", r"\d{3}"], "eggs").unwrap());
+        assert!(!RegexValue::match_any(&[], "anything").unwrap());
+        assert!(RegexValue::match_any(&["(unclosed"], "text").is_err());
+    }
+
+    #[test]
+    fn test_match_spans() {
+        let regex = RegexValue::new(r"\d+").unwrap();
+
+        let spans: Vec<_> =
+            regex.find_all("a1 bb22 c333").into_iter().map(|m| (m.start, m.end, m.text)).collect();
+        assert_eq!(
+            spans,
+            vec![(1, 2, "1".to_string()), (5, 7, "22".to_string()), (9, 12, "333".to_string())]
+        );
+        assert!(regex.find_all("no digits").is_empty());
+        assert_eq!(regex.find("no digits"), None);
+
+        // "é" and "ü" are two bytes each, so byte offsets run ahead of char offsets
+        let text = "café über 42";
+        let word = RegexValue::new(r"über").unwrap().find(text).unwrap();
+        assert_eq!((word.start, word.end), (6, 11));
+        assert_eq!(&text[word.start..word.end], "über");
+        let number = regex.find(text).unwrap();
+        assert_eq!((number.start, number.end), (12, 14));
+        assert_eq!(text[..number.start].chars().count(), 10);
+    }
+
     #[test]
     fn test_captures() {
         let regex = RegexValue::new(r"(\d{4})-(\d{2})-(\d{2})").unwrap();
@@ -1,18 +1,37 @@
 use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, Deref, DerefMut};
 use std::str::FromStr;
+use std::sync::Arc;
+#[cfg(feature = "intern")]
+use std::sync::OnceLock;
 
+#[cfg(feature = "intern")]
+use dashmap::DashMap;
+use regex::Regex;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::{ValueError, ValueResult};
+use crate::{ArrayValue, ObjectValue, Value, ValueError, ValueResult};
 
 /// String value type with efficient operations and conversions
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
-pub struct StringValue(String);
+///
+/// Values are normally backed by an owned `String`. Interned values (see
+/// `StringValue::interned`, behind the `intern` feature) share their storage
+/// and are copied on first mutation. Equality, ordering, hashing, `Display`
+/// and serialization only look at the contents.
+#[derive(Clone)]
+pub struct StringValue(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Owned(String),
+    Shared(Arc<str>),
+}
 
 impl StringValue {
     // --- Constructors ---
@@ -21,28 +40,38 @@ impl StringValue {
     #[inline]
     #[must_use]
     pub const fn new(value: String) -> Self {
-        Self(value)
+        Self(Repr::Owned(value))
     }
 
     /// Creates a new string value from anything convertible to String
     #[inline]
     #[must_use]
     pub fn from_value(value: impl Into<String>) -> Self {
-        Self(value.into())
+        Self(Repr::Owned(value.into()))
     }
 
     /// Creates an empty string value (const)
     #[inline]
     #[must_use]
     pub const fn empty() -> Self {
-        Self(String::new())
+        Self(Repr::Owned(String::new()))
+    }
+
+    /// Creates a string value backed by shared storage
+    ///
+    /// Lets callers plug in their own interning; clones share `value` and the
+    /// first mutation copies it.
+    #[inline]
+    #[must_use]
+    pub fn from_shared(value: Arc<str>) -> Self {
+        Self(Repr::Shared(value))
     }
 
     /// Creates a string value with specified capacity
     #[inline]
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(String::with_capacity(capacity))
+        Self(Repr::Owned(String::with_capacity(capacity)))
     }
 
     // --- Basic Properties ---
@@ -51,35 +80,92 @@ impl StringValue {
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.as_str().is_empty()
     }
 
     /// Returns the length of the string in bytes
     #[inline]
     #[must_use]
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.as_str().len()
     }
 
     /// Returns the length of the string in characters
     #[inline]
     #[must_use]
     pub fn char_count(&self) -> usize {
-        self.0.chars().count()
+        self.as_str().chars().count()
+    }
+
+    /// Returns the length of the string in bytes; same as `len`
+    #[inline]
+    #[must_use]
+    pub fn byte_len(&self) -> usize {
+        LengthUnit::Bytes.count(self.as_str())
+    }
+
+    /// Returns the length of the string in Unicode scalar values (`char`s);
+    /// same as `char_count`
+    #[inline]
+    #[must_use]
+    pub fn char_len(&self) -> usize {
+        LengthUnit::Chars.count(self.as_str())
+    }
+
+    /// Returns the length of the string in extended grapheme clusters, i.e.
+    /// user-perceived characters
+    ///
+    /// A family emoji joined with zero-width joiners or a letter followed by
+    /// a combining accent counts as one.
+    #[inline]
+    #[must_use]
+    pub fn grapheme_len(&self) -> usize {
+        LengthUnit::Graphemes.count(self.as_str())
+    }
+
+    /// Returns the length of the string counted in `unit`
+    #[inline]
+    #[must_use]
+    pub fn len_in(&self, unit: LengthUnit) -> usize {
+        unit.count(self.as_str())
     }
 
     /// Returns the string as a &str
     #[inline]
     #[must_use]
     pub fn as_str(&self) -> &str {
-        &self.0
+        match &self.0 {
+            Repr::Owned(s) => s,
+            Repr::Shared(s) => s,
+        }
     }
 
     /// Converts to owned String, consuming self
     #[inline]
     #[must_use = "consuming self usually means its value is needed"]
     pub fn into_string(self) -> String {
-        self.0
+        match self.0 {
+            Repr::Owned(s) => s,
+            Repr::Shared(s) => s.to_string(),
+        }
+    }
+
+    /// Returns true if the storage is shared with other interned values
+    #[inline]
+    #[must_use]
+    pub fn is_shared(&self) -> bool {
+        matches!(self.0, Repr::Shared(_))
+    }
+
+    /// Returns the owned string, copying shared storage first
+    fn make_mut(&mut self) -> &mut String {
+        if let Repr::Shared(shared) = &self.0 {
+            self.0 = Repr::Owned(shared.to_string());
+        }
+        match &mut self.0 {
+            Repr::Owned(s) => s,
+            Repr::Shared(_) => unreachable!("shared storage was just made owned"),
+        }
     }
 
     // --- Modification (in-place) ---
@@ -87,25 +173,25 @@ impl StringValue {
     /// Appends a string slice to this string
     #[inline]
     pub fn push_str(&mut self, string: &str) {
-        self.0.push_str(string);
+        self.make_mut().push_str(string);
     }
 
     /// Appends a character to this string
     #[inline]
     pub fn push(&mut self, ch: char) {
-        self.0.push(ch);
+        self.make_mut().push(ch);
     }
 
     /// Truncates this string to the specified length
     #[inline]
     pub fn truncate(&mut self, new_len: usize) {
-        self.0.truncate(new_len);
+        self.make_mut().truncate(new_len);
     }
 
     /// Clears the string, removing all contents
     #[inline]
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.make_mut().clear();
     }
 
     // --- Transformations (returning new StringValue) ---
@@ -114,31 +200,31 @@ impl StringValue {
     #[inline]
     #[must_use = "the trimmed string slice should be used"]
     pub fn trim(&self) -> &str {
-        self.0.trim()
+        self.as_str().trim()
     }
 
     /// Creates a StringValue with trimmed content
     #[must_use = "the new trimmed string should be used"]
     pub fn trimmed(&self) -> StringValue {
-        StringValue::new(self.0.trim().to_string())
+        StringValue::new(self.as_str().trim().to_string())
     }
 
     /// Converts to lowercase
     #[must_use = "the new lowercase string should be used"]
     pub fn to_lowercase(&self) -> StringValue {
-        StringValue::new(self.0.to_lowercase())
+        StringValue::new(self.as_str().to_lowercase())
     }
 
     /// Converts to uppercase
     #[must_use = "the new uppercase string should be used"]
     pub fn to_uppercase(&self) -> StringValue {
-        StringValue::new(self.0.to_uppercase())
+        StringValue::new(self.as_str().to_uppercase())
     }
 
     /// Capitalizes the first character
     #[must_use]
     pub fn capitalize(&self) -> StringValue {
-        let mut chars = self.0.chars();
+        let mut chars = self.as_str().chars();
         match chars.next() {
             None => StringValue::empty(),
             Some(first) => {
@@ -149,65 +235,97 @@ impl StringValue {
         }
     }
 
+    /// Returns the string in the given Unicode normalization form
+    #[must_use = "the new normalized string should be used"]
+    pub fn normalize(&self, form: NormalizationForm) -> StringValue {
+        StringValue::new(form.apply(self.as_str()).collect::<String>())
+    }
+
     // --- Search and Comparison ---
 
+    /// Checks equality ignoring case, using full Unicode case folding
+    ///
+    /// Folding is locale-independent, so `"ß"` equals `"SS"` but the Turkish
+    /// dotted `"İ"` only equals `"i\u{307}"`, not a plain `"i"`.
+    #[must_use = "the boolean result should be used"]
+    pub fn eq_ignore_case(&self, other: &str) -> bool {
+        case_fold(self.as_str().chars()).eq(case_fold(other.chars()))
+    }
+
+    /// Checks equality after NFC normalization of both strings, optionally
+    /// ignoring case as in [`StringValue::eq_ignore_case`]
+    ///
+    /// Composed and decomposed forms of the same text (e.g. `"é"` and
+    /// `"e\u{301}"`) compare equal.
+    #[must_use = "the boolean result should be used"]
+    pub fn eq_normalized(&self, other: &str, ignore_case: bool) -> bool {
+        if ignore_case {
+            // Folding can produce decomposed sequences, so normalize again
+            let left: String = case_fold(self.as_str().nfc()).collect();
+            let right: String = case_fold(other.nfc()).collect();
+            left.nfc().eq(right.nfc())
+        } else {
+            self.as_str().nfc().eq(other.nfc())
+        }
+    }
+
     /// Checks if string contains a pattern
     #[inline]
     #[must_use = "the boolean result should be used"]
     pub fn contains(&self, pattern: &str) -> bool {
-        self.0.contains(pattern)
+        self.as_str().contains(pattern)
     }
 
     /// Checks if string starts with a pattern
     #[inline]
     #[must_use = "the boolean result should be used"]
     pub fn starts_with(&self, pattern: &str) -> bool {
-        self.0.starts_with(pattern)
+        self.as_str().starts_with(pattern)
     }
 
     /// Checks if string ends with a pattern
     #[inline]
     #[must_use = "the boolean result should be used"]
     pub fn ends_with(&self, pattern: &str) -> bool {
-        self.0.ends_with(pattern)
+        self.as_str().ends_with(pattern)
     }
 
     /// Finds the first occurrence of a pattern
     #[inline]
     #[must_use]
     pub fn find(&self, pattern: &str) -> Option<usize> {
-        self.0.find(pattern)
+        self.as_str().find(pattern)
     }
 
     /// Finds the last occurrence of a pattern
     #[inline]
     #[must_use]
     pub fn rfind(&self, pattern: &str) -> Option<usize> {
-        self.0.rfind(pattern)
+        self.as_str().rfind(pattern)
     }
 
     /// Removes prefix if present
     #[must_use = "the optional new string should be used"]
     pub fn strip_prefix(&self, prefix: &str) -> Option<StringValue> {
-        self.0.strip_prefix(prefix).map(|s| StringValue::new(s.to_string()))
+        self.as_str().strip_prefix(prefix).map(|s| StringValue::new(s.to_string()))
     }
 
     /// Removes suffix if present
     #[must_use = "the optional new string should be used"]
     pub fn strip_suffix(&self, suffix: &str) -> Option<StringValue> {
-        self.0.strip_suffix(suffix).map(|s| StringValue::new(s.to_string()))
+        self.as_str().strip_suffix(suffix).map(|s| StringValue::new(s.to_string()))
     }
 
     /// Replaces all matches of a pattern with another string
     #[must_use = "the new string with replacements should be used"]
     pub fn replace(&self, from: &str, to: &str) -> StringValue {
-        StringValue::new(self.0.replace(from, to))
+        StringValue::new(self.as_str().replace(from, to))
     }
 
     /// Replaces the first match of a pattern with another string
     #[must_use]
     pub fn replacen(&self, from: &str, to: &str, count: usize) -> StringValue {
-        StringValue::new(self.0.replacen(from, to, count))
+        StringValue::new(self.as_str().replacen(from, to, count))
     }
 
     // --- Splitting ---
@@ -215,19 +333,59 @@ impl StringValue {
     /// Splits the string by a pattern and returns a vector of StringValues
     #[must_use = "the vector of split strings should be used"]
     pub fn split(&self, pattern: &str) -> Vec<StringValue> {
-        self.0.split(pattern).map(|s| StringValue::new(s.to_string())).collect()
+        self.as_str().split(pattern).map(|s| StringValue::new(s.to_string())).collect()
     }
 
     /// Splits by whitespace
     #[must_use = "the vector of split strings should be used"]
     pub fn split_whitespace(&self) -> Vec<StringValue> {
-        self.0.split_whitespace().map(|s| StringValue::new(s.to_string())).collect()
+        self.as_str().split_whitespace().map(|s| StringValue::new(s.to_string())).collect()
     }
 
     /// Splits into lines
     #[must_use]
     pub fn lines(&self) -> Vec<StringValue> {
-        self.0.lines().map(|s| StringValue::new(s.to_string())).collect()
+        self.as_str().lines().map(|s| StringValue::new(s.to_string())).collect()
+    }
+
+    /// Splits the string on every match of a regex pattern
+    ///
+    /// Returns an error if the pattern is not a valid regex
+    pub fn split_regex(&self, pattern: &str) -> ValueResult<ArrayValue> {
+        let regex = Self::compile_regex(pattern)?;
+        Ok(regex.split(self.as_str()).map(Value::string).collect())
+    }
+
+    /// Splits on a regex pattern into at most `limit` pieces
+    ///
+    /// The last piece holds the unsplit remainder of the string
+    pub fn split_regex_n(&self, pattern: &str, limit: usize) -> ValueResult<ArrayValue> {
+        let regex = Self::compile_regex(pattern)?;
+        Ok(regex.splitn(self.as_str(), limit).map(Value::string).collect())
+    }
+
+    /// Splits on a regex pattern, keeping the matched separators
+    ///
+    /// Pieces and separators alternate in the result, starting and ending
+    /// with a (possibly empty) piece: `"a, b"` split on `,\s*` yields
+    /// `["a", ", ", "b"]`. Zero-width matches produce empty separators.
+    pub fn split_regex_keep(&self, pattern: &str) -> ValueResult<ArrayValue> {
+        let regex = Self::compile_regex(pattern)?;
+        let mut parts = ArrayValue::new(Vec::new());
+        let mut last = 0;
+
+        for separator in regex.find_iter(self.as_str()) {
+            parts.push(Value::string(&self.as_str()[last..separator.start()]));
+            parts.push(Value::string(separator.as_str()));
+            last = separator.end();
+        }
+        parts.push(Value::string(&self.as_str()[last..]));
+
+        Ok(parts)
+    }
+
+    fn compile_regex(pattern: &str) -> ValueResult<Regex> {
+        Regex::new(pattern).map_err(|e| ValueError::invalid_regex(pattern, e.to_string()))
     }
 
     // --- Substring and Character Access ---
@@ -253,7 +411,7 @@ impl StringValue {
             return Ok(StringValue::empty());
         }
 
-        let substring: String = self.0.chars().skip(start).take(end - start).collect();
+        let substring: String = self.as_str().chars().skip(start).take(end - start).collect();
 
         Ok(StringValue::new(substring))
     }
@@ -269,9 +427,9 @@ impl StringValue {
             return self.clone();
         }
 
-        let mut chars = self.0.char_indices();
-        let end_byte = chars.nth(n).map(|(i, _)| i).unwrap_or(self.0.len());
-        StringValue::new(self.0[..end_byte].to_string())
+        let mut chars = self.as_str().char_indices();
+        let end_byte = chars.nth(n).map(|(i, _)| i).unwrap_or(self.as_str().len());
+        StringValue::new(self.as_str()[..end_byte].to_string())
     }
 
     /// Returns all characters after skipping n
@@ -285,14 +443,14 @@ impl StringValue {
             return StringValue::empty();
         }
 
-        let mut chars = self.0.char_indices();
-        let start_byte = chars.nth(n).map(|(i, _)| i).unwrap_or(self.0.len());
-        StringValue::new(self.0[start_byte..].to_string())
+        let mut chars = self.as_str().char_indices();
+        let start_byte = chars.nth(n).map(|(i, _)| i).unwrap_or(self.as_str().len());
+        StringValue::new(self.as_str()[start_byte..].to_string())
     }
 
     /// Safe character access by index
     pub fn char_at(&self, index: usize) -> ValueResult<char> {
-        self.0.chars().nth(index).ok_or_else(|| {
+        self.as_str().chars().nth(index).ok_or_else(|| {
             ValueError::custom(format!(
                 "Character index {} out of bounds for string of length {}",
                 index,
@@ -309,7 +467,7 @@ impl StringValue {
         T: FromStr,
         T::Err: std::fmt::Display,
     {
-        self.0.parse::<T>().map_err(|e| ValueError::custom(format!("Parse error: {e}")))
+        self.as_str().parse::<T>().map_err(|e| ValueError::custom(format!("Parse error: {e}")))
     }
 
     /// Explicit parse with type annotation (alias for parse)
@@ -337,13 +495,13 @@ impl StringValue {
     /// Checks if the string is alphabetic
     #[must_use]
     pub fn is_alphabetic(&self) -> bool {
-        !self.0.is_empty() && self.0.chars().all(|c| c.is_alphabetic())
+        !self.as_str().is_empty() && self.as_str().chars().all(|c| c.is_alphabetic())
     }
 
     /// Checks if the string is alphanumeric
     #[must_use]
     pub fn is_alphanumeric(&self) -> bool {
-        !self.0.is_empty() && self.0.chars().all(|c| c.is_alphanumeric())
+        !self.as_str().is_empty() && self.as_str().chars().all(|c| c.is_alphanumeric())
     }
 
     // --- Advanced Operations ---
@@ -351,13 +509,13 @@ impl StringValue {
     /// Repeats the string n times
     #[must_use]
     pub fn repeat(&self, n: usize) -> StringValue {
-        StringValue::new(self.0.repeat(n))
+        StringValue::new(self.as_str().repeat(n))
     }
 
     /// Reverses the string (character-wise)
     #[must_use]
     pub fn reverse(&self) -> StringValue {
-        StringValue::new(self.0.chars().rev().collect())
+        StringValue::new(self.as_str().chars().rev().collect())
     }
 
     /// Pads the string to a specified width with spaces
@@ -367,7 +525,7 @@ impl StringValue {
             self.clone()
         } else {
             let padding = " ".repeat(width - self.char_count());
-            StringValue::new(format!("{}{}", padding, self.0))
+            StringValue::new(format!("{}{}", padding, self.as_str()))
         }
     }
 
@@ -378,7 +536,7 @@ impl StringValue {
             self.clone()
         } else {
             let padding = " ".repeat(width - self.char_count());
-            StringValue::new(format!("{}{}", self.0, padding))
+            StringValue::new(format!("{}{}", self.as_str(), padding))
         }
     }
 
@@ -397,47 +555,207 @@ impl StringValue {
         StringValue::new(format!(
             "{}{}{}",
             " ".repeat(left_padding),
-            self.0,
+            self.as_str(),
             " ".repeat(right_padding)
         ))
     }
+
+    // === Interpolation ===
+
+    /// Replaces `${key}` placeholders with values from `vars`
+    ///
+    /// Keys may be dot paths (`${user.name}`) resolved with
+    /// [`ObjectValue::get_nested`]. Placeholders whose key is not found are
+    /// left as written; use [`try_interpolate`](Self::try_interpolate) to
+    /// treat them as errors.
+    #[must_use]
+    pub fn interpolate(&self, vars: &ObjectValue) -> StringValue {
+        self.interpolate_inner(vars, false).unwrap_or_else(|_| self.clone())
+    }
+
+    /// Like [`interpolate`](Self::interpolate), but errors on the first
+    /// placeholder whose key is not found in `vars`
+    pub fn try_interpolate(&self, vars: &ObjectValue) -> ValueResult<StringValue> {
+        self.interpolate_inner(vars, true)
+    }
+
+    fn interpolate_inner(&self, vars: &ObjectValue, strict: bool) -> ValueResult<StringValue> {
+        let mut result = String::with_capacity(self.len());
+        let mut rest = self.as_str();
+
+        while let Some(start) = rest.find("${") {
+            let Some(len) = rest[start + 2..].find('}') else {
+                break;
+            };
+            let placeholder = &rest[start..start + 2 + len + 1];
+            let key = placeholder[2..placeholder.len() - 1].trim();
+
+            result.push_str(&rest[..start]);
+            match vars.get_nested(key) {
+                Some(value) => result.push_str(&value.display_string()),
+                None if strict => return Err(ValueError::key_not_found(key)),
+                None => result.push_str(placeholder),
+            }
+            rest = &rest[start + placeholder.len()..];
+        }
+
+        result.push_str(rest);
+        Ok(StringValue::new(result))
+    }
+}
+
+/// Unicode normalization forms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NormalizationForm {
+    /// Canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+impl NormalizationForm {
+    fn apply<'a>(self, s: &'a str) -> Box<dyn Iterator<Item = char> + 'a> {
+        match self {
+            Self::Nfc => Box::new(s.nfc()),
+            Self::Nfd => Box::new(s.nfd()),
+            Self::Nfkc => Box::new(s.nfkc()),
+            Self::Nfkd => Box::new(s.nfkd()),
+        }
+    }
+}
+
+/// Unit in which a string's length is measured
+///
+/// Defaults to `Chars`. `Bytes` is the UTF-8 encoded size and `Graphemes`
+/// counts extended grapheme clusters, which is what users see as characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LengthUnit {
+    /// UTF-8 bytes
+    Bytes,
+    /// Unicode scalar values
+    #[default]
+    Chars,
+    /// Extended grapheme clusters
+    Graphemes,
+}
+
+impl LengthUnit {
+    /// Returns the length of `s` in this unit
+    #[must_use]
+    pub fn count(self, s: &str) -> usize {
+        match self {
+            Self::Bytes => s.len(),
+            Self::Chars => s.chars().count(),
+            Self::Graphemes => s.graphemes(true).count(),
+        }
+    }
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Bytes => "bytes",
+            Self::Chars => "characters",
+            Self::Graphemes => "graphemes",
+        })
+    }
+}
+
+/// Case-folds characters by mapping through uppercase then lowercase
+fn case_fold(chars: impl Iterator<Item = char>) -> impl Iterator<Item = char> {
+    chars.flat_map(char::to_uppercase).flat_map(char::to_lowercase)
 }
 
 // --- Trait Implementations ---
 
+impl fmt::Debug for StringValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StringValue").field(&self.as_str()).finish()
+    }
+}
+
+impl PartialEq for StringValue {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for StringValue {}
+
+impl Hash for StringValue {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialOrd for StringValue {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StringValue {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for StringValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for StringValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
+
 impl Deref for StringValue {
-    type Target = String;
+    type Target = str;
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        &self.0
+        self.as_str()
     }
 }
 
 impl DerefMut for StringValue {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        self.make_mut().as_mut_str()
     }
 }
 
 impl AsRef<str> for StringValue {
     #[inline]
     fn as_ref(&self) -> &str {
-        &self.0
-    }
-}
-
-impl AsRef<String> for StringValue {
-    #[inline]
-    fn as_ref(&self) -> &String {
-        &self.0
+        self.as_str()
     }
 }
 
 impl fmt::Display for StringValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        f.write_str(self.as_str())
     }
 }
 
@@ -454,7 +772,7 @@ impl FromStr for StringValue {
 impl From<String> for StringValue {
     #[inline]
     fn from(value: String) -> Self {
-        Self(value)
+        Self::new(value)
     }
 }
 
@@ -477,7 +795,7 @@ impl From<Cow<'_, str>> for StringValue {
     fn from(value: Cow<'_, str>) -> Self {
         match value {
             Cow::Borrowed(s) => Self::new(s.to_string()),
-            Cow::Owned(s) => Self(s),
+            Cow::Owned(s) => Self::new(s),
         }
     }
 }
@@ -486,14 +804,14 @@ impl From<Cow<'_, str>> for StringValue {
 impl From<StringValue> for String {
     #[inline]
     fn from(value: StringValue) -> Self {
-        value.0
+        value.into_string()
     }
 }
 
 impl From<StringValue> for Cow<'_, str> {
     #[inline]
     fn from(value: StringValue) -> Self {
-        Cow::Owned(value.0)
+        Cow::Owned(value.into_string())
     }
 }
 
@@ -507,7 +825,7 @@ impl Default for StringValue {
 impl Borrow<str> for StringValue {
     #[inline]
     fn borrow(&self) -> &str {
-        &self.0
+        self.as_str()
     }
 }
 
@@ -515,21 +833,21 @@ impl Borrow<str> for StringValue {
 impl PartialEq<str> for StringValue {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        self.0 == other
+        self.as_str() == other
     }
 }
 
 impl PartialEq<&str> for StringValue {
     #[inline]
     fn eq(&self, other: &&str) -> bool {
-        self.0 == *other
+        self.as_str() == *other
     }
 }
 
 impl PartialEq<String> for StringValue {
     #[inline]
     fn eq(&self, other: &String) -> bool {
-        &self.0 == other
+        self.as_str() == other
     }
 }
 
@@ -538,7 +856,7 @@ impl<T: Into<StringValue>> Add<T> for StringValue {
     type Output = StringValue;
 
     fn add(mut self, rhs: T) -> Self::Output {
-        self.0.push_str(&rhs.into().0);
+        self.make_mut().push_str(rhs.into().as_str());
         self
     }
 }
@@ -548,7 +866,7 @@ impl<T: Into<StringValue>> Add<T> for StringValue {
 impl From<StringValue> for serde_json::Value {
     #[inline]
     fn from(value: StringValue) -> Self {
-        serde_json::Value::String(value.0)
+        serde_json::Value::String(value.into_string())
     }
 }
 
@@ -586,6 +904,82 @@ impl<'a> FromIterator<&'a str> for StringValue {
     }
 }
 
+// --- Interning ---
+
+/// Pool of shared strings backing [`StringValue::interned`]
+///
+/// Interning the same text twice returns values sharing one allocation.
+/// Strings stay in the pool until it is dropped; values handed out remain
+/// valid afterwards. Use a scoped interner when the set of strings is
+/// unbounded, since the global one used by [`StringValue::interned`] is never
+/// cleared.
+#[cfg(feature = "intern")]
+#[derive(Debug, Default)]
+pub struct StringInterner {
+    strings: DashMap<InternKey, ()>,
+}
+
+/// Pool entry hashing and comparing as `str`, so lookups need no allocation
+#[cfg(feature = "intern")]
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct InternKey(Arc<str>);
+
+#[cfg(feature = "intern")]
+impl Borrow<str> for InternKey {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "intern")]
+impl StringInterner {
+    /// Creates an empty interner
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a value sharing storage with every other value interned here
+    /// from the same text
+    pub fn intern(&self, s: &str) -> StringValue {
+        let shared = match self.strings.get(s) {
+            Some(entry) => Arc::clone(&entry.key().0),
+            // Another thread may intern the same text meanwhile; `entry` keeps
+            // whichever copy landed first
+            None => {
+                let entry = self.strings.entry(InternKey(Arc::from(s))).or_default();
+                Arc::clone(&entry.key().0)
+            },
+        };
+        StringValue::from_shared(shared)
+    }
+
+    /// Number of distinct strings in the pool
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if nothing has been interned
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(feature = "intern")]
+impl StringValue {
+    /// Creates a string value from the process-wide interner
+    ///
+    /// Meant for small, frequently repeated strings such as enum-like field
+    /// values; clones are cheap and mutation copies the text out first.
+    #[must_use]
+    pub fn interned(s: &str) -> Self {
+        static GLOBAL: OnceLock<StringInterner> = OnceLock::new();
+        GLOBAL.get_or_init(StringInterner::new).intern(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -607,6 +1001,62 @@ mod tests {
         assert_eq!(s3.as_str(), "test");
     }
 
+    #[test]
+    fn test_split_regex() {
+        let s = StringValue::from("a  b\tc\n d");
+        let parts = s.split_regex(r"\s+").unwrap();
+        assert_eq!(
+            parts.into_vec(),
+            vec![Value::string("a"), Value::string("b"), Value::string("c"), Value::string("d")]
+        );
+
+        let parts = s.split_regex_n(r"\s+", 2).unwrap();
+        assert_eq!(parts.into_vec(), vec![Value::string("a"), Value::string("b\tc\n d")]);
+
+        let parts = StringValue::from("a,b;c").split_regex_keep("[,;]").unwrap();
+        assert_eq!(parts.len(), 5);
+        assert_eq!(parts[1], Value::string(","));
+        assert_eq!(parts[3], Value::string(";"));
+
+        // Zero-width patterns terminate
+        let parts = StringValue::from("abc").split_regex("").unwrap();
+        assert_eq!(parts.len(), 5);
+        let parts = StringValue::from("abc").split_regex_keep("").unwrap();
+        assert_eq!(parts.len(), 9);
+
+        let err = s.split_regex("(unclosed").unwrap_err();
+        assert!(matches!(err, ValueError::InvalidRegex { .. }));
+    }
+
+    #[test]
+    fn test_case_insensitive_eq() {
+        let s = StringValue::from("Hello World");
+        assert!(s.eq_ignore_case("hello world"));
+        assert!(s.eq_ignore_case("HELLO WORLD"));
+        assert!(!s.eq_ignore_case("hello"));
+
+        assert!(StringValue::from("Straße").eq_ignore_case("STRASSE"));
+
+        // Locale-independent folding: dotted capital I keeps its dot
+        let istanbul = StringValue::from("İstanbul");
+        assert!(istanbul.eq_ignore_case("i\u{307}stanbul"));
+        assert!(!istanbul.eq_ignore_case("istanbul"));
+    }
+
+    #[test]
+    fn test_normalized_eq() {
+        let composed = StringValue::from("Caf\u{e9}");
+        let decomposed = "Cafe\u{301}";
+        assert_ne!(composed.as_str(), decomposed);
+        assert!(composed.eq_normalized(decomposed, false));
+        assert!(!composed.eq_normalized("cafe\u{301}", false));
+        assert!(composed.eq_normalized("CAFE\u{301}", true));
+        assert!(!composed.eq_normalized("cafe", true));
+
+        assert_eq!(StringValue::from(decomposed).normalize(NormalizationForm::Nfc), composed);
+        assert_eq!(composed.normalize(NormalizationForm::Nfd).as_str(), decomposed);
+    }
+
     #[test]
     fn test_strip_prefix_suffix() {
         let s = StringValue::from("Hello, world!");
@@ -751,6 +1201,25 @@ mod tests {
         assert_eq!(s2.as_str(), "hello world");
     }
 
+    #[test]
+    fn test_unicode_lengths() {
+        // man, woman, girl, boy joined by zero-width joiners
+        let family = ["\u{1F468}", "\u{1F469}", "\u{1F467}", "\u{1F466}"].join("\u{200D}");
+        let family = StringValue::from(family);
+        assert_eq!(family.grapheme_len(), 1);
+        assert_eq!(family.char_len(), 7);
+        assert_eq!(family.byte_len(), 25);
+        assert_eq!(family.len_in(LengthUnit::Bytes), family.len());
+
+        let accented = StringValue::from("e\u{301}");
+        assert_eq!(accented.grapheme_len(), 1);
+        assert_eq!(accented.char_len(), 2);
+        assert_eq!(accented.byte_len(), 3);
+
+        assert_eq!(LengthUnit::default(), LengthUnit::Chars);
+        assert_eq!(StringValue::empty().grapheme_len(), 0);
+    }
+
     #[test]
     fn test_validation() {
         let numeric = StringValue::from("123.45");
@@ -785,4 +1254,55 @@ mod tests {
         assert_eq!(s.rfind("hello"), Some(12));
         assert_eq!(s.find("xyz"), None);
     }
+
+    #[test]
+    fn test_interpolate() {
+        let mut user = ObjectValue::new();
+        user.insert("name".to_string(), Value::string("Ada"));
+        let mut vars = ObjectValue::new();
+        vars.insert("count".to_string(), Value::number(3));
+        vars.insert("user".to_string(), Value::object(user));
+
+        let s = StringValue::from("Processed ${count} items");
+        assert_eq!(s.interpolate(&vars).as_str(), "Processed 3 items");
+
+        let s = StringValue::from("Hello, ${ user.name }!");
+        assert_eq!(s.interpolate(&vars).as_str(), "Hello, Ada!");
+
+        let s = StringValue::from("${user.name} has ${unknown} and ${open");
+        assert_eq!(s.interpolate(&vars).as_str(), "Ada has ${unknown} and ${open");
+        assert!(s.try_interpolate(&vars).is_err());
+        assert_eq!(
+            StringValue::from("${user.name}").try_interpolate(&vars).unwrap().as_str(),
+            "Ada"
+        );
+    }
+
+    #[cfg(feature = "intern")]
+    #[test]
+    fn test_interned() {
+        let a = StringValue::interned("pending");
+        let b = StringValue::interned("pending");
+        assert!(a.is_shared());
+        assert_eq!(a.as_str().as_ptr(), b.as_str().as_ptr());
+        assert_eq!(a, b);
+
+        let owned = StringValue::from("pending");
+        assert!(!owned.is_shared());
+        assert_eq!(a, owned);
+        assert_eq!(a.cmp(&owned), Ordering::Equal);
+        assert_eq!(a.to_string(), owned.to_string());
+
+        let mut mutated = b.clone();
+        mutated.push_str("!");
+        assert_eq!(mutated, "pending!");
+        assert_eq!(b, "pending");
+
+        let scoped = StringInterner::new();
+        let c = scoped.intern("pending");
+        assert_eq!(c, a);
+        assert_ne!(c.as_str().as_ptr(), a.as_str().as_ptr());
+        scoped.intern("done");
+        assert_eq!(scoped.len(), 2);
+    }
 }
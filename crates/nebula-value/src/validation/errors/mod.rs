@@ -65,9 +65,22 @@ pub enum ValidationError {
 
     #[error("Unsupported operation for field '{field}': {operation} cannot be applied to {value_type}")]
     UnsupportedOperation { field: String, operation: String, value_type: String },
+
+    #[error("Validation failed for field '{field}': {message}")]
+    Custom { field: String, message: String, transient: bool },
 }
 
 impl ValidationError {
+    /// Creates a failure reported by a custom validator
+    pub fn custom(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Custom { field: field.into(), message: message.into(), transient: false }
+    }
+
+    /// Creates a retryable failure, e.g. when a custom validator's lookup timed out
+    pub fn transient(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Custom { field: field.into(), message: message.into(), transient: true }
+    }
+
     /// Returns the field that caused the validation error
     pub fn field(&self) -> &String {
         match self {
@@ -83,6 +96,7 @@ impl ValidationError {
             Self::ValueConstraint { field, .. } => field,
             Self::TypeMismatch { field, .. } => field,
             Self::UnsupportedOperation { field, .. } => field,
+            Self::Custom { field, .. } => field,
         }
     }
 
@@ -101,6 +115,7 @@ impl ValidationError {
             Self::ValueConstraint { .. } => "value_constraint",
             Self::TypeMismatch { .. } => "type_mismatch",
             Self::UnsupportedOperation { .. } => "unsupported_operation",
+            Self::Custom { .. } => "custom",
         }
     }
 
@@ -115,7 +130,8 @@ impl ValidationError {
             Self::Emptiness { .. } |
             Self::CrossField { .. } |
             Self::Conditional { .. } |
-            Self::ValueConstraint { .. }
+            Self::ValueConstraint { .. } |
+            Self::Custom { transient: false, .. }
         )
     }
 
@@ -124,7 +140,51 @@ impl ValidationError {
         matches!(self,
             Self::TypeMismatch { .. } |
             Self::UnsupportedOperation { .. } |
-            Self::Logical { .. }
+            Self::Logical { .. } |
+            Self::Custom { transient: true, .. }
         )
     }
+
+    /// Returns true if the failure is temporary and validation may succeed on retry
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Custom { transient, .. } => *transient,
+            Self::ValueConstraint { error, .. } => error.is_transient(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transient() {
+        let err = ValidationError::transient("email", "Lookup service unavailable");
+        assert!(err.is_transient());
+        assert!(!err.is_user_error());
+        assert_eq!(err.category(), "custom");
+
+        assert!(!ValidationError::custom("email", "Already registered").is_transient());
+
+        let err = ValidationError::String {
+            field: "name".to_string(),
+            error: StringError::TooShort { actual: 2, min: 3 },
+        };
+        assert!(!err.is_transient());
+        assert!(err.is_user_error());
+
+        let err = ValidationError::ValueConstraint {
+            field: "avatar".to_string(),
+            error: ValueError::io(std::io::ErrorKind::TimedOut, "read timed out"),
+        };
+        assert!(err.is_transient());
+
+        let err = ValidationError::ValueConstraint {
+            field: "avatar".to_string(),
+            error: ValueError::file_operation_failed("file is too large"),
+        };
+        assert!(!err.is_transient());
+    }
 }
\ No newline at end of file
@@ -1,13 +1,18 @@
+use std::fmt::Write;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "serde")]
-use crate::error::ValueError;
+use crate::error::{ValueError, ValueResult};
 use crate::{
     ArrayValue, BinaryValue, BooleanValue, ColorValue, CronValue, DateTimeValue, DurationValue,
     ExpressionValue, FileValue, ModeValue, NumberValue, ObjectValue, RegexValue, StringValue,
 };
 
+/// Fixed bytes [`Value::estimated_size_bytes`] charges per array or object:
+/// the shared-storage reference counts plus the collection header
+const CONTAINER_OVERHEAD_BYTES: usize = 2 * size_of::<usize>() + size_of::<Vec<Value>>();
+
 /// The main Value enum representing all possible value types in Nebula
 ///
 /// This enum supports both tagged and untagged serialization depending on
@@ -281,8 +286,32 @@ impl Value {
         if let Self::Duration(d) = self { Some(d) } else { None }
     }
 
+    /// Returns the object field or array element addressed by `key`
+    ///
+    /// String keys index objects and `usize` keys index arrays; any other
+    /// combination returns `None`, so lookups chain without matching on the
+    /// variant: `value.get("users")?.get(0)?.get("name")`.
+    #[must_use]
+    pub fn get<'k>(&self, key: impl Into<ValueKey<'k>>) -> Option<&Value> {
+        match (self, key.into()) {
+            (Self::Object(object), ValueKey::Key(key)) => object.get(key),
+            (Self::Array(array), ValueKey::Index(index)) => array.get(index),
+            _ => None,
+        }
+    }
+
     // === Mutable accessor methods ===
 
+    /// Mutable counterpart of [`get`](Self::get)
+    #[must_use]
+    pub fn get_mut<'k>(&mut self, key: impl Into<ValueKey<'k>>) -> Option<&mut Value> {
+        match (self, key.into()) {
+            (Self::Object(object), ValueKey::Key(key)) => object.get_mut(key),
+            (Self::Array(array), ValueKey::Index(index)) => array.get_mut(index),
+            _ => None,
+        }
+    }
+
     /// Returns a mutable reference to the array value if this is an array
     #[inline]
     #[must_use]
@@ -344,13 +373,48 @@ impl Value {
         }
     }
 
+    /// Renders the value as indented, JSON-like text with size limits
+    ///
+    /// Intended for logs and debug output of values of unknown size:
+    ///
+    /// - arrays and objects show at most `max_items` entries, followed by an
+    ///   `... (N more)` marker
+    /// - arrays and objects nested deeper than `max_depth` levels render as
+    ///   `[...]` / `{...}`
+    /// - strings longer than `max_string` characters are cut and end in `...`
+    ///
+    /// Object keys are sorted so the output is stable.
+    #[must_use]
+    pub fn to_pretty_string_bounded(
+        &self,
+        max_items: usize,
+        max_depth: usize,
+        max_string: usize,
+    ) -> String {
+        let limits = PrettyLimits { max_items, max_depth, max_string };
+        let mut out = String::new();
+        write_bounded(self, &mut out, 0, &limits);
+        out
+    }
+
     /// Returns true if the value is considered "truthy"
+    ///
+    /// Matches the truthiness used by template conditionals:
+    ///
+    /// | Value                     | Truthy |
+    /// |---------------------------|--------|
+    /// | `null`                    | no     |
+    /// | `false`                   | no     |
+    /// | `0`, `0.0`, `NaN`         | no     |
+    /// | empty string              | no     |
+    /// | empty array / object      | no     |
+    /// | everything else           | yes    |
     #[must_use]
     pub fn is_truthy(&self) -> bool {
         match self {
             Self::Boolean(b) => **b,
-            Self::String(s) => !s.to_string().is_empty(),
-            Self::Number(n) => !n.is_zero(),
+            Self::String(s) => !s.is_empty(),
+            Self::Number(n) => !n.is_zero() && !n.is_nan(),
             Self::Array(a) => !a.is_empty(),
             Self::Object(o) => !o.is_empty(),
             Self::Null => false,
@@ -365,6 +429,381 @@ impl Value {
     pub fn is_falsy(&self) -> bool {
         !self.is_truthy()
     }
+
+    // === Coercion ===
+
+    /// Treats the value as "one or many": arrays are returned as is, null
+    /// becomes an empty array and any other value is wrapped in a
+    /// single-element array
+    #[must_use]
+    pub fn coerce_to_array(&self) -> ArrayValue {
+        match self {
+            Self::Array(array) => array.clone(),
+            Self::Null => ArrayValue::empty(),
+            other => ArrayValue::new(vec![other.clone()]),
+        }
+    }
+
+    /// Like [`coerce_to_array`](Self::coerce_to_array), but errors instead of
+    /// producing an empty array, i.e. for null or an empty array
+    pub fn coerce_to_array_nonempty(&self) -> ValueResult<ArrayValue> {
+        let array = self.coerce_to_array();
+        if array.is_empty() {
+            return Err(ValueError::type_conversion(self.type_name(), "non-empty array"));
+        }
+        Ok(array)
+    }
+
+    // === JSON Pointer (RFC 6901) ===
+
+    /// Looks up a value by JSON Pointer, e.g. `/users/0/name`
+    ///
+    /// The empty pointer refers to the whole value. Returns `None` if the
+    /// pointer is malformed or does not resolve.
+    #[must_use]
+    pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+        let tokens = parse_pointer(pointer).ok()?;
+        tokens.iter().try_fold(self, |current, token| match current {
+            Self::Object(obj) => obj.get(token),
+            Self::Array(arr) => arr.get(pointer_index(token, arr.len()).ok()?),
+            _ => None,
+        })
+    }
+
+    /// Sets a value by JSON Pointer
+    ///
+    /// Missing object members along the path are created as empty objects,
+    /// and a final `-` token appends to an array. The empty pointer replaces
+    /// the whole value. Errors if the path passes through a scalar, or if an
+    /// array index is malformed or not below the array's length.
+    pub fn pointer_set(&mut self, pointer: &str, value: Value) -> ValueResult<()> {
+        let tokens = parse_pointer(pointer)?;
+        let Some((last, parents)) = tokens.split_last() else {
+            *self = value;
+            return Ok(());
+        };
+
+        let mut current = self;
+        for token in parents {
+            current = match current {
+                Self::Object(obj) => {
+                    obj.entry(token.clone()).or_insert_with(|| Self::Object(ObjectValue::new()))
+                },
+                Self::Array(arr) => {
+                    let index = pointer_index(token, arr.len())?;
+                    &mut arr[index]
+                },
+                other => return Err(pointer_through_scalar(other, pointer)),
+            };
+        }
+
+        match current {
+            Self::Object(obj) => {
+                obj.insert(last.clone(), value);
+            },
+            Self::Array(arr) if last == "-" => arr.push(value),
+            Self::Array(arr) => {
+                let index = pointer_index(last, arr.len())?;
+                arr[index] = value;
+            },
+            other => return Err(pointer_through_scalar(other, pointer)),
+        }
+
+        Ok(())
+    }
+
+    // === Structural comparison ===
+
+    /// Compares two values structurally, skipping the given object keys at
+    /// every nesting level
+    ///
+    /// Useful for comparing outputs that carry volatile fields such as
+    /// `timestamp`. Array order matters; see
+    /// [`equals_ignoring_unordered`](Self::equals_ignoring_unordered).
+    #[must_use]
+    pub fn equals_ignoring(&self, other: &Value, ignore_keys: &[&str]) -> bool {
+        equals_ignoring(self, other, ignore_keys, false)
+    }
+
+    /// Like [`equals_ignoring`](Self::equals_ignoring), but arrays compare
+    /// equal when they hold the same elements in any order
+    ///
+    /// Array comparison is quadratic in the array length.
+    #[must_use]
+    pub fn equals_ignoring_unordered(&self, other: &Value, ignore_keys: &[&str]) -> bool {
+        equals_ignoring(self, other, ignore_keys, true)
+    }
+
+    /// Compares two values structurally, treating numbers within `epsilon`
+    /// of each other as equal
+    ///
+    /// Array order and object keys must match exactly; see
+    /// [`NumberValue::approx_eq`] for the number comparison.
+    #[must_use]
+    pub fn approx_equals(&self, other: &Value, epsilon: f64) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.approx_eq(b, epsilon),
+            (Self::Array(a), Self::Array(b)) => {
+                a.len() == b.len()
+                    && a.iter().zip(b.iter()).all(|(x, y)| x.approx_equals(y, epsilon))
+            },
+            (Self::Object(a), Self::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter().all(|(key, value)| {
+                        b.get(key).is_some_and(|other| value.approx_equals(other, epsilon))
+                    })
+            },
+            _ => self == other,
+        }
+    }
+
+    // === Structure metrics ===
+
+    /// Returns the maximum nesting level of arrays and objects
+    ///
+    /// Scalars have depth 0 and each enclosing array or object adds one, so
+    /// `{"a": {"b": 1}}` has depth 2. Traversal is iterative, so arbitrarily
+    /// deep input cannot overflow the stack.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        let mut max_depth = 0;
+        self.walk(|_, depth| {
+            max_depth = max_depth.max(depth);
+            true
+        });
+        max_depth
+    }
+
+    /// Returns the total number of values, counting every container and
+    /// every scalar inside it
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        let mut count = 0usize;
+        self.walk(|_, _| {
+            count = count.saturating_add(1);
+            true
+        });
+        count
+    }
+
+    /// Checks that [`depth`](Self::depth) and [`node_count`](Self::node_count)
+    /// do not exceed the given limits
+    ///
+    /// Stops at the first node that breaches either limit, so rejecting a
+    /// huge payload costs no more than `max_nodes` steps.
+    #[must_use]
+    pub fn within_limits(&self, max_depth: usize, max_nodes: usize) -> bool {
+        let mut count = 0usize;
+        self.walk(|_, depth| {
+            count += 1;
+            count <= max_nodes && depth <= max_depth
+        })
+    }
+
+    /// Estimates the memory this value occupies, in bytes
+    ///
+    /// A heuristic for byte budgets, not an exact figure. It counts the
+    /// inline size of every value, string and binary contents, object keys,
+    /// and a fixed overhead per array or object. Allocator slack, spare
+    /// capacity and the heap data of other types (expressions, regexes, ...)
+    /// are ignored, and storage shared between clones is counted in full by
+    /// each of them.
+    #[must_use]
+    pub fn estimated_size_bytes(&self) -> usize {
+        let mut size = 0usize;
+        self.walk(|value, _| {
+            let heap = match value {
+                Self::String(s) => s.len(),
+                Self::Binary(b) => b.len(),
+                Self::Array(_) => CONTAINER_OVERHEAD_BYTES,
+                Self::Object(object) => {
+                    let keys = object.keys().map(|key| size_of::<String>() + key.len());
+                    CONTAINER_OVERHEAD_BYTES + keys.sum::<usize>()
+                },
+                _ => 0,
+            };
+            size = size.saturating_add(size_of::<Value>() + heap);
+            true
+        });
+        size
+    }
+
+    /// Visits this value and all nested values depth-first with their nesting
+    /// depth, stopping early when `visit` returns false
+    ///
+    /// Returns false if the walk was stopped. Children are pulled lazily, so
+    /// stopping costs nothing for the elements not yet reached and the stack
+    /// grows with nesting depth rather than width.
+    fn walk<'a>(&'a self, mut visit: impl FnMut(&'a Value, usize) -> bool) -> bool {
+        type Children<'a> = Box<dyn Iterator<Item = &'a Value> + 'a>;
+        let mut stack: Vec<(Children<'a>, usize)> = vec![(Box::new(std::iter::once(self)), 0)];
+
+        while let Some((children, depth)) = stack.last_mut() {
+            let depth = *depth;
+            let Some(value) = children.next() else {
+                stack.pop();
+                continue;
+            };
+            if !visit(value, depth) {
+                return false;
+            }
+            match value {
+                Self::Array(array) if !array.is_empty() => {
+                    stack.push((Box::new(array.iter()), depth + 1));
+                },
+                Self::Object(object) if !object.is_empty() => {
+                    stack.push((Box::new(object.values()), depth + 1));
+                },
+                _ => {},
+            }
+        }
+
+        true
+    }
+}
+
+/// Drops `stack` and everything nested in it without recursing, so dropping
+/// a deeply nested value can't overflow the stack
+///
+/// Containers whose storage is shared with a clone are left to the clone.
+pub(crate) fn drop_nested(mut stack: Vec<Value>) {
+    while let Some(mut value) = stack.pop() {
+        match &mut value {
+            Value::Array(array) => array.take_unique_into(&mut stack),
+            Value::Object(object) => object.take_unique_into(&mut stack),
+            _ => {},
+        }
+    }
+}
+
+fn equals_ignoring(a: &Value, b: &Value, ignore_keys: &[&str], unordered: bool) -> bool {
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => {
+            let kept = |key: &&String| !ignore_keys.contains(&key.as_str());
+            a.keys().filter(kept).count() == b.keys().filter(kept).count()
+                && a.iter().filter(|(key, _)| kept(key)).all(|(key, value)| {
+                    b.get(key)
+                        .is_some_and(|other| equals_ignoring(value, other, ignore_keys, unordered))
+                })
+        },
+        (Value::Array(a), Value::Array(b)) if a.len() == b.len() => {
+            if !unordered {
+                return a
+                    .iter()
+                    .zip(b.iter())
+                    .all(|(x, y)| equals_ignoring(x, y, ignore_keys, unordered));
+            }
+            // Equality up to ignored keys is an equivalence, so greedy matching is exact
+            let mut matched = vec![false; b.len()];
+            a.iter().all(|x| {
+                let found = b.iter().enumerate().position(|(i, y)| {
+                    !matched[i] && equals_ignoring(x, y, ignore_keys, unordered)
+                });
+                found.map(|i| matched[i] = true).is_some()
+            })
+        },
+        (Value::Array(_), Value::Array(_)) => false,
+        _ => a == b,
+    }
+}
+
+/// Splits a JSON Pointer into unescaped reference tokens
+fn parse_pointer(pointer: &str) -> ValueResult<Vec<String>> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return Err(ValueError::invalid_format("JSON pointer", pointer));
+    };
+    Ok(rest.split('/').map(|token| token.replace("~1", "/").replace("~0", "~")).collect())
+}
+
+/// Parses an array index token, which must refer to an existing element
+fn pointer_index(token: &str, length: usize) -> ValueResult<usize> {
+    let valid = token == "0"
+        || (!token.is_empty()
+            && !token.starts_with('0')
+            && token.bytes().all(|b| b.is_ascii_digit()));
+    if !valid {
+        return Err(ValueError::invalid_format("JSON pointer array index", token));
+    }
+
+    let index = token
+        .parse::<usize>()
+        .map_err(|_| ValueError::invalid_format("JSON pointer array index", token))?;
+    if index >= length {
+        return Err(ValueError::index_out_of_bounds(index, length));
+    }
+    Ok(index)
+}
+
+fn pointer_through_scalar(value: &Value, pointer: &str) -> ValueError {
+    ValueError::custom(format!(
+        "Cannot set '{pointer}': path passes through a {} value",
+        value.type_name()
+    ))
+}
+
+/// Limits for [`Value::to_pretty_string_bounded`]
+struct PrettyLimits {
+    max_items: usize,
+    max_depth: usize,
+    max_string: usize,
+}
+
+fn write_bounded(value: &Value, out: &mut String, depth: usize, limits: &PrettyLimits) {
+    let indent = "  ".repeat(depth + 1);
+    match value {
+        Value::Array(array) if array.is_empty() => out.push_str("[]"),
+        Value::Object(object) if object.is_empty() => out.push_str("{}"),
+        Value::Array(_) if depth >= limits.max_depth => out.push_str("[...]"),
+        Value::Object(_) if depth >= limits.max_depth => out.push_str("{...}"),
+        Value::Array(array) => {
+            out.push('[');
+            for (i, item) in array.iter().take(limits.max_items).enumerate() {
+                out.push_str(if i == 0 { "\n" } else { ",\n" });
+                out.push_str(&indent);
+                write_bounded(item, out, depth + 1, limits);
+            }
+            write_remaining(out, &indent, array.len(), limits.max_items);
+            out.push('\n');
+            out.push_str(&"  ".repeat(depth));
+            out.push(']');
+        },
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().take(limits.max_items).enumerate() {
+                out.push_str(if i == 0 { "\n" } else { ",\n" });
+                out.push_str(&indent);
+                let _ = write!(out, "{key:?}: ");
+                write_bounded(item, out, depth + 1, limits);
+            }
+            write_remaining(out, &indent, object.len(), limits.max_items);
+            out.push('\n');
+            out.push_str(&"  ".repeat(depth));
+            out.push('}');
+        },
+        Value::String(s) => {
+            let s = s.as_str();
+            if s.chars().count() > limits.max_string {
+                let cut: String = s.chars().take(limits.max_string).collect();
+                let _ = write!(out, "{:?}", format!("{cut}..."));
+            } else {
+                let _ = write!(out, "{s:?}");
+            }
+        },
+        other => out.push_str(&other.display_string()),
+    }
+}
+
+/// Writes the `... (N more)` marker for a truncated array or object
+fn write_remaining(out: &mut String, indent: &str, len: usize, max_items: usize) {
+    if len > max_items {
+        let separator = if max_items == 0 { "\n" } else { ",\n" };
+        let _ = write!(out, "{separator}{indent}... ({} more)", len - max_items);
+    }
 }
 
 // === Display implementation ===
@@ -375,6 +814,106 @@ impl std::fmt::Display for Value {
     }
 }
 
+// === Navigation keys ===
+
+/// Object key or array index accepted by [`Value::get`] and
+/// [`Value::get_mut`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueKey<'a> {
+    /// Field name of an object
+    Key(&'a str),
+    /// Position in an array
+    Index(usize),
+}
+
+impl<'a> From<&'a str> for ValueKey<'a> {
+    fn from(key: &'a str) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl<'a> From<&'a String> for ValueKey<'a> {
+    fn from(key: &'a String) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<usize> for ValueKey<'_> {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+// === Parsing from strings ===
+
+/// Options for [`Value::parse_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Detect JSON arrays and objects; has no effect without the `json`
+    /// feature
+    pub detect_json: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self { detect_json: true }
+    }
+}
+
+impl Value {
+    /// Parses untyped text, detecting its type
+    ///
+    /// Detection is attempted on the trimmed input, in order:
+    ///
+    /// 1. `null`
+    /// 2. `true` / `false`
+    /// 3. integers that fit in an `i64`
+    /// 4. finite floats (`1.5`, `-2e3`; `inf` and `NaN` stay strings)
+    /// 5. JSON arrays and objects, when enabled and the `json` feature is on
+    ///
+    /// Anything else, including malformed JSON, becomes a string holding the
+    /// untrimmed input.
+    #[must_use]
+    pub fn parse_with(s: &str, options: ParseOptions) -> Self {
+        let trimmed = s.trim();
+        match trimmed {
+            "null" => return Self::Null,
+            "true" => return Self::boolean(true),
+            "false" => return Self::boolean(false),
+            _ => {},
+        }
+
+        if let Ok(i) = trimmed.parse::<i64>() {
+            return Self::number(i);
+        }
+        if let Some(f) = trimmed.parse::<f64>().ok().filter(|f| f.is_finite()) {
+            return Self::number(f);
+        }
+
+        #[cfg(feature = "json")]
+        if options.detect_json
+            && ((trimmed.starts_with('[') && trimmed.ends_with(']'))
+                || (trimmed.starts_with('{') && trimmed.ends_with('}')))
+        {
+            return Self::from_json_str(trimmed).unwrap_or_else(|_| Self::string(s));
+        }
+        #[cfg(not(feature = "json"))]
+        let _ = options;
+
+        Self::string(s)
+    }
+}
+
+/// Parses with [`ParseOptions::default`]; never fails, falling back to a
+/// string
+impl std::str::FromStr for Value {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> ValueResult<Self> {
+        Ok(Self::parse_with(s, ParseOptions::default()))
+    }
+}
+
 // === From implementations for basic types ===
 
 impl From<&str> for Value {
@@ -441,6 +980,131 @@ impl<T: Into<Value>> From<Option<T>> for Value {
     }
 }
 
+// === TryFrom implementations for basic types ===
+
+impl TryFrom<&Value> for i64 {
+    type Error = ValueError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(n) => n.as_i64(),
+            other => Err(ValueError::type_conversion(other.type_name(), "i64")),
+        }
+    }
+}
+
+impl TryFrom<Value> for i64 {
+    type Error = ValueError;
+
+    #[inline]
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&Value> for f64 {
+    type Error = ValueError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(n) => Ok(n.as_f64()),
+            other => Err(ValueError::type_conversion(other.type_name(), "f64")),
+        }
+    }
+}
+
+impl TryFrom<Value> for f64 {
+    type Error = ValueError;
+
+    #[inline]
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&Value> for bool {
+    type Error = ValueError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value.as_boolean().ok_or_else(|| ValueError::type_conversion(value.type_name(), "bool"))
+    }
+}
+
+impl TryFrom<Value> for bool {
+    type Error = ValueError;
+
+    #[inline]
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&Value> for String {
+    type Error = ValueError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value
+            .as_string()
+            .map(str::to_string)
+            .ok_or_else(|| ValueError::type_conversion(value.type_name(), "String"))
+    }
+}
+
+impl TryFrom<Value> for String {
+    type Error = ValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(s) => Ok(s.into_string()),
+            other => Err(ValueError::type_conversion(other.type_name(), "String")),
+        }
+    }
+}
+
+impl TryFrom<&Value> for Vec<Value> {
+    type Error = ValueError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value
+            .as_array()
+            .map(|a| a.to_vec())
+            .ok_or_else(|| ValueError::type_conversion(value.type_name(), "Vec<Value>"))
+    }
+}
+
+impl TryFrom<Value> for Vec<Value> {
+    type Error = ValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Array(a) => Ok(a.into_vec()),
+            other => Err(ValueError::type_conversion(other.type_name(), "Vec<Value>")),
+        }
+    }
+}
+
+impl TryFrom<&Value> for ObjectValue {
+    type Error = ValueError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value
+            .as_object()
+            .cloned()
+            .ok_or_else(|| ValueError::type_conversion(value.type_name(), "ObjectValue"))
+    }
+}
+
+impl TryFrom<Value> for ObjectValue {
+    type Error = ValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Object(o) => Ok(o),
+            other => Err(ValueError::type_conversion(other.type_name(), "ObjectValue")),
+        }
+    }
+}
+
 // === Comparison implementation ===
 
 impl PartialOrd for Value {
@@ -466,6 +1130,149 @@ impl PartialOrd for Value {
     }
 }
 
+// === Non-finite number handling ===
+
+/// What [`Value::sanitize_for_json`] does with `NaN` and infinite numbers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NonFinitePolicy {
+    /// Replace with null, as the JSON conversion does implicitly
+    #[default]
+    ToNull,
+    /// Replace with the strings `"NaN"`, `"Infinity"` or `"-Infinity"`
+    ToString,
+    /// Fail on the first non-finite number
+    Error,
+}
+
+impl Value {
+    /// Returns a copy with non-finite numbers replaced according to `policy`
+    ///
+    /// JSON cannot represent `NaN` or infinities, and converting them to
+    /// `serde_json::Value` silently yields null. Sanitizing first makes that
+    /// loss explicit. Arrays and objects are processed recursively; errors
+    /// name the path of the offending number.
+    pub fn sanitize_for_json(&self, policy: NonFinitePolicy) -> ValueResult<Value> {
+        sanitize_non_finite(self, policy, "")
+    }
+}
+
+fn sanitize_non_finite(value: &Value, policy: NonFinitePolicy, path: &str) -> ValueResult<Value> {
+    let child_path = |segment: &dyn std::fmt::Display| {
+        if path.is_empty() { segment.to_string() } else { format!("{path}.{segment}") }
+    };
+    match value {
+        Value::Number(n) if !n.is_finite() => {
+            let f = n.as_f64();
+            let name = if f.is_nan() {
+                "NaN"
+            } else if f > 0.0 {
+                "Infinity"
+            } else {
+                "-Infinity"
+            };
+            match policy {
+                NonFinitePolicy::ToNull => Ok(Value::Null),
+                NonFinitePolicy::ToString => Ok(Value::string(name)),
+                NonFinitePolicy::Error => {
+                    let location =
+                        if path.is_empty() { String::new() } else { format!(" at '{path}'") };
+                    Err(ValueError::custom(format!(
+                        "Non-finite number {name}{location} cannot be used in JSON"
+                    )))
+                },
+            }
+        },
+        Value::Array(arr) => {
+            let items: ValueResult<Vec<Value>> = arr
+                .iter()
+                .enumerate()
+                .map(|(i, item)| sanitize_non_finite(item, policy, &child_path(&i)))
+                .collect();
+            Ok(Value::array(ArrayValue::new(items?)))
+        },
+        Value::Object(obj) => {
+            let mut object = ObjectValue::new();
+            for (key, item) in obj.iter() {
+                object.insert(key.clone(), sanitize_non_finite(item, policy, &child_path(key))?);
+            }
+            Ok(Value::object(object))
+        },
+        other => Ok(other.clone()),
+    }
+}
+
+// === Compaction ===
+
+/// What [`Value::compact_with`] prunes
+///
+/// The default, used by [`Value::compact`], only drops nulls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompactOptions {
+    /// Drop null object entries and array elements
+    pub drop_null: bool,
+    /// Drop objects that are empty, including ones emptied by compaction
+    pub drop_empty_objects: bool,
+    /// Drop arrays that are empty, including ones emptied by compaction
+    pub drop_empty_arrays: bool,
+}
+
+impl Default for CompactOptions {
+    fn default() -> Self {
+        Self { drop_null: true, drop_empty_objects: false, drop_empty_arrays: false }
+    }
+}
+
+impl Value {
+    /// Returns a copy with null object entries and array elements removed,
+    /// recursively
+    #[must_use]
+    pub fn compact(&self) -> Value {
+        self.compact_with(CompactOptions::default())
+    }
+
+    /// Returns a copy with the entries selected by `options` removed,
+    /// recursively
+    ///
+    /// Children are compacted before their container is checked, so an
+    /// object holding only nulls is dropped as empty when both options are
+    /// set. The value itself is never dropped: compacting `null` gives `null`
+    /// and an emptied root stays an empty array or object.
+    #[must_use]
+    pub fn compact_with(&self, options: CompactOptions) -> Value {
+        match self {
+            Value::Array(arr) => {
+                let items = arr.iter().filter_map(|item| compact_entry(item, options));
+                Value::array(items.collect::<ArrayValue>())
+            },
+            Value::Object(obj) => {
+                let mut object = ObjectValue::new();
+                for (key, item) in obj.iter() {
+                    if let Some(item) = compact_entry(item, options) {
+                        object.insert(key.clone(), item);
+                    }
+                }
+                Value::object(object)
+            },
+            other => other.clone(),
+        }
+    }
+}
+
+/// Compacts a nested value, returning `None` if `options` drop it
+fn compact_entry(value: &Value, options: CompactOptions) -> Option<Value> {
+    if value.is_null() {
+        return (!options.drop_null).then_some(Value::Null);
+    }
+
+    let compacted = value.compact_with(options);
+    let dropped = match &compacted {
+        Value::Array(arr) => options.drop_empty_arrays && arr.is_empty(),
+        Value::Object(obj) => options.drop_empty_objects && obj.is_empty(),
+        _ => false,
+    };
+    (!dropped).then_some(compacted)
+}
+
 // === JSON conversion (feature-gated) ===
 
 #[cfg(feature = "json")]
@@ -518,10 +1325,169 @@ impl TryFrom<serde_json::Value> for Value {
     }
 }
 
+/// Policy applied when converting JSON into a [`Value`]
+///
+/// The default options match the plain `TryFrom<serde_json::Value>`
+/// conversion.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsonConversionOptions {
+    /// Convert floats without a fractional part (e.g. `2.0`) to integers
+    pub integralize_floats: bool,
+    /// Convert empty JSON objects to null
+    pub empty_object_as_null: bool,
+}
+
+#[cfg(feature = "json")]
+impl Value {
+    /// Converts JSON into a value, applying the given conversion policy
+    /// recursively
+    pub fn from_json_with(
+        json: serde_json::Value,
+        options: &JsonConversionOptions,
+    ) -> ValueResult<Self> {
+        match json {
+            serde_json::Value::Number(n) if options.integralize_floats && !n.is_i64() => {
+                match n.as_f64() {
+                    Some(f) if f.fract() == 0.0 && f >= i64::MIN as f64 && f < i64::MAX as f64 => {
+                        Ok(Value::number(f as i64))
+                    },
+                    _ => Value::try_from(serde_json::Value::Number(n)),
+                }
+            },
+            serde_json::Value::Array(arr) => {
+                let values: Result<Vec<Value>, ValueError> =
+                    arr.into_iter().map(|v| Value::from_json_with(v, options)).collect();
+                Ok(Value::array(ArrayValue::new(values?)))
+            },
+            serde_json::Value::Object(obj) if obj.is_empty() && options.empty_object_as_null => {
+                Ok(Value::Null)
+            },
+            serde_json::Value::Object(obj) => {
+                let mut object = ObjectValue::new();
+                for (key, val) in obj {
+                    object.insert(key, Value::from_json_with(val, options)?);
+                }
+                Ok(Value::object(object))
+            },
+            other => Value::try_from(other),
+        }
+    }
+
+    /// Parses a JSON string into a value
+    ///
+    /// JSON objects and arrays become [`Value::Object`] and [`Value::Array`];
+    /// parse failures are reported as [`ValueError`].
+    pub fn from_json_str(s: &str) -> ValueResult<Self> {
+        let json: serde_json::Value =
+            serde_json::from_str(s).map_err(|e| ValueError::json_deserialization(e.to_string()))?;
+        Value::try_from(json)
+    }
+
+    /// Serializes the value to a compact JSON string
+    pub fn to_json_string(&self) -> ValueResult<String> {
+        serde_json::to_string(&serde_json::Value::from(self.clone()))
+            .map_err(|e| ValueError::json_serialization(e.to_string()))
+    }
+
+    /// Serializes the value to a pretty-printed JSON string
+    pub fn to_json_string_pretty(&self) -> ValueResult<String> {
+        serde_json::to_string_pretty(&serde_json::Value::from(self.clone()))
+            .map_err(|e| ValueError::json_serialization(e.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_equals_ignoring() {
+        let mut first = ObjectValue::new();
+        first.insert("id".to_string(), Value::number(7));
+        first.insert("timestamp".to_string(), Value::number(1_000));
+        let mut second = first.clone();
+        second.insert("timestamp".to_string(), Value::number(2_000));
+
+        let a = Value::from(vec![Value::object(first.clone())]);
+        let b = Value::from(vec![Value::object(second.clone())]);
+        assert_ne!(a, b);
+        assert!(a.equals_ignoring(&b, &["timestamp"]));
+
+        second.insert("id".to_string(), Value::number(8));
+        let c = Value::from(vec![Value::object(second)]);
+        assert!(!a.equals_ignoring(&c, &["timestamp"]));
+
+        // An ignored key present on one side only is still ignored
+        first.remove("timestamp");
+        let stamped = &a.as_array().unwrap()[0];
+        assert!(Value::object(first).equals_ignoring(stamped, &["timestamp"]));
+    }
+
+    #[test]
+    fn test_equals_ignoring_unordered() {
+        let a = Value::from(vec![1, 2, 2]);
+        let b = Value::from(vec![2, 1, 2]);
+        assert!(!a.equals_ignoring(&b, &[]));
+        assert!(a.equals_ignoring_unordered(&b, &[]));
+        assert!(!a.equals_ignoring_unordered(&Value::from(vec![1, 1, 2]), &[]));
+    }
+
+    #[test]
+    fn test_approx_equals() {
+        let nested = |x: f64| {
+            let mut inner = ObjectValue::new();
+            inner.insert("score".to_string(), Value::number(x));
+            inner.insert("label".to_string(), Value::string("a"));
+            let mut outer = ObjectValue::new();
+            let items = Value::from(vec![Value::number(1), Value::object(inner)]);
+            outer.insert("items".to_string(), items);
+            Value::object(outer)
+        };
+
+        let a = nested(0.1 + 0.2);
+        let b = nested(0.3);
+        assert_ne!(a, b);
+        assert!(a.approx_equals(&b, 1e-9));
+        assert!(!a.approx_equals(&b, 0.0));
+        assert!(a.approx_equals(&a, 0.0));
+        assert!(!a.approx_equals(&nested(0.31), 1e-9));
+
+        // Non-numbers and structure still compare exactly
+        assert!(!Value::string("1").approx_equals(&Value::number(1), 1.0));
+        assert!(!Value::from(vec![1, 2]).approx_equals(&Value::from(vec![2, 1]), 0.5));
+        assert!(!Value::from(vec![1]).approx_equals(&Value::from(vec![1, 1]), 1.0));
+    }
+
+    #[test]
+    fn test_from_str_detection() {
+        assert_eq!("null".parse::<Value>().unwrap(), Value::Null);
+        assert_eq!("true".parse::<Value>().unwrap(), Value::boolean(true));
+        // `==` treats 42 and 42.0 alike, so check the variant
+        assert!(matches!(
+            " 42 ".parse::<Value>().unwrap(),
+            Value::Number(NumberValue::Integer(42))
+        ));
+        assert!(matches!("-1.5e2".parse::<Value>().unwrap(), Value::Number(NumberValue::Float(_))));
+        assert_eq!("hello".parse::<Value>().unwrap(), Value::string("hello"));
+        assert_eq!("NaN".parse::<Value>().unwrap(), Value::string("NaN"));
+        assert_eq!("True".parse::<Value>().unwrap(), Value::string("True"));
+        assert_eq!("[1, 2".parse::<Value>().unwrap(), Value::string("[1, 2"));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_str_json() {
+        let array = "[1, 2]".parse::<Value>().unwrap();
+        assert_eq!(array, Value::from(vec![1, 2]));
+
+        let object = r#"{"a": true}"#.parse::<Value>().unwrap();
+        assert_eq!(object.as_object().unwrap().get("a"), Some(&Value::boolean(true)));
+
+        let plain = Value::parse_with("[1, 2]", ParseOptions { detect_json: false });
+        assert_eq!(plain, Value::string("[1, 2]"));
+    }
+
     #[test]
     fn test_value_creation() {
         let string_val = Value::string("hello");
@@ -563,6 +1529,217 @@ mod tests {
         assert!(Value::number(42).is_truthy());
         assert!(Value::number(0).is_falsy());
         assert!(Value::null().is_falsy());
+
+        assert!(Value::number(0.0).is_falsy());
+        assert!(Value::number(f64::NAN).is_falsy());
+        assert!(Value::array(ArrayValue::new(vec![])).is_falsy());
+        assert!(Value::object(ObjectValue::new()).is_falsy());
+
+        assert!(Value::number(-0.5).is_truthy());
+        assert!(Value::string("false").is_truthy());
+        assert!(Value::array(ArrayValue::new(vec![Value::null()])).is_truthy());
+    }
+
+    #[test]
+    fn test_pointer_set() -> ValueResult<()> {
+        let mut value = Value::object(ObjectValue::new());
+
+        value.pointer_set("/config/db/host", Value::string("localhost"))?;
+        assert_eq!(value.pointer("/config/db/host"), Some(&Value::string("localhost")));
+
+        value.pointer_set("/tags", Value::array(ArrayValue::new(vec![Value::string("a")])))?;
+        value.pointer_set("/tags/-", Value::string("b"))?;
+        value.pointer_set("/tags/0", Value::string("z"))?;
+        assert_eq!(value.pointer("/tags/1"), Some(&Value::string("b")));
+        assert_eq!(value.pointer("/tags/0"), Some(&Value::string("z")));
+
+        value.pointer_set("/a~1b/c~0d", Value::number(1))?;
+        assert!(value.as_object().unwrap().get("a/b").is_some());
+        assert_eq!(value.pointer("/a~1b/c~0d"), Some(&Value::number(1)));
+        assert_eq!(value.pointer(""), Some(&value));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pointer_set_errors() {
+        let mut value = Value::object(ObjectValue::new());
+        value.pointer_set("/name", Value::string("x")).unwrap();
+        value.pointer_set("/list", Value::array(ArrayValue::new(vec![]))).unwrap();
+
+        let error = value.pointer_set("/name/first", Value::null()).unwrap_err();
+        assert!(error.to_string().contains("string"));
+        assert!(matches!(
+            value.pointer_set("/list/0", Value::null()),
+            Err(ValueError::IndexOutOfBounds { index: 0, length: 0 })
+        ));
+        assert!(value.pointer_set("/list/01", Value::null()).is_err());
+        assert!(value.pointer_set("no-slash", Value::null()).is_err());
+        assert_eq!(value.pointer("/list/-"), None);
+    }
+
+    #[test]
+    fn test_pretty_string_bounded() {
+        let array = Value::array(ArrayValue::new((0..100).map(Value::number).collect::<Vec<_>>()));
+        assert_eq!(
+            array.to_pretty_string_bounded(3, 5, 10),
+            "[\n  0,\n  1,\n  2,\n  ... (97 more)\n]"
+        );
+        assert_eq!(array.to_pretty_string_bounded(0, 5, 10), "[\n  ... (100 more)\n]");
+
+        let mut level3 = ObjectValue::new();
+        level3.insert("c".to_string(), Value::boolean(true));
+        let mut level2 = ObjectValue::new();
+        level2.insert("b".to_string(), Value::object(level3));
+        let mut root = ObjectValue::new();
+        root.insert("a".to_string(), Value::object(level2));
+        root.insert("empty".to_string(), Value::array(ArrayValue::new(vec![])));
+        let nested = Value::object(root);
+        assert_eq!(
+            nested.to_pretty_string_bounded(10, 2, 10),
+            "{\n  \"a\": {\n    \"b\": {...}\n  },\n  \"empty\": []\n}"
+        );
+        assert_eq!(nested.to_pretty_string_bounded(10, 0, 10), "{...}");
+
+        let long = Value::string("a".repeat(50));
+        assert_eq!(long.to_pretty_string_bounded(10, 5, 5), "\"aaaaa...\"");
+        assert_eq!(Value::string("short").to_pretty_string_bounded(10, 5, 5), "\"short\"");
+        assert_eq!(Value::null().to_pretty_string_bounded(10, 5, 5), "null");
+    }
+
+    #[test]
+    fn test_structure_metrics() {
+        let mut level3 = ObjectValue::new();
+        level3.insert("d".to_string(), Value::number(1));
+        let mut level2 = ObjectValue::new();
+        level2.insert("c".to_string(), Value::object(level3));
+        let mut level1 = ObjectValue::new();
+        level1.insert("b".to_string(), Value::object(level2));
+        let mut root = ObjectValue::new();
+        root.insert("a".to_string(), Value::object(level1));
+        let nested = Value::object(root);
+        assert_eq!(nested.depth(), 4);
+        assert_eq!(Value::number(1).depth(), 0);
+
+        let mut mixed = ObjectValue::new();
+        mixed.insert("name".to_string(), Value::string("x"));
+        mixed.insert(
+            "tags".to_string(),
+            Value::array(ArrayValue::new(vec![Value::string("a"), Value::null()])),
+        );
+        let mixed = Value::object(mixed);
+        // object + name + array + two elements
+        assert_eq!(mixed.node_count(), 5);
+        assert_eq!(mixed.depth(), 2);
+
+        assert!(mixed.within_limits(2, 5));
+        assert!(!mixed.within_limits(1, 5));
+        assert!(!mixed.within_limits(2, 4));
+    }
+
+    #[test]
+    fn test_coerce_to_array() {
+        let scalar = Value::string("only");
+        assert_eq!(scalar.coerce_to_array(), ArrayValue::new(vec![Value::string("only")]));
+        assert_eq!(scalar.coerce_to_array_nonempty().unwrap().len(), 1);
+
+        let array = Value::array(ArrayValue::new(vec![Value::number(1), Value::number(2)]));
+        assert_eq!(Value::Array(array.coerce_to_array()), array);
+
+        let object = Value::Object(ObjectValue::new());
+        assert_eq!(object.coerce_to_array(), ArrayValue::new(vec![object.clone()]));
+
+        assert!(Value::null().coerce_to_array().is_empty());
+        assert!(Value::null().coerce_to_array_nonempty().is_err());
+        assert!(Value::array(ArrayValue::empty()).coerce_to_array_nonempty().is_err());
+    }
+
+    #[test]
+    fn test_get_chaining() {
+        let user =
+            |name: &str| Value::object(ObjectValue::from_pairs([("name", Value::string(name))]));
+        let mut root = Value::object(ObjectValue::from_pairs([(
+            "users",
+            Value::from(vec![user("ann"), user("bob")]),
+        )]));
+
+        let name = root.get("users").and_then(|users| users.get(1)).and_then(|u| u.get("name"));
+        assert_eq!(name, Some(&Value::string("bob")));
+
+        // Type mismatches and missing entries return None
+        assert_eq!(root.get(0), None);
+        assert_eq!(root.get("users").and_then(|users| users.get("name")), None);
+        assert_eq!(root.get("users").and_then(|users| users.get(2)), None);
+        assert_eq!(Value::string("text").get("len"), None);
+
+        let key = "users".to_string();
+        let first = root.get_mut(&key).and_then(|users| users.get_mut(0)).unwrap();
+        *first.get_mut("name").unwrap() = Value::string("amy");
+        assert_eq!(root.pointer("/users/0/name"), Some(&Value::string("amy")));
+    }
+
+    #[test]
+    fn test_estimated_size_bytes() {
+        let large = Value::string("x".repeat(10_000));
+        assert_eq!(large.estimated_size_bytes(), 10_000 + size_of::<Value>());
+
+        let leaves = [Value::string("hello"), Value::number(42), Value::binary(vec![0u8; 64])];
+        let leaf_total: usize = leaves.iter().map(Value::estimated_size_bytes).sum();
+
+        let mut object = ObjectValue::new();
+        object.insert("items".to_string(), Value::from(leaves[..2].to_vec()));
+        object.insert("data".to_string(), leaves[2].clone());
+        let nested = Value::object(object);
+
+        let keys = 2 * size_of::<String>() + "items".len() + "data".len();
+        let containers = 2 * (size_of::<Value>() + CONTAINER_OVERHEAD_BYTES);
+        assert_eq!(nested.estimated_size_bytes(), leaf_total + containers + keys);
+        assert!(nested.estimated_size_bytes() > leaf_total);
+    }
+
+    #[test]
+    fn test_within_limits_stops_early() {
+        // 1 array and 1000 elements
+        let wide = Value::array(ArrayValue::new(vec![Value::null(); 1000]));
+        assert!(!wide.within_limits(1, 10));
+        assert!(!wide.within_limits(1, 1000));
+        assert!(wide.within_limits(1, 1001));
+        assert!(!wide.within_limits(0, 1001));
+
+        let mut deep = Value::null();
+        for _ in 0..10_000 {
+            deep = Value::array(ArrayValue::new(vec![deep]));
+        }
+        assert_eq!(deep.depth(), 10_000);
+        assert!(!deep.within_limits(64, usize::MAX));
+    }
+
+    #[test]
+    fn test_try_into_basic_types() -> Result<(), ValueError> {
+        let n: i64 = Value::number(42).try_into()?;
+        assert_eq!(n, 42);
+        let f: f64 = (&Value::number(1.5)).try_into()?;
+        assert_eq!(f, 1.5);
+        let b: bool = Value::boolean(true).try_into()?;
+        assert!(b);
+        let s: String = Value::string("hello").try_into()?;
+        assert_eq!(s, "hello");
+        let v: Vec<Value> = Value::from(vec![1, 2]).try_into()?;
+        assert_eq!(v, vec![Value::number(1), Value::number(2)]);
+        let o: ObjectValue = Value::object(ObjectValue::new()).try_into()?;
+        assert!(o.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_into_mismatch() {
+        let result: Result<i64, _> = Value::string("42").try_into();
+        assert!(matches!(result, Err(ValueError::TypeConversion { .. })));
+        let result: Result<i64, _> = Value::number(1.5).try_into();
+        assert!(result.is_err());
+        let result: Result<String, _> = (&Value::number(1)).try_into();
+        assert!(result.is_err());
+        let result: Result<ObjectValue, _> = Value::Null.try_into();
+        assert!(result.is_err());
     }
 
     #[test]
@@ -573,6 +1750,119 @@ mod tests {
         assert_eq!(Value::null().type_name(), "null");
     }
 
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_string_round_trip() {
+        let source = r#"{"user":{"name":"Alice","tags":["a","b"],"age":30}}"#;
+        let value = Value::from_json_str(source).unwrap();
+        let user = value.as_object().unwrap().get("user").unwrap();
+        assert!(user.is_object());
+
+        let back = Value::from_json_str(&value.to_json_string().unwrap()).unwrap();
+        assert_eq!(back, value);
+        let back = Value::from_json_str(&value.to_json_string_pretty().unwrap()).unwrap();
+        assert_eq!(back, value);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_conversion_options() {
+        let json = serde_json::json!({"count": 2.0, "ratio": 2.5, "meta": {}});
+
+        let value = Value::from_json_with(json.clone(), &JsonConversionOptions::default()).unwrap();
+        let object = value.as_object().unwrap();
+        // `==` treats 2 and 2.0 alike, so check the variant
+        assert!(matches!(object.get("count"), Some(Value::Number(NumberValue::Float(_)))));
+        assert!(object.get("meta").unwrap().is_object());
+
+        let options =
+            JsonConversionOptions { integralize_floats: true, empty_object_as_null: true };
+        let value = Value::from_json_with(json, &options).unwrap();
+        let object = value.as_object().unwrap();
+        assert!(matches!(object.get("count"), Some(Value::Number(NumberValue::Integer(2)))));
+        assert_eq!(object.get("ratio"), Some(&Value::number(2.5)));
+        assert_eq!(object.get("meta"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn test_sanitize_for_json() {
+        let value = Value::object(ObjectValue::from_pairs([
+            ("ratio", Value::number(f64::NAN)),
+            ("limits", Value::array(ArrayValue::new(vec![Value::number(f64::INFINITY)]))),
+            ("count", Value::number(3)),
+        ]));
+
+        let nulls = value.sanitize_for_json(NonFinitePolicy::ToNull).unwrap();
+        let object = nulls.as_object().unwrap();
+        assert_eq!(object.get("ratio"), Some(&Value::Null));
+        assert_eq!(object.get_nested("limits.0"), Some(&Value::Null));
+        assert_eq!(object.get("count"), Some(&Value::number(3)));
+
+        let strings = value.sanitize_for_json(NonFinitePolicy::ToString).unwrap();
+        let object = strings.as_object().unwrap();
+        assert_eq!(object.get("ratio"), Some(&Value::string("NaN")));
+        assert_eq!(object.get_nested("limits.0"), Some(&Value::string("Infinity")));
+
+        let err = value.sanitize_for_json(NonFinitePolicy::Error).unwrap_err();
+        assert!(err.to_string().contains("Non-finite number"));
+        let err = Value::object(ObjectValue::from_pairs([("x", Value::number(f64::NEG_INFINITY))]))
+            .sanitize_for_json(NonFinitePolicy::Error)
+            .unwrap_err();
+        assert!(err.to_string().contains("-Infinity at 'x'"));
+    }
+
+    #[test]
+    fn test_compact() {
+        let value = Value::object(ObjectValue::from_pairs([
+            ("name", Value::string("Ada")),
+            ("nickname", Value::Null),
+            (
+                "address",
+                Value::object(ObjectValue::from_pairs([
+                    ("street", Value::Null),
+                    ("city", Value::Null),
+                ])),
+            ),
+            ("tags", Value::array(ArrayValue::new(vec![Value::Null, Value::string("x")]))),
+            ("extra", Value::array(ArrayValue::new(vec![]))),
+        ]));
+
+        let compacted = value.compact();
+        let object = compacted.as_object().unwrap();
+        assert!(!object.contains_key("nickname"));
+        assert_eq!(object.get("address"), Some(&Value::object(ObjectValue::new())));
+        assert_eq!(object.get_nested("tags.0"), Some(&Value::string("x")));
+        assert_eq!(object.get("extra").and_then(Value::as_array).map(ArrayValue::len), Some(0));
+
+        let options = CompactOptions {
+            drop_empty_objects: true,
+            drop_empty_arrays: true,
+            ..CompactOptions::default()
+        };
+        let compacted = value.compact_with(options);
+        let object = compacted.as_object().unwrap();
+        assert!(!object.contains_key("address"));
+        assert!(!object.contains_key("extra"));
+        assert_eq!(object.len(), 2);
+
+        let keep_nulls = CompactOptions { drop_null: false, ..options };
+        let compacted = value.compact_with(keep_nulls);
+        assert_eq!(compacted.get("nickname"), Some(&Value::Null));
+        assert!(compacted.get("address").is_some());
+
+        // The root is never dropped
+        assert_eq!(Value::Null.compact(), Value::Null);
+        let emptied = Value::object(ObjectValue::from_pairs([("x", Value::Null)]));
+        assert_eq!(emptied.compact_with(options), Value::object(ObjectValue::new()));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_string_malformed() {
+        let err: ValueError = Value::from_json_str("{\"unclosed\": ").unwrap_err();
+        assert!(matches!(err, ValueError::JsonDeserialization { .. }));
+    }
+
     #[cfg(all(feature = "json", feature = "serde"))]
     #[test]
     fn test_json_conversion() {